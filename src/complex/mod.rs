@@ -0,0 +1,1573 @@
+use crate::constants::{H_PI, LN10, LN2};
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// ### Complex
+///
+/// Complex Number Type
+///
+/// The `Complex` type stores a complex number `re + im·i` as a pair of `f64` values.
+/// It supports the arithmetic operators (`+`, `-`, `*`, `/` and unary `-`) with other complex numbers and with `f64` values,
+/// so real and complex operands can be mixed freely.
+///
+/// The complex elementary functions live in the `complex` module and mirror the names in `functions::num`
+/// (`complex::sqrt`, `complex::ln`, `complex::asin`, ...). They return the principal value, as MATLAB does,
+/// so `complex::sqrt(-1)` is `i` where the real `sqrt(-1.0)` is `NaN`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::Complex;
+/// let z = Complex::new(3.0, 4.0);
+/// let w = Complex::new(1.0, -2.0);
+/// assert_eq!(z + w, Complex::new(4.0, 2.0));
+/// assert_eq!(z - w, Complex::new(2.0, 6.0));
+/// assert_eq!(z * w, Complex::new(11.0, -2.0));
+/// assert_eq!(z / Complex::new(0.0, 1.0), Complex::new(4.0, -3.0));
+/// assert_eq!(z * 2.0, Complex::new(6.0, 8.0));
+/// assert_eq!(1.0 - z, Complex::new(-2.0, -4.0));
+/// assert_eq!(-z, Complex::new(-3.0, -4.0));
+/// assert_eq!(Complex::I * Complex::I, Complex::from(-1.0));
+/// assert_eq!(z.abs(), 5.0);
+/// assert_eq!(z.conj(), Complex::new(3.0, -4.0));
+/// assert_eq!(z.to_string(), "3 + 4i");
+/// assert_eq!(w.to_string(), "1 - 2i");
+/// ```
+/// <small>End Type Doc</small>
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Complex {
+    pub re: f64,
+    pub im: f64,
+}
+
+impl Complex {
+    /// The complex number `0 + 0i`.
+    pub const ZERO: Complex = Complex { re: 0.0, im: 0.0 };
+    /// The complex number `1 + 0i`.
+    pub const ONE: Complex = Complex { re: 1.0, im: 0.0 };
+    /// The imaginary unit `0 + 1i`.
+    pub const I: Complex = Complex { re: 0.0, im: 1.0 };
+
+    /// ### new(re, im)
+    ///
+    /// Complex Constructor
+    ///
+    /// The `new` function builds the complex number `re + im·i` from its rectangular parts (MATLAB's `complex(re, im)`).
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::math::Complex;
+    /// let z = Complex::new(1.5, -2.0);
+    /// assert_eq!(z.re, 1.5);
+    /// assert_eq!(z.im, -2.0);
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn new(re: f64, im: f64) -> Complex {
+        Complex { re, im }
+    }
+
+    /// ### from_polar(r, theta)
+    ///
+    /// Complex Constructor
+    ///
+    /// The `from_polar` function builds the complex number `r·(cos θ + i·sin θ)` from its modulus `r` and argument `theta` in radians.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::math::{Complex, H_PI};
+    /// let z = Complex::from_polar(2.0, H_PI);
+    /// assert!(z.re.abs() < 1e-15);
+    /// assert_eq!(z.im, 2.0);
+    /// assert_eq!(Complex::from_polar(2.0, 0.0), Complex::new(2.0, 0.0));
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn from_polar(r: f64, theta: f64) -> Complex {
+        Complex::new(r * theta.cos(), r * theta.sin())
+    }
+
+    /// ### to_polar()
+    ///
+    /// Complex Conversion
+    ///
+    /// The `to_polar` function returns the modulus and the argument (in radians) of the complex number as a pair `(r, theta)`,
+    /// the inverse of `from_polar`.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::math::{Complex, PI};
+    /// assert_eq!(Complex::new(-2.0, 0.0).to_polar(), (2.0, PI));
+    /// let (r, theta) = Complex::new(3.0, 4.0).to_polar();
+    /// let z = Complex::from_polar(r, theta);
+    /// assert!((z - Complex::new(3.0, 4.0)).abs() < 1e-15);
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn to_polar(self) -> (f64, f64) {
+        (self.abs(), self.arg())
+    }
+
+    /// ### abs()
+    ///
+    /// Complex Function
+    ///
+    /// The `abs` function returns the modulus `|z| = sqrt(re² + im²)`, computed without intermediate overflow.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::math::{Complex, fix64};
+    /// assert_eq!(Complex::new(3.0, -4.0).abs(), 5.0);
+    /// assert_eq!(fix64(Complex::new(3e200, 4e200).abs() / 1e200), 5.0);
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn abs(self) -> f64 {
+        self.re.hypot(self.im)
+    }
+
+    /// ### norm_sqr()
+    ///
+    /// Complex Function
+    ///
+    /// The `norm_sqr` function returns the squared modulus `re² + im²`, which avoids the square root of `abs`.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::math::Complex;
+    /// assert_eq!(Complex::new(3.0, -4.0).norm_sqr(), 25.0);
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn norm_sqr(self) -> f64 {
+        self.re * self.re + self.im * self.im
+    }
+
+    /// ### arg()
+    ///
+    /// Complex Function
+    ///
+    /// The `arg` function returns the argument (phase angle) of the complex number in radians, in the interval `(-π, π]` (MATLAB's `angle`).
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::math::{Complex, H_PI, PI};
+    /// assert_eq!(Complex::new(0.0, 1.0).arg(), H_PI);
+    /// assert_eq!(Complex::new(-1.0, 0.0).arg(), PI);
+    /// assert_eq!(Complex::new(1.0, 0.0).arg(), 0.0);
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn arg(self) -> f64 {
+        self.im.atan2(self.re)
+    }
+
+    /// ### conj()
+    ///
+    /// Complex Function
+    ///
+    /// The `conj` function returns the complex conjugate `re - im·i`.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::math::Complex;
+    /// assert_eq!(Complex::new(1.0, 2.0).conj(), Complex::new(1.0, -2.0));
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn conj(self) -> Complex {
+        Complex::new(self.re, -self.im)
+    }
+
+    /// ### recip()
+    ///
+    /// Complex Function
+    ///
+    /// The `recip` function returns the reciprocal `1 / z`.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::math::Complex;
+    /// assert_eq!(Complex::new(0.0, 2.0).recip(), Complex::new(0.0, -0.5));
+    /// assert_eq!(Complex::new(3.0, 4.0).recip(), Complex::new(0.12, -0.16));
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn recip(self) -> Complex {
+        Complex::ONE / self
+    }
+
+    /// ### is_nan()
+    ///
+    /// Complex Function
+    ///
+    /// The `is_nan` function returns `true` if either part of the complex number is `NaN`.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::math::{Complex, NAN_F64};
+    /// assert!(Complex::new(1.0, NAN_F64).is_nan());
+    /// assert!(!Complex::new(1.0, 2.0).is_nan());
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn is_nan(self) -> bool {
+        self.re.is_nan() || self.im.is_nan()
+    }
+
+    /// ### is_finite()
+    ///
+    /// Complex Function
+    ///
+    /// The `is_finite` function returns `true` if both parts of the complex number are finite.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::math::{Complex, INF_F64};
+    /// assert!(Complex::new(1.0, 2.0).is_finite());
+    /// assert!(!Complex::new(INF_F64, 0.0).is_finite());
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn is_finite(self) -> bool {
+        self.re.is_finite() && self.im.is_finite()
+    }
+}
+
+impl From<f64> for Complex {
+    fn from(re: f64) -> Complex {
+        Complex::new(re, 0.0)
+    }
+}
+
+impl fmt::Display for Complex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.im.is_sign_negative() && !self.im.is_nan() {
+            write!(f, "{} - {}i", self.re, -self.im)
+        } else {
+            write!(f, "{} + {}i", self.re, self.im)
+        }
+    }
+}
+
+impl Add for Complex {
+    type Output = Complex;
+
+    fn add(self, rhs: Complex) -> Complex {
+        Complex::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+impl Sub for Complex {
+    type Output = Complex;
+
+    fn sub(self, rhs: Complex) -> Complex {
+        Complex::new(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+impl Mul for Complex {
+    type Output = Complex;
+
+    fn mul(self, rhs: Complex) -> Complex {
+        Complex::new(
+            self.re * rhs.re - self.im * rhs.im,
+            self.re * rhs.im + self.im * rhs.re,
+        )
+    }
+}
+
+impl Div for Complex {
+    type Output = Complex;
+
+    // Smith's algorithm: scale by the larger part of the divisor to avoid overflow.
+    fn div(self, rhs: Complex) -> Complex {
+        if rhs.re.abs() >= rhs.im.abs() {
+            let r = rhs.im / rhs.re;
+            let d = rhs.re + rhs.im * r;
+            Complex::new((self.re + self.im * r) / d, (self.im - self.re * r) / d)
+        } else {
+            let r = rhs.re / rhs.im;
+            let d = rhs.re * r + rhs.im;
+            Complex::new((self.re * r + self.im) / d, (self.im * r - self.re) / d)
+        }
+    }
+}
+
+impl Neg for Complex {
+    type Output = Complex;
+
+    fn neg(self) -> Complex {
+        Complex::new(-self.re, -self.im)
+    }
+}
+
+// Mixed real operands are promoted to complex numbers with a zero imaginary part.
+macro_rules! mixed_binop {
+    ($tr:ident, $method:ident, $tr_assign:ident, $method_assign:ident) => {
+        impl $tr<f64> for Complex {
+            type Output = Complex;
+
+            fn $method(self, rhs: f64) -> Complex {
+                self.$method(Complex::from(rhs))
+            }
+        }
+
+        impl $tr<Complex> for f64 {
+            type Output = Complex;
+
+            fn $method(self, rhs: Complex) -> Complex {
+                Complex::from(self).$method(rhs)
+            }
+        }
+
+        impl $tr_assign for Complex {
+            fn $method_assign(&mut self, rhs: Complex) {
+                *self = (*self).$method(rhs);
+            }
+        }
+
+        impl $tr_assign<f64> for Complex {
+            fn $method_assign(&mut self, rhs: f64) {
+                *self = (*self).$method(rhs);
+            }
+        }
+    };
+}
+
+mixed_binop!(Add, add, AddAssign, add_assign);
+mixed_binop!(Sub, sub, SubAssign, sub_assign);
+mixed_binop!(Mul, mul, MulAssign, mul_assign);
+mixed_binop!(Div, div, DivAssign, div_assign);
+
+/// Multiplies by the imaginary unit.
+fn mul_i(z: Complex) -> Complex {
+    Complex::new(-z.im, z.re)
+}
+
+/// ### abs(z)
+///
+/// Complex Function
+///
+/// The `abs` function returns the modulus `|z|` of a complex number.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::{complex, math::Complex};
+/// assert_eq!(complex::abs(Complex::new(-3.0, 4.0)), 5.0);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn abs(z: Complex) -> f64 {
+    z.abs()
+}
+
+/// ### arg(z)
+///
+/// Complex Function
+///
+/// The `arg` function returns the argument (phase angle) of a complex number in radians, in the interval `(-π, π]`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::{complex, math::{Complex, PI}};
+/// assert_eq!(complex::arg(Complex::new(-1.0, 0.0)), PI);
+/// assert_eq!(complex::arg(Complex::new(-1.0, -0.0)), -PI);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn arg(z: Complex) -> f64 {
+    z.arg()
+}
+
+/// ### conj(z)
+///
+/// Complex Function
+///
+/// The `conj` function returns the complex conjugate of a complex number.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::{complex, math::Complex};
+/// assert_eq!(complex::conj(Complex::new(1.0, 2.0)), Complex::new(1.0, -2.0));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn conj(z: Complex) -> Complex {
+    z.conj()
+}
+
+/// ### exp(z)
+///
+/// Complex Function
+///
+/// The `exp` function returns `e` raised to a complex power, `e^re·(cos im + i·sin im)`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::{complex, math::{Complex, PI}};
+/// let z = complex::exp(Complex::new(0.0, PI));
+/// assert_eq!(z.re, -1.0);
+/// assert!(z.im.abs() < 1e-15);
+/// assert_eq!(complex::exp(Complex::new(1.0, 0.0)), Complex::new(std::f64::consts::E, 0.0));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn exp(z: Complex) -> Complex {
+    Complex::from_polar(z.re.exp(), z.im)
+}
+
+/// ### ln(z)
+///
+/// Complex Function
+///
+/// The `ln` function returns the principal natural logarithm `ln|z| + i·arg(z)` of a complex number,
+/// so negative real numbers have the imaginary part `π` (MATLAB's `log`).
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::{complex, math::{Complex, H_PI, PI}};
+/// assert_eq!(complex::ln(Complex::new(-1.0, 0.0)), Complex::new(0.0, PI));
+/// assert_eq!(complex::ln(Complex::I), Complex::new(0.0, H_PI));
+/// assert_eq!(complex::ln(Complex::ZERO).re, f64::NEG_INFINITY);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn ln(z: Complex) -> Complex {
+    Complex::new(z.abs().ln(), z.arg())
+}
+
+/// ### log2(z)
+///
+/// Complex Function
+///
+/// The `log2` function returns the principal base-2 logarithm of a complex number, `ln(z) / ln(2)`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::{complex, math::{Complex, PI, LN2}};
+/// assert_eq!(complex::log2(Complex::new(8.0, 0.0)), Complex::new(3.0, 0.0));
+/// assert_eq!(complex::log2(Complex::new(-1.0, 0.0)), Complex::new(0.0, PI / LN2));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn log2(z: Complex) -> Complex {
+    ln(z) / LN2
+}
+
+/// ### log10(z)
+///
+/// Complex Function
+///
+/// The `log10` function returns the principal base-10 logarithm of a complex number, `ln(z) / ln(10)`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::{complex, math::{Complex, PI, LN10}};
+/// assert_eq!(complex::log10(Complex::new(100.0, 0.0)), Complex::new(2.0, 0.0));
+/// assert_eq!(complex::log10(Complex::new(-1.0, 0.0)), Complex::new(0.0, PI / LN10));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn log10(z: Complex) -> Complex {
+    ln(z) / LN10
+}
+
+/// ### pow(z, w)
+///
+/// Complex Function
+///
+/// The `pow` function returns the principal value of `z` raised to the complex power `w`, `exp(w·ln(z))`.
+/// `0^w` is `1` when `w` is zero and `0` when the real part of `w` is positive.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::{complex, math::{Complex, H_PI, fix64}};
+/// let z = complex::pow(Complex::I, Complex::I);
+/// assert_eq!(fix64(z.re), fix64((-H_PI).exp()));
+/// assert_eq!(z.im, 0.0);
+/// let w = complex::pow(Complex::new(-8.0, 0.0), Complex::from(1.0 / 3.0));
+/// assert_eq!((fix64(w.re), fix64(w.im)), (1.0, 1.7320508));
+/// assert_eq!(complex::pow(Complex::ZERO, Complex::new(2.0, 1.0)), Complex::ZERO);
+/// assert_eq!(complex::pow(Complex::ZERO, Complex::ZERO), Complex::ONE);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn pow(z: Complex, w: Complex) -> Complex {
+    if z == Complex::ZERO {
+        if w == Complex::ZERO {
+            return Complex::ONE;
+        }
+        if w.re > 0.0 {
+            return Complex::ZERO;
+        }
+    }
+    exp(w * ln(z))
+}
+
+/// ### sqrt(z)
+///
+/// Complex Function
+///
+/// The `sqrt` function returns the principal square root of a complex number, the root with a non-negative real part,
+/// so the square root of a negative real number is purely imaginary.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::{complex, math::Complex};
+/// assert_eq!(complex::sqrt(Complex::new(-1.0, 0.0)), Complex::I);
+/// assert_eq!(complex::sqrt(Complex::new(-4.0, 0.0)), Complex::new(0.0, 2.0));
+/// assert_eq!(complex::sqrt(Complex::new(3.0, 4.0)), Complex::new(2.0, 1.0));
+/// assert_eq!(complex::sqrt(Complex::new(0.0, -2.0)), Complex::new(1.0, -1.0));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn sqrt(z: Complex) -> Complex {
+    if z == Complex::ZERO {
+        return Complex::new(0.0, z.im);
+    }
+    // Taking the root of (|re| + |z|) / 2 avoids cancellation between re and |z|.
+    let t = ((z.re.abs() + z.abs()) / 2.0).sqrt();
+    if z.re >= 0.0 {
+        Complex::new(t, z.im / (2.0 * t))
+    } else {
+        Complex::new(z.im.abs() / (2.0 * t), t.copysign(z.im))
+    }
+}
+
+/// ### sin(z)
+///
+/// Complex Function
+///
+/// The `sin` function returns the sine of a complex number, `sin(re)·cosh(im) + i·cos(re)·sinh(im)`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::{complex, math::{Complex, fix64}};
+/// let z = complex::sin(Complex::new(1.0, 1.0));
+/// assert_eq!((fix64(z.re), fix64(z.im)), (1.2984576, 0.6349639));
+/// assert_eq!(complex::sin(Complex::from(0.5)), Complex::from(0.5_f64.sin()));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn sin(z: Complex) -> Complex {
+    Complex::new(z.re.sin() * z.im.cosh(), z.re.cos() * z.im.sinh())
+}
+
+/// ### cos(z)
+///
+/// Complex Function
+///
+/// The `cos` function returns the cosine of a complex number, `cos(re)·cosh(im) - i·sin(re)·sinh(im)`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::{complex, math::{Complex, fix64}};
+/// let z = complex::cos(Complex::new(1.0, 1.0));
+/// assert_eq!((fix64(z.re), fix64(z.im)), (0.83373004, -0.9888977));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn cos(z: Complex) -> Complex {
+    Complex::new(z.re.cos() * z.im.cosh(), -z.re.sin() * z.im.sinh())
+}
+
+/// ### tan(z)
+///
+/// Complex Function
+///
+/// The `tan` function returns the tangent of a complex number. The result stays finite for large imaginary parts,
+/// where it approaches `±i`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::{complex, math::{Complex, fix64}};
+/// let z = complex::tan(Complex::new(1.0, 1.0));
+/// assert_eq!((fix64(z.re), fix64(z.im)), (0.2717526, 1.0839233));
+/// assert_eq!(complex::tan(Complex::new(1.0, 1000.0)).im, 1.0);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn tan(z: Complex) -> Complex {
+    let (a, b) = (2.0 * z.re, 2.0 * z.im);
+    if b.abs() > 40.0 {
+        // cosh(2·im) dominates the denominator, so tan(z) = 2·sin(2·re)·e^(-2|im|) ± i to double precision.
+        return Complex::new(2.0 * a.sin() * (-b.abs()).exp(), b.signum());
+    }
+    let d = a.cos() + b.cosh();
+    Complex::new(a.sin() / d, b.sinh() / d)
+}
+
+/// ### sec(z)
+///
+/// Complex Function
+///
+/// The `sec` function returns the secant of a complex number, `1 / cos(z)`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::{complex, math::{Complex, fix64}};
+/// let z = complex::sec(Complex::new(1.0, 1.0));
+/// assert_eq!((fix64(z.re), fix64(z.im)), (0.49833703, 0.5910838));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn sec(z: Complex) -> Complex {
+    cos(z).recip()
+}
+
+/// ### csc(z)
+///
+/// Complex Function
+///
+/// The `csc` function returns the cosecant of a complex number, `1 / sin(z)`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::{complex, math::{Complex, fix64}};
+/// let z = complex::csc(Complex::new(1.0, 1.0));
+/// assert_eq!((fix64(z.re), fix64(z.im)), (0.621518, -0.303931));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn csc(z: Complex) -> Complex {
+    sin(z).recip()
+}
+
+/// ### cot(z)
+///
+/// Complex Function
+///
+/// The `cot` function returns the cotangent of a complex number, `1 / tan(z)`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::{complex, math::{Complex, fix64}};
+/// let z = complex::cot(Complex::new(1.0, 1.0));
+/// assert_eq!((fix64(z.re), fix64(z.im)), (0.21762156, -0.86801416));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn cot(z: Complex) -> Complex {
+    tan(z).recip()
+}
+
+/// ### asin(z)
+///
+/// Complex Function
+///
+/// The `asin` function returns the principal inverse sine of a complex number, `-i·ln(i·z + sqrt(1 - z²))`,
+/// so arguments outside `[-1, 1]` give a complex result (MATLAB's `asin`).
+/// Real arguments inside `[-1, 1]` give the same value as the real `asin`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::{complex, math::{Complex, fix64}};
+/// let z = complex::asin(Complex::from(2.0));
+/// assert_eq!((fix64(z.re), fix64(z.im)), (1.5707964, -1.316958));
+/// let z = complex::asin(Complex::from(-2.0));
+/// assert_eq!((fix64(z.re), fix64(z.im)), (-1.5707964, 1.316958));
+/// assert_eq!(complex::asin(Complex::from(0.5)), Complex::from(0.5_f64.asin()));
+/// let z = complex::asin(Complex::from(-1e10));
+/// assert_eq!((fix64(z.re), fix64(z.im)), (-1.5707964, 23.718998));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn asin(z: Complex) -> Complex {
+    if z.im == 0.0 && z.re.abs() <= 1.0 {
+        return Complex::new(z.re.asin(), z.im);
+    }
+    // asin is odd; evaluating it in the half-plane where i·z and the root do not cancel keeps full precision.
+    if z.im > 0.0 || (z.im == 0.0 && z.re < 0.0) {
+        return -asin(-z);
+    }
+    let w = ln(mul_i(z) + sqrt(1.0 - z * z));
+    Complex::new(w.im, -w.re)
+}
+
+/// ### acos(z)
+///
+/// Complex Function
+///
+/// The `acos` function returns the principal inverse cosine of a complex number, `π/2 - asin(z)`,
+/// so arguments outside `[-1, 1]` give a complex result (MATLAB's `acos`).
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::{complex, math::{Complex, fix64}};
+/// let z = complex::acos(Complex::from(2.0));
+/// assert_eq!((fix64(z.re), fix64(z.im)), (0.0, 1.316958));
+/// assert_eq!(complex::acos(Complex::from(0.5)), Complex::from(0.5_f64.acos()));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn acos(z: Complex) -> Complex {
+    if z.im == 0.0 && z.re.abs() <= 1.0 {
+        return Complex::new(z.re.acos(), -z.im);
+    }
+    H_PI - asin(z)
+}
+
+/// ### atan(z)
+///
+/// Complex Function
+///
+/// The `atan` function returns the principal inverse tangent of a complex number, `(i/2)·(ln(1 - i·z) - ln(1 + i·z))`.
+/// It is infinite at `±i`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::{complex, math::{Complex, fix64}};
+/// let z = complex::atan(Complex::new(0.0, 2.0));
+/// assert_eq!((fix64(z.re), fix64(z.im)), (1.5707964, 0.54930615));
+/// assert_eq!(complex::atan(Complex::from(1.0)), Complex::from(1.0_f64.atan()));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn atan(z: Complex) -> Complex {
+    if z.im == 0.0 {
+        return Complex::new(z.re.atan(), z.im);
+    }
+    let iz = mul_i(z);
+    let w = ln(1.0 - iz) - ln(1.0 + iz);
+    Complex::new(-w.im / 2.0, w.re / 2.0)
+}
+
+/// ### asec(z)
+///
+/// Complex Function
+///
+/// The `asec` function returns the principal inverse secant of a complex number, `acos(1 / z)`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::{complex, math::{Complex, fix64}};
+/// let z = complex::asec(Complex::from(0.5));
+/// assert_eq!((fix64(z.re), fix64(z.im)), (0.0, 1.316958));
+/// assert_eq!(fix64(complex::asec(Complex::from(2.0)).re), 1.0471976);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn asec(z: Complex) -> Complex {
+    acos(z.recip())
+}
+
+/// ### acsc(z)
+///
+/// Complex Function
+///
+/// The `acsc` function returns the principal inverse cosecant of a complex number, `asin(1 / z)`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::{complex, math::{Complex, fix64}};
+/// let z = complex::acsc(Complex::from(0.5));
+/// assert_eq!((fix64(z.re), fix64(z.im)), (1.5707964, -1.316958));
+/// assert_eq!(fix64(complex::acsc(Complex::from(2.0)).re), 0.5235988);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn acsc(z: Complex) -> Complex {
+    asin(z.recip())
+}
+
+/// ### acot(z)
+///
+/// Complex Function
+///
+/// The `acot` function returns the principal inverse cotangent of a complex number, `atan(1 / z)`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::{complex, math::{Complex, fix64}};
+/// let z = complex::acot(Complex::new(0.0, 2.0));
+/// assert_eq!((fix64(z.re), fix64(z.im)), (0.0, -0.54930615));
+/// assert_eq!(fix64(complex::acot(Complex::from(1.0)).re), 0.7853982);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn acot(z: Complex) -> Complex {
+    atan(z.recip())
+}
+
+/// ### sinh(z)
+///
+/// Complex Function
+///
+/// The `sinh` function returns the hyperbolic sine of a complex number, `sinh(re)·cos(im) + i·cosh(re)·sin(im)`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::{complex, math::{Complex, fix64}};
+/// let z = complex::sinh(Complex::new(1.0, 1.0));
+/// assert_eq!((fix64(z.re), fix64(z.im)), (0.6349639, 1.2984576));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn sinh(z: Complex) -> Complex {
+    Complex::new(z.re.sinh() * z.im.cos(), z.re.cosh() * z.im.sin())
+}
+
+/// ### cosh(z)
+///
+/// Complex Function
+///
+/// The `cosh` function returns the hyperbolic cosine of a complex number, `cosh(re)·cos(im) + i·sinh(re)·sin(im)`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::{complex, math::{Complex, PI}};
+/// let z = complex::cosh(Complex::new(0.0, PI));
+/// assert_eq!(z.re, -1.0);
+/// assert_eq!(z.im, 0.0);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn cosh(z: Complex) -> Complex {
+    Complex::new(z.re.cosh() * z.im.cos(), z.re.sinh() * z.im.sin())
+}
+
+/// ### tanh(z)
+///
+/// Complex Function
+///
+/// The `tanh` function returns the hyperbolic tangent of a complex number, `-i·tan(i·z)`.
+/// The result stays finite for large real parts, where it approaches `±1`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::{complex, math::{Complex, fix64}};
+/// let z = complex::tanh(Complex::new(1.0, 1.0));
+/// assert_eq!((fix64(z.re), fix64(z.im)), (1.0839233, 0.2717526));
+/// assert_eq!(complex::tanh(Complex::new(-1000.0, 1.0)).re, -1.0);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn tanh(z: Complex) -> Complex {
+    let w = tan(mul_i(z));
+    Complex::new(w.im, -w.re)
+}
+
+/// ### sech(z)
+///
+/// Complex Function
+///
+/// The `sech` function returns the hyperbolic secant of a complex number, `1 / cosh(z)`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::{complex, math::{Complex, fix64}};
+/// let z = complex::sech(Complex::new(1.0, 1.0));
+/// assert_eq!((fix64(z.re), fix64(z.im)), (0.49833703, -0.5910838));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn sech(z: Complex) -> Complex {
+    cosh(z).recip()
+}
+
+/// ### csch(z)
+///
+/// Complex Function
+///
+/// The `csch` function returns the hyperbolic cosecant of a complex number, `1 / sinh(z)`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::{complex, math::{Complex, fix64}};
+/// let z = complex::csch(Complex::new(1.0, 1.0));
+/// assert_eq!((fix64(z.re), fix64(z.im)), (0.303931, -0.621518));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn csch(z: Complex) -> Complex {
+    sinh(z).recip()
+}
+
+/// ### coth(z)
+///
+/// Complex Function
+///
+/// The `coth` function returns the hyperbolic cotangent of a complex number, `1 / tanh(z)`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::{complex, math::{Complex, fix64}};
+/// let z = complex::coth(Complex::new(1.0, 1.0));
+/// assert_eq!((fix64(z.re), fix64(z.im)), (0.86801416, -0.21762156));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn coth(z: Complex) -> Complex {
+    tanh(z).recip()
+}
+
+/// ### asinh(z)
+///
+/// Complex Function
+///
+/// The `asinh` function returns the principal inverse hyperbolic sine of a complex number, `-i·asin(i·z)`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::{complex, math::{Complex, fix64}};
+/// let z = complex::asinh(Complex::new(0.0, 2.0));
+/// assert_eq!((fix64(z.re), fix64(z.im)), (1.316958, 1.5707964));
+/// assert_eq!(complex::asinh(Complex::from(-3.0)), Complex::from((-3.0_f64).asinh()));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn asinh(z: Complex) -> Complex {
+    if z.im == 0.0 {
+        return Complex::new(z.re.asinh(), z.im);
+    }
+    let w = asin(mul_i(z));
+    Complex::new(w.im, -w.re)
+}
+
+/// ### acosh(z)
+///
+/// Complex Function
+///
+/// The `acosh` function returns the principal inverse hyperbolic cosine of a complex number, `ln(z + sqrt(z + 1)·sqrt(z - 1))`,
+/// so arguments below `1` give a complex result (MATLAB's `acosh`).
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::{complex, math::{Complex, fix64}};
+/// let z = complex::acosh(Complex::from(0.5));
+/// assert_eq!((fix64(z.re), fix64(z.im)), (0.0, 1.0471976));
+/// let z = complex::acosh(Complex::from(-2.0));
+/// assert_eq!((fix64(z.re), fix64(z.im)), (1.316958, 3.1415927));
+/// assert_eq!(complex::acosh(Complex::from(2.0)), Complex::from(2.0_f64.acosh()));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn acosh(z: Complex) -> Complex {
+    if z.im == 0.0 && z.re >= 1.0 {
+        return Complex::new(z.re.acosh(), z.im);
+    }
+    ln(z + sqrt(z + 1.0) * sqrt(z - 1.0))
+}
+
+/// ### atanh(z)
+///
+/// Complex Function
+///
+/// The `atanh` function returns the principal inverse hyperbolic tangent of a complex number, `-i·atan(i·z)`,
+/// so arguments outside `(-1, 1)` give a complex result (MATLAB's `atanh`).
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::{complex, math::{Complex, fix64}};
+/// let z = complex::atanh(Complex::from(2.0));
+/// assert_eq!((fix64(z.re), fix64(z.im)), (0.54930615, 1.5707964));
+/// let z = complex::atanh(Complex::from(-2.0));
+/// assert_eq!((fix64(z.re), fix64(z.im)), (-0.54930615, 1.5707964));
+/// assert_eq!(complex::atanh(Complex::from(0.5)), Complex::from(0.5_f64.atanh()));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn atanh(z: Complex) -> Complex {
+    if z.im == 0.0 {
+        if z.re.abs() <= 1.0 {
+            return Complex::new(z.re.atanh(), z.im);
+        }
+        // On the branch cuts the sign of the zero imaginary part selects the side, as for the other inverse functions.
+        return Complex::new(
+            0.5 * ((z.re + 1.0) / (z.re - 1.0)).abs().ln(),
+            H_PI.copysign(z.im),
+        );
+    }
+    let w = atan(mul_i(z));
+    Complex::new(w.im, -w.re)
+}
+
+/// ### asech(z)
+///
+/// Complex Function
+///
+/// The `asech` function returns the principal inverse hyperbolic secant of a complex number, `acosh(1 / z)`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::{complex, math::{Complex, fix64}};
+/// let z = complex::asech(Complex::from(2.0));
+/// assert_eq!((fix64(z.re), fix64(z.im)), (0.0, 1.0471976));
+/// assert_eq!(fix64(complex::asech(Complex::from(0.5)).re), 1.316958);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn asech(z: Complex) -> Complex {
+    acosh(z.recip())
+}
+
+/// ### acsch(z)
+///
+/// Complex Function
+///
+/// The `acsch` function returns the principal inverse hyperbolic cosecant of a complex number, `asinh(1 / z)`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::{complex, math::{Complex, fix64}};
+/// let z = complex::acsch(Complex::new(0.0, 0.5));
+/// assert_eq!((fix64(z.re), fix64(z.im)), (-1.316958, -1.5707964));
+/// assert_eq!(fix64(complex::acsch(Complex::from(1.0)).re), 0.8813736);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn acsch(z: Complex) -> Complex {
+    asinh(z.recip())
+}
+
+/// ### acoth(z)
+///
+/// Complex Function
+///
+/// The `acoth` function returns the principal inverse hyperbolic cotangent of a complex number, `atanh(1 / z)`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::{complex, math::{Complex, fix64}};
+/// let z = complex::acoth(Complex::from(0.5));
+/// assert_eq!((fix64(z.re), fix64(z.im)), (0.54930615, 1.5707964));
+/// assert_eq!(fix64(complex::acoth(Complex::from(2.0)).re), 0.54930615);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn acoth(z: Complex) -> Complex {
+    atanh(z.recip())
+}
+
+/// ### abs_vec(z)
+///
+/// Complex Function
+///
+/// The `abs_vec` function applies `abs` to every element of a slice of complex numbers (`&[Complex]`)
+/// and returns the results as a vector (`Vec<f64>`).
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::{complex, math::{Complex}};
+/// assert_eq!(complex::abs_vec(&[Complex::new(3.0, 4.0), Complex::from(-2.0)]), [5.0, 2.0]);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn abs_vec(z: &[Complex]) -> Vec<f64> {
+    z.iter().map(|&z| abs(z)).collect()
+}
+
+/// ### arg_vec(z)
+///
+/// Complex Function
+///
+/// The `arg_vec` function applies `arg` to every element of a slice of complex numbers (`&[Complex]`)
+/// and returns the results as a vector (`Vec<f64>`).
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::{complex, math::{Complex, H_PI, PI}};
+/// assert_eq!(complex::arg_vec(&[Complex::I, Complex::from(-1.0)]), [H_PI, PI]);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn arg_vec(z: &[Complex]) -> Vec<f64> {
+    z.iter().map(|&z| arg(z)).collect()
+}
+
+/// ### conj_vec(z)
+///
+/// Complex Function
+///
+/// The `conj_vec` function applies `conj` to every element of a slice of complex numbers (`&[Complex]`)
+/// and returns the results as a vector (`Vec<Complex>`).
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::{complex, math::{Complex}};
+/// let z = [Complex::new(1.0, 1.0), Complex::from(-2.0)];
+/// assert_eq!(complex::conj_vec(&z), [complex::conj(z[0]), complex::conj(z[1])]);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn conj_vec(z: &[Complex]) -> Vec<Complex> {
+    z.iter().map(|&z| conj(z)).collect()
+}
+
+/// ### exp_vec(z)
+///
+/// Complex Function
+///
+/// The `exp_vec` function applies `exp` to every element of a slice of complex numbers (`&[Complex]`)
+/// and returns the results as a vector (`Vec<Complex>`).
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::{complex, math::{Complex}};
+/// let z = [Complex::new(1.0, 1.0), Complex::from(-2.0)];
+/// assert_eq!(complex::exp_vec(&z), [complex::exp(z[0]), complex::exp(z[1])]);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn exp_vec(z: &[Complex]) -> Vec<Complex> {
+    z.iter().map(|&z| exp(z)).collect()
+}
+
+/// ### ln_vec(z)
+///
+/// Complex Function
+///
+/// The `ln_vec` function applies `ln` to every element of a slice of complex numbers (`&[Complex]`)
+/// and returns the results as a vector (`Vec<Complex>`).
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::{complex, math::{Complex}};
+/// let z = [Complex::new(1.0, 1.0), Complex::from(-2.0)];
+/// assert_eq!(complex::ln_vec(&z), [complex::ln(z[0]), complex::ln(z[1])]);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn ln_vec(z: &[Complex]) -> Vec<Complex> {
+    z.iter().map(|&z| ln(z)).collect()
+}
+
+/// ### log2_vec(z)
+///
+/// Complex Function
+///
+/// The `log2_vec` function applies `log2` to every element of a slice of complex numbers (`&[Complex]`)
+/// and returns the results as a vector (`Vec<Complex>`).
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::{complex, math::{Complex}};
+/// let z = [Complex::new(1.0, 1.0), Complex::from(-2.0)];
+/// assert_eq!(complex::log2_vec(&z), [complex::log2(z[0]), complex::log2(z[1])]);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn log2_vec(z: &[Complex]) -> Vec<Complex> {
+    z.iter().map(|&z| log2(z)).collect()
+}
+
+/// ### log10_vec(z)
+///
+/// Complex Function
+///
+/// The `log10_vec` function applies `log10` to every element of a slice of complex numbers (`&[Complex]`)
+/// and returns the results as a vector (`Vec<Complex>`).
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::{complex, math::{Complex}};
+/// let z = [Complex::new(1.0, 1.0), Complex::from(-2.0)];
+/// assert_eq!(complex::log10_vec(&z), [complex::log10(z[0]), complex::log10(z[1])]);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn log10_vec(z: &[Complex]) -> Vec<Complex> {
+    z.iter().map(|&z| log10(z)).collect()
+}
+
+/// ### sqrt_vec(z)
+///
+/// Complex Function
+///
+/// The `sqrt_vec` function applies `sqrt` to every element of a slice of complex numbers (`&[Complex]`)
+/// and returns the results as a vector (`Vec<Complex>`).
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::{complex, math::{Complex}};
+/// assert_eq!(
+///     complex::sqrt_vec(&[Complex::from(-1.0), Complex::from(4.0)]),
+///     [Complex::I, Complex::from(2.0)]
+/// );
+/// ```
+/// <small>End Fun Doc</small>
+pub fn sqrt_vec(z: &[Complex]) -> Vec<Complex> {
+    z.iter().map(|&z| sqrt(z)).collect()
+}
+
+/// ### sin_vec(z)
+///
+/// Complex Function
+///
+/// The `sin_vec` function applies `sin` to every element of a slice of complex numbers (`&[Complex]`)
+/// and returns the results as a vector (`Vec<Complex>`).
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::{complex, math::{Complex}};
+/// let z = [Complex::new(1.0, 1.0), Complex::from(-2.0)];
+/// assert_eq!(complex::sin_vec(&z), [complex::sin(z[0]), complex::sin(z[1])]);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn sin_vec(z: &[Complex]) -> Vec<Complex> {
+    z.iter().map(|&z| sin(z)).collect()
+}
+
+/// ### cos_vec(z)
+///
+/// Complex Function
+///
+/// The `cos_vec` function applies `cos` to every element of a slice of complex numbers (`&[Complex]`)
+/// and returns the results as a vector (`Vec<Complex>`).
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::{complex, math::{Complex}};
+/// let z = [Complex::new(1.0, 1.0), Complex::from(-2.0)];
+/// assert_eq!(complex::cos_vec(&z), [complex::cos(z[0]), complex::cos(z[1])]);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn cos_vec(z: &[Complex]) -> Vec<Complex> {
+    z.iter().map(|&z| cos(z)).collect()
+}
+
+/// ### tan_vec(z)
+///
+/// Complex Function
+///
+/// The `tan_vec` function applies `tan` to every element of a slice of complex numbers (`&[Complex]`)
+/// and returns the results as a vector (`Vec<Complex>`).
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::{complex, math::{Complex}};
+/// let z = [Complex::new(1.0, 1.0), Complex::from(-2.0)];
+/// assert_eq!(complex::tan_vec(&z), [complex::tan(z[0]), complex::tan(z[1])]);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn tan_vec(z: &[Complex]) -> Vec<Complex> {
+    z.iter().map(|&z| tan(z)).collect()
+}
+
+/// ### sec_vec(z)
+///
+/// Complex Function
+///
+/// The `sec_vec` function applies `sec` to every element of a slice of complex numbers (`&[Complex]`)
+/// and returns the results as a vector (`Vec<Complex>`).
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::{complex, math::{Complex}};
+/// let z = [Complex::new(1.0, 1.0), Complex::from(-2.0)];
+/// assert_eq!(complex::sec_vec(&z), [complex::sec(z[0]), complex::sec(z[1])]);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn sec_vec(z: &[Complex]) -> Vec<Complex> {
+    z.iter().map(|&z| sec(z)).collect()
+}
+
+/// ### csc_vec(z)
+///
+/// Complex Function
+///
+/// The `csc_vec` function applies `csc` to every element of a slice of complex numbers (`&[Complex]`)
+/// and returns the results as a vector (`Vec<Complex>`).
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::{complex, math::{Complex}};
+/// let z = [Complex::new(1.0, 1.0), Complex::from(-2.0)];
+/// assert_eq!(complex::csc_vec(&z), [complex::csc(z[0]), complex::csc(z[1])]);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn csc_vec(z: &[Complex]) -> Vec<Complex> {
+    z.iter().map(|&z| csc(z)).collect()
+}
+
+/// ### cot_vec(z)
+///
+/// Complex Function
+///
+/// The `cot_vec` function applies `cot` to every element of a slice of complex numbers (`&[Complex]`)
+/// and returns the results as a vector (`Vec<Complex>`).
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::{complex, math::{Complex}};
+/// let z = [Complex::new(1.0, 1.0), Complex::from(-2.0)];
+/// assert_eq!(complex::cot_vec(&z), [complex::cot(z[0]), complex::cot(z[1])]);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn cot_vec(z: &[Complex]) -> Vec<Complex> {
+    z.iter().map(|&z| cot(z)).collect()
+}
+
+/// ### asin_vec(z)
+///
+/// Complex Function
+///
+/// The `asin_vec` function applies `asin` to every element of a slice of complex numbers (`&[Complex]`)
+/// and returns the results as a vector (`Vec<Complex>`).
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::{complex, math::{Complex}};
+/// let z = [Complex::new(1.0, 1.0), Complex::from(-2.0)];
+/// assert_eq!(complex::asin_vec(&z), [complex::asin(z[0]), complex::asin(z[1])]);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn asin_vec(z: &[Complex]) -> Vec<Complex> {
+    z.iter().map(|&z| asin(z)).collect()
+}
+
+/// ### acos_vec(z)
+///
+/// Complex Function
+///
+/// The `acos_vec` function applies `acos` to every element of a slice of complex numbers (`&[Complex]`)
+/// and returns the results as a vector (`Vec<Complex>`).
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::{complex, math::{Complex}};
+/// let z = [Complex::new(1.0, 1.0), Complex::from(-2.0)];
+/// assert_eq!(complex::acos_vec(&z), [complex::acos(z[0]), complex::acos(z[1])]);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn acos_vec(z: &[Complex]) -> Vec<Complex> {
+    z.iter().map(|&z| acos(z)).collect()
+}
+
+/// ### atan_vec(z)
+///
+/// Complex Function
+///
+/// The `atan_vec` function applies `atan` to every element of a slice of complex numbers (`&[Complex]`)
+/// and returns the results as a vector (`Vec<Complex>`).
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::{complex, math::{Complex}};
+/// let z = [Complex::new(1.0, 1.0), Complex::from(-2.0)];
+/// assert_eq!(complex::atan_vec(&z), [complex::atan(z[0]), complex::atan(z[1])]);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn atan_vec(z: &[Complex]) -> Vec<Complex> {
+    z.iter().map(|&z| atan(z)).collect()
+}
+
+/// ### asec_vec(z)
+///
+/// Complex Function
+///
+/// The `asec_vec` function applies `asec` to every element of a slice of complex numbers (`&[Complex]`)
+/// and returns the results as a vector (`Vec<Complex>`).
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::{complex, math::{Complex}};
+/// let z = [Complex::new(1.0, 1.0), Complex::from(-2.0)];
+/// assert_eq!(complex::asec_vec(&z), [complex::asec(z[0]), complex::asec(z[1])]);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn asec_vec(z: &[Complex]) -> Vec<Complex> {
+    z.iter().map(|&z| asec(z)).collect()
+}
+
+/// ### acsc_vec(z)
+///
+/// Complex Function
+///
+/// The `acsc_vec` function applies `acsc` to every element of a slice of complex numbers (`&[Complex]`)
+/// and returns the results as a vector (`Vec<Complex>`).
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::{complex, math::{Complex}};
+/// let z = [Complex::new(1.0, 1.0), Complex::from(-2.0)];
+/// assert_eq!(complex::acsc_vec(&z), [complex::acsc(z[0]), complex::acsc(z[1])]);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn acsc_vec(z: &[Complex]) -> Vec<Complex> {
+    z.iter().map(|&z| acsc(z)).collect()
+}
+
+/// ### acot_vec(z)
+///
+/// Complex Function
+///
+/// The `acot_vec` function applies `acot` to every element of a slice of complex numbers (`&[Complex]`)
+/// and returns the results as a vector (`Vec<Complex>`).
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::{complex, math::{Complex}};
+/// let z = [Complex::new(1.0, 1.0), Complex::from(-2.0)];
+/// assert_eq!(complex::acot_vec(&z), [complex::acot(z[0]), complex::acot(z[1])]);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn acot_vec(z: &[Complex]) -> Vec<Complex> {
+    z.iter().map(|&z| acot(z)).collect()
+}
+
+/// ### sinh_vec(z)
+///
+/// Complex Function
+///
+/// The `sinh_vec` function applies `sinh` to every element of a slice of complex numbers (`&[Complex]`)
+/// and returns the results as a vector (`Vec<Complex>`).
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::{complex, math::{Complex}};
+/// let z = [Complex::new(1.0, 1.0), Complex::from(-2.0)];
+/// assert_eq!(complex::sinh_vec(&z), [complex::sinh(z[0]), complex::sinh(z[1])]);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn sinh_vec(z: &[Complex]) -> Vec<Complex> {
+    z.iter().map(|&z| sinh(z)).collect()
+}
+
+/// ### cosh_vec(z)
+///
+/// Complex Function
+///
+/// The `cosh_vec` function applies `cosh` to every element of a slice of complex numbers (`&[Complex]`)
+/// and returns the results as a vector (`Vec<Complex>`).
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::{complex, math::{Complex}};
+/// let z = [Complex::new(1.0, 1.0), Complex::from(-2.0)];
+/// assert_eq!(complex::cosh_vec(&z), [complex::cosh(z[0]), complex::cosh(z[1])]);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn cosh_vec(z: &[Complex]) -> Vec<Complex> {
+    z.iter().map(|&z| cosh(z)).collect()
+}
+
+/// ### tanh_vec(z)
+///
+/// Complex Function
+///
+/// The `tanh_vec` function applies `tanh` to every element of a slice of complex numbers (`&[Complex]`)
+/// and returns the results as a vector (`Vec<Complex>`).
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::{complex, math::{Complex}};
+/// let z = [Complex::new(1.0, 1.0), Complex::from(-2.0)];
+/// assert_eq!(complex::tanh_vec(&z), [complex::tanh(z[0]), complex::tanh(z[1])]);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn tanh_vec(z: &[Complex]) -> Vec<Complex> {
+    z.iter().map(|&z| tanh(z)).collect()
+}
+
+/// ### sech_vec(z)
+///
+/// Complex Function
+///
+/// The `sech_vec` function applies `sech` to every element of a slice of complex numbers (`&[Complex]`)
+/// and returns the results as a vector (`Vec<Complex>`).
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::{complex, math::{Complex}};
+/// let z = [Complex::new(1.0, 1.0), Complex::from(-2.0)];
+/// assert_eq!(complex::sech_vec(&z), [complex::sech(z[0]), complex::sech(z[1])]);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn sech_vec(z: &[Complex]) -> Vec<Complex> {
+    z.iter().map(|&z| sech(z)).collect()
+}
+
+/// ### csch_vec(z)
+///
+/// Complex Function
+///
+/// The `csch_vec` function applies `csch` to every element of a slice of complex numbers (`&[Complex]`)
+/// and returns the results as a vector (`Vec<Complex>`).
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::{complex, math::{Complex}};
+/// let z = [Complex::new(1.0, 1.0), Complex::from(-2.0)];
+/// assert_eq!(complex::csch_vec(&z), [complex::csch(z[0]), complex::csch(z[1])]);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn csch_vec(z: &[Complex]) -> Vec<Complex> {
+    z.iter().map(|&z| csch(z)).collect()
+}
+
+/// ### coth_vec(z)
+///
+/// Complex Function
+///
+/// The `coth_vec` function applies `coth` to every element of a slice of complex numbers (`&[Complex]`)
+/// and returns the results as a vector (`Vec<Complex>`).
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::{complex, math::{Complex}};
+/// let z = [Complex::new(1.0, 1.0), Complex::from(-2.0)];
+/// assert_eq!(complex::coth_vec(&z), [complex::coth(z[0]), complex::coth(z[1])]);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn coth_vec(z: &[Complex]) -> Vec<Complex> {
+    z.iter().map(|&z| coth(z)).collect()
+}
+
+/// ### asinh_vec(z)
+///
+/// Complex Function
+///
+/// The `asinh_vec` function applies `asinh` to every element of a slice of complex numbers (`&[Complex]`)
+/// and returns the results as a vector (`Vec<Complex>`).
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::{complex, math::{Complex}};
+/// let z = [Complex::new(1.0, 1.0), Complex::from(-2.0)];
+/// assert_eq!(complex::asinh_vec(&z), [complex::asinh(z[0]), complex::asinh(z[1])]);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn asinh_vec(z: &[Complex]) -> Vec<Complex> {
+    z.iter().map(|&z| asinh(z)).collect()
+}
+
+/// ### acosh_vec(z)
+///
+/// Complex Function
+///
+/// The `acosh_vec` function applies `acosh` to every element of a slice of complex numbers (`&[Complex]`)
+/// and returns the results as a vector (`Vec<Complex>`).
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::{complex, math::{Complex}};
+/// let z = [Complex::new(1.0, 1.0), Complex::from(-2.0)];
+/// assert_eq!(complex::acosh_vec(&z), [complex::acosh(z[0]), complex::acosh(z[1])]);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn acosh_vec(z: &[Complex]) -> Vec<Complex> {
+    z.iter().map(|&z| acosh(z)).collect()
+}
+
+/// ### atanh_vec(z)
+///
+/// Complex Function
+///
+/// The `atanh_vec` function applies `atanh` to every element of a slice of complex numbers (`&[Complex]`)
+/// and returns the results as a vector (`Vec<Complex>`).
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::{complex, math::{Complex}};
+/// let z = [Complex::new(1.0, 1.0), Complex::from(-2.0)];
+/// assert_eq!(complex::atanh_vec(&z), [complex::atanh(z[0]), complex::atanh(z[1])]);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn atanh_vec(z: &[Complex]) -> Vec<Complex> {
+    z.iter().map(|&z| atanh(z)).collect()
+}
+
+/// ### asech_vec(z)
+///
+/// Complex Function
+///
+/// The `asech_vec` function applies `asech` to every element of a slice of complex numbers (`&[Complex]`)
+/// and returns the results as a vector (`Vec<Complex>`).
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::{complex, math::{Complex}};
+/// let z = [Complex::new(1.0, 1.0), Complex::from(-2.0)];
+/// assert_eq!(complex::asech_vec(&z), [complex::asech(z[0]), complex::asech(z[1])]);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn asech_vec(z: &[Complex]) -> Vec<Complex> {
+    z.iter().map(|&z| asech(z)).collect()
+}
+
+/// ### acsch_vec(z)
+///
+/// Complex Function
+///
+/// The `acsch_vec` function applies `acsch` to every element of a slice of complex numbers (`&[Complex]`)
+/// and returns the results as a vector (`Vec<Complex>`).
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::{complex, math::{Complex}};
+/// let z = [Complex::new(1.0, 1.0), Complex::from(-2.0)];
+/// assert_eq!(complex::acsch_vec(&z), [complex::acsch(z[0]), complex::acsch(z[1])]);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn acsch_vec(z: &[Complex]) -> Vec<Complex> {
+    z.iter().map(|&z| acsch(z)).collect()
+}
+
+/// ### acoth_vec(z)
+///
+/// Complex Function
+///
+/// The `acoth_vec` function applies `acoth` to every element of a slice of complex numbers (`&[Complex]`)
+/// and returns the results as a vector (`Vec<Complex>`).
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::{complex, math::{Complex}};
+/// let z = [Complex::new(1.0, 1.0), Complex::from(-2.0)];
+/// assert_eq!(complex::acoth_vec(&z), [complex::acoth(z[0]), complex::acoth(z[1])]);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn acoth_vec(z: &[Complex]) -> Vec<Complex> {
+    z.iter().map(|&z| acoth(z)).collect()
+}
@@ -1,3 +1,6 @@
+/// Physical constants
+pub mod physics;
+
 /// ### E
 ///
 /// Mathematical constant
@@ -8,9 +11,25 @@
 /// ```rust
 /// use mathlab::math::E;
 /// assert_eq!(E, 2.718281828459045);
+/// assert_eq!(E, std::f64::consts::E);
+/// ```
+/// <small>End Con Doc</small>
+pub const E: f64 = std::f64::consts::E;
+
+/// ### E_F32
+///
+/// Mathematical constant
+///
+/// The Number e (Euler's number), in `single-precision`
+///
+/// ### Example
+/// ```rust
+/// use mathlab::math::{E, E_F32};
+/// assert_eq!(E_F32, 2.7182817);
+/// assert_eq!(E_F32, E as f32);
 /// ```
 /// <small>End Con Doc</small>
-pub const E: f64 = 2.718281828459045;
+pub const E_F32: f32 = std::f32::consts::E;
 
 /// ### H_PI
 ///
@@ -18,32 +37,60 @@ pub const E: f64 = 2.718281828459045;
 ///
 /// Half Pi (π / 2)
 ///
-/// (3.1415926536 / 2) = 1.5707963268
-///
 /// ### Example
 /// ```rust
 /// use mathlab::math::{H_PI, PI};
+/// assert_eq!(H_PI, 1.5707963267948966);
+/// assert_eq!(H_PI, std::f64::consts::FRAC_PI_2);
 /// assert_eq!(H_PI, PI / 2.0);
-/// assert_eq!(H_PI, 1.5707963268);
 /// ```
 /// <small>End Con Doc</small>
-pub const H_PI: f64 = 1.5707963268;
+pub const H_PI: f64 = std::f64::consts::FRAC_PI_2;
 
-/// ### PI
+/// ### H_PI_F32
 ///
 /// Mathematical constant
 ///
-/// The Number Pi
+/// Half Pi (π / 2), in `single-precision`
 ///
-/// (21.9911485752 / 7) = 3.1415926536
+/// ### Example
+/// ```rust
+/// use mathlab::math::{H_PI, H_PI_F32};
+/// assert_eq!(H_PI_F32, 1.5707964);
+/// assert_eq!(H_PI_F32, H_PI as f32);
+/// ```
+/// <small>End Con Doc</small>
+pub const H_PI_F32: f32 = std::f32::consts::FRAC_PI_2;
+
+/// ### PI
+///
+/// Mathematical constant
+///
+/// The Number Pi (π)
 ///
 /// ### Example
 /// ```rust
 /// use mathlab::math::PI;
-/// assert_eq!(PI, 3.1415926536);
+/// assert_eq!(PI, 3.141592653589793);
+/// assert_eq!(PI, std::f64::consts::PI);
 /// ```
 /// <small>End Con Doc</small>
-pub const PI: f64 = 3.1415926536;
+pub const PI: f64 = std::f64::consts::PI;
+
+/// ### PI_F32
+///
+/// Mathematical constant
+///
+/// The Number Pi (π), in `single-precision`
+///
+/// ### Example
+/// ```rust
+/// use mathlab::math::{PI, PI_F32};
+/// assert_eq!(PI_F32, 3.1415927);
+/// assert_eq!(PI_F32, PI as f32);
+/// ```
+/// <small>End Con Doc</small>
+pub const PI_F32: f32 = std::f32::consts::PI;
 
 /// ### Q_PI
 ///
@@ -51,16 +98,30 @@ pub const PI: f64 = 3.1415926536;
 ///
 /// Quarter Pi (π / 4)
 ///
-/// (3.1415926536 / 4) = 0.7853981634
-///
 /// ### Example
 /// ```rust
 /// use mathlab::math::{Q_PI, PI};
+/// assert_eq!(Q_PI, 0.7853981633974483);
+/// assert_eq!(Q_PI, std::f64::consts::FRAC_PI_4);
 /// assert_eq!(Q_PI, PI / 4.0);
-/// assert_eq!(Q_PI, 0.7853981634);
 /// ```
 /// <small>End Con Doc</small>
-pub const Q_PI: f64 = 0.7853981634;
+pub const Q_PI: f64 = std::f64::consts::FRAC_PI_4;
+
+/// ### Q_PI_F32
+///
+/// Mathematical constant
+///
+/// Quarter Pi (π / 4), in `single-precision`
+///
+/// ### Example
+/// ```rust
+/// use mathlab::math::{Q_PI, Q_PI_F32};
+/// assert_eq!(Q_PI_F32, 0.7853982);
+/// assert_eq!(Q_PI_F32, Q_PI as f32);
+/// ```
+/// <small>End Con Doc</small>
+pub const Q_PI_F32: f32 = std::f32::consts::FRAC_PI_4;
 
 /// ### PHI
 ///
@@ -78,22 +139,51 @@ pub const Q_PI: f64 = 0.7853981634;
 /// <small>End Con Doc</small>
 pub const PHI: f64 = 1.618033988749895;
 
+/// ### PHI_F32
+///
+/// Mathematical constant
+///
+/// The Golden Ratio (Phi), in `single-precision`
+///
+/// ### Example
+/// ```rust
+/// use mathlab::math::{PHI, PHI_F32};
+/// assert_eq!(PHI_F32, 1.618034);
+/// assert_eq!(PHI_F32, PHI as f32);
+/// ```
+/// <small>End Con Doc</small>
+pub const PHI_F32: f32 = 1.618034;
+
 /// ### TAU
 ///
 /// Mathematical constant
 ///
 /// Tau is a circle constant and the value is equivalent to 2π
 ///
-/// (2 * PI) = 6.2831853072
-///
 /// ### Example
 /// ```rust
 /// use mathlab::math::{TAU, PI};
+/// assert_eq!(TAU, 6.283185307179586);
+/// assert_eq!(TAU, std::f64::consts::TAU);
 /// assert_eq!(TAU, 2.0 * PI);
-/// assert_eq!(TAU, 6.2831853072);
 /// ```
 /// <small>End Con Doc</small>
-pub const TAU: f64 = 6.2831853072;
+pub const TAU: f64 = std::f64::consts::TAU;
+
+/// ### TAU_F32
+///
+/// Mathematical constant
+///
+/// Tau is a circle constant and the value is equivalent to 2π, in `single-precision`
+///
+/// ### Example
+/// ```rust
+/// use mathlab::math::{TAU, TAU_F32};
+/// assert_eq!(TAU_F32, 6.2831855);
+/// assert_eq!(TAU_F32, TAU as f32);
+/// ```
+/// <small>End Con Doc</small>
+pub const TAU_F32: f32 = std::f32::consts::TAU;
 
 /// ### LN2
 ///
@@ -104,10 +194,26 @@ pub const TAU: f64 = 6.2831853072;
 /// ### Example
 /// ```rust
 /// use mathlab::math::LN2;
-/// assert_eq!(LN2, 0.693147180559945);
+/// assert_eq!(LN2, 0.6931471805599453);
+/// assert_eq!(LN2, std::f64::consts::LN_2);
 /// ```
 /// <small>End Con Doc</small>
-pub const LN2: f64 = 0.693147180559945;
+pub const LN2: f64 = std::f64::consts::LN_2;
+
+/// ### LN2_F32
+///
+/// Mathematical constant
+///
+/// The natural logarithm of 2, in `single-precision`
+///
+/// ### Example
+/// ```rust
+/// use mathlab::math::{LN2, LN2_F32};
+/// assert_eq!(LN2_F32, 0.6931472);
+/// assert_eq!(LN2_F32, LN2 as f32);
+/// ```
+/// <small>End Con Doc</small>
+pub const LN2_F32: f32 = std::f32::consts::LN_2;
 
 /// ### LN10
 ///
@@ -119,9 +225,25 @@ pub const LN2: f64 = 0.693147180559945;
 /// ```rust
 /// use mathlab::math::LN10;
 /// assert_eq!(LN10, 2.302585092994046);
+/// assert_eq!(LN10, std::f64::consts::LN_10);
 /// ```
 /// <small>End Con Doc</small>
-pub const LN10: f64 = 2.302585092994046;
+pub const LN10: f64 = std::f64::consts::LN_10;
+
+/// ### LN10_F32
+///
+/// Mathematical constant
+///
+/// The natural logarithm of 10, in `single-precision`
+///
+/// ### Example
+/// ```rust
+/// use mathlab::math::{LN10, LN10_F32};
+/// assert_eq!(LN10_F32, 2.3025851);
+/// assert_eq!(LN10_F32, LN10 as f32);
+/// ```
+/// <small>End Con Doc</small>
+pub const LN10_F32: f32 = std::f32::consts::LN_10;
 
 /// ### LOG2E
 ///
@@ -132,10 +254,26 @@ pub const LN10: f64 = 2.302585092994046;
 /// ### Example
 /// ```rust
 /// use mathlab::math::LOG2E;
-/// assert_eq!(LOG2E, 1.442695040888963);
+/// assert_eq!(LOG2E, 1.4426950408889634);
+/// assert_eq!(LOG2E, std::f64::consts::LOG2_E);
 /// ```
 /// <small>End Con Doc</small>
-pub const LOG2E: f64 = 1.442695040888963;
+pub const LOG2E: f64 = std::f64::consts::LOG2_E;
+
+/// ### LOG2E_F32
+///
+/// Mathematical constant
+///
+/// The base 2 logarithm of E, in `single-precision`
+///
+/// ### Example
+/// ```rust
+/// use mathlab::math::{LOG2E, LOG2E_F32};
+/// assert_eq!(LOG2E_F32, 1.442695);
+/// assert_eq!(LOG2E_F32, LOG2E as f32);
+/// ```
+/// <small>End Con Doc</small>
+pub const LOG2E_F32: f32 = std::f32::consts::LOG2_E;
 
 /// ### LOG10E
 ///
@@ -146,10 +284,180 @@ pub const LOG2E: f64 = 1.442695040888963;
 /// ### Example
 /// ```rust
 /// use mathlab::math::LOG10E;
-/// assert_eq!(LOG10E, 0.434294481903252);
+/// assert_eq!(LOG10E, 0.4342944819032518);
+/// assert_eq!(LOG10E, std::f64::consts::LOG10_E);
+/// ```
+/// <small>End Con Doc</small>
+pub const LOG10E: f64 = std::f64::consts::LOG10_E;
+
+/// ### LOG10E_F32
+///
+/// Mathematical constant
+///
+/// The base 10 logarithm of E, in `single-precision`
+///
+/// ### Example
+/// ```rust
+/// use mathlab::math::{LOG10E, LOG10E_F32};
+/// assert_eq!(LOG10E_F32, 0.4342945);
+/// assert_eq!(LOG10E_F32, LOG10E as f32);
+/// ```
+/// <small>End Con Doc</small>
+pub const LOG10E_F32: f32 = std::f32::consts::LOG10_E;
+
+/// ### SQRT_2
+///
+/// Mathematical constant
+///
+/// The square root of 2
+///
+/// ### Example
+/// ```rust
+/// use mathlab::math::SQRT_2;
+/// assert_eq!(SQRT_2, 1.4142135623730951);
+/// assert_eq!(SQRT_2, std::f64::consts::SQRT_2);
+/// ```
+/// <small>End Con Doc</small>
+pub const SQRT_2: f64 = std::f64::consts::SQRT_2;
+
+/// ### SQRT_2_F32
+///
+/// Mathematical constant
+///
+/// The square root of 2, in `single-precision`
+///
+/// ### Example
+/// ```rust
+/// use mathlab::math::{SQRT_2, SQRT_2_F32};
+/// assert_eq!(SQRT_2_F32, 1.4142135);
+/// assert_eq!(SQRT_2_F32, SQRT_2 as f32);
+/// ```
+/// <small>End Con Doc</small>
+pub const SQRT_2_F32: f32 = std::f32::consts::SQRT_2;
+
+/// ### FRAC_1_SQRT_2
+///
+/// Mathematical constant
+///
+/// One over the square root of 2 (1 / √2)
+///
+/// ### Example
+/// ```rust
+/// use mathlab::math::{FRAC_1_SQRT_2, SQRT_2};
+/// assert_eq!(FRAC_1_SQRT_2, 0.7071067811865476);
+/// assert_eq!(FRAC_1_SQRT_2, std::f64::consts::FRAC_1_SQRT_2);
+/// assert!((FRAC_1_SQRT_2 - 1.0 / SQRT_2).abs() < 1e-15);
+/// ```
+/// <small>End Con Doc</small>
+pub const FRAC_1_SQRT_2: f64 = std::f64::consts::FRAC_1_SQRT_2;
+
+/// ### FRAC_1_SQRT_2_F32
+///
+/// Mathematical constant
+///
+/// One over the square root of 2 (1 / √2), in `single-precision`
+///
+/// ### Example
+/// ```rust
+/// use mathlab::math::{FRAC_1_SQRT_2, FRAC_1_SQRT_2_F32};
+/// assert_eq!(FRAC_1_SQRT_2_F32, 0.70710677);
+/// assert_eq!(FRAC_1_SQRT_2_F32, FRAC_1_SQRT_2 as f32);
+/// ```
+/// <small>End Con Doc</small>
+pub const FRAC_1_SQRT_2_F32: f32 = std::f32::consts::FRAC_1_SQRT_2;
+
+/// ### EULER_GAMMA
+///
+/// Mathematical constant
+///
+/// The Euler–Mascheroni constant (γ)
+///
+/// γ = lim (1 + 1/2 + ... + 1/n − ln n) = 0.5772156649015329
+///
+/// ### Example
+/// ```rust
+/// use mathlab::math::EULER_GAMMA;
+/// assert_eq!(EULER_GAMMA, 0.5772156649015329);
+/// ```
+/// <small>End Con Doc</small>
+pub const EULER_GAMMA: f64 = 0.5772156649015329;
+
+/// ### EULER_GAMMA_F32
+///
+/// Mathematical constant
+///
+/// The Euler–Mascheroni constant (γ), in `single-precision`
+///
+/// ### Example
+/// ```rust
+/// use mathlab::math::{EULER_GAMMA, EULER_GAMMA_F32};
+/// assert_eq!(EULER_GAMMA_F32, 0.5772157);
+/// assert_eq!(EULER_GAMMA_F32, EULER_GAMMA as f32);
+/// ```
+/// <small>End Con Doc</small>
+pub const EULER_GAMMA_F32: f32 = 0.5772157;
+
+/// ### CATALAN
+///
+/// Mathematical constant
+///
+/// Catalan's constant (G)
+///
+/// G = 1 − 1/3² + 1/5² − 1/7² + ... = 0.915965594177219
+///
+/// ### Example
+/// ```rust
+/// use mathlab::math::CATALAN;
+/// assert_eq!(CATALAN, 0.915965594177219);
+/// ```
+/// <small>End Con Doc</small>
+pub const CATALAN: f64 = 0.915965594177219;
+
+/// ### CATALAN_F32
+///
+/// Mathematical constant
+///
+/// Catalan's constant (G), in `single-precision`
+///
+/// ### Example
+/// ```rust
+/// use mathlab::math::{CATALAN, CATALAN_F32};
+/// assert_eq!(CATALAN_F32, 0.9159656);
+/// assert_eq!(CATALAN_F32, CATALAN as f32);
+/// ```
+/// <small>End Con Doc</small>
+pub const CATALAN_F32: f32 = 0.9159656;
+
+/// ### APERY
+///
+/// Mathematical constant
+///
+/// Apéry's constant (ζ(3))
+///
+/// ζ(3) = 1 + 1/2³ + 1/3³ + ... = 1.2020569031595942
+///
+/// ### Example
+/// ```rust
+/// use mathlab::math::APERY;
+/// assert_eq!(APERY, 1.2020569031595942);
+/// ```
+/// <small>End Con Doc</small>
+pub const APERY: f64 = 1.2020569031595942;
+
+/// ### APERY_F32
+///
+/// Mathematical constant
+///
+/// Apéry's constant (ζ(3)), in `single-precision`
+///
+/// ### Example
+/// ```rust
+/// use mathlab::math::{APERY, APERY_F32};
+/// assert_eq!(APERY_F32, 1.2020569);
+/// assert_eq!(APERY_F32, APERY as f32);
 /// ```
 /// <small>End Con Doc</small>
-pub const LOG10E: f64 = 0.434294481903252;
+pub const APERY_F32: f32 = 1.2020569;
 
 /// ### NAN_F32
 ///
@@ -0,0 +1,130 @@
+/// ### C
+///
+/// Physical constant
+///
+/// Speed of light in vacuum (c), in m·s⁻¹ (exact)
+///
+/// CODATA 2018 value: 299792458.0
+///
+/// ### Example
+/// ```rust
+/// use mathlab::constants::physics::C;
+/// assert_eq!(C, 299792458.0);
+/// ```
+/// <small>End Con Doc</small>
+pub const C: f64 = 299_792_458.0;
+
+/// ### H
+///
+/// Physical constant
+///
+/// Planck constant (h), in J·s (exact)
+///
+/// CODATA 2018 value: 6.62607015e-34
+///
+/// ### Example
+/// ```rust
+/// use mathlab::constants::physics::H;
+/// assert_eq!(H, 6.62607015e-34);
+/// ```
+/// <small>End Con Doc</small>
+pub const H: f64 = 6.626_070_15e-34;
+
+/// ### HBAR
+///
+/// Physical constant
+///
+/// Reduced Planck constant (ħ = h / 2π), in J·s
+///
+/// CODATA 2018 value: 1.054571817e-34
+///
+/// ### Example
+/// ```rust
+/// use mathlab::math::PI;
+/// use mathlab::constants::physics::{H, HBAR};
+/// assert_eq!(HBAR, 1.054571817e-34);
+/// assert!((HBAR - H / (2.0 * PI)).abs() < 1e-42);
+/// ```
+/// <small>End Con Doc</small>
+pub const HBAR: f64 = 1.054_571_817e-34;
+
+/// ### K_B
+///
+/// Physical constant
+///
+/// Boltzmann constant (k_B), in J·K⁻¹ (exact)
+///
+/// CODATA 2018 value: 1.380649e-23
+///
+/// ### Example
+/// ```rust
+/// use mathlab::constants::physics::K_B;
+/// assert_eq!(K_B, 1.380649e-23);
+/// ```
+/// <small>End Con Doc</small>
+pub const K_B: f64 = 1.380_649e-23;
+
+/// ### N_A
+///
+/// Physical constant
+///
+/// Avogadro constant (N_A), in mol⁻¹ (exact)
+///
+/// CODATA 2018 value: 6.02214076e23
+///
+/// ### Example
+/// ```rust
+/// use mathlab::constants::physics::N_A;
+/// assert_eq!(N_A, 6.02214076e23);
+/// ```
+/// <small>End Con Doc</small>
+pub const N_A: f64 = 6.022_140_76e23;
+
+/// ### G
+///
+/// Physical constant
+///
+/// Newtonian constant of gravitation (G), in m³·kg⁻¹·s⁻² (standard uncertainty 0.00015e-11)
+///
+/// CODATA 2018 value: 6.6743e-11
+///
+/// ### Example
+/// ```rust
+/// use mathlab::constants::physics::G;
+/// assert_eq!(G, 6.6743e-11);
+/// ```
+/// <small>End Con Doc</small>
+pub const G: f64 = 6.674_30e-11;
+
+/// ### E_CHARGE
+///
+/// Physical constant
+///
+/// Elementary charge (e), in C (exact)
+///
+/// CODATA 2018 value: 1.602176634e-19
+///
+/// ### Example
+/// ```rust
+/// use mathlab::constants::physics::E_CHARGE;
+/// assert_eq!(E_CHARGE, 1.602176634e-19);
+/// ```
+/// <small>End Con Doc</small>
+pub const E_CHARGE: f64 = 1.602_176_634e-19;
+
+/// ### R
+///
+/// Physical constant
+///
+/// Molar gas constant (R = N_A · k_B), in J·mol⁻¹·K⁻¹ (exact)
+///
+/// CODATA 2018 value: 8.31446261815324
+///
+/// ### Example
+/// ```rust
+/// use mathlab::constants::physics::{K_B, N_A, R};
+/// assert_eq!(R, 8.31446261815324);
+/// assert!((R - N_A * K_B).abs() < 1e-12);
+/// ```
+/// <small>End Con Doc</small>
+pub const R: f64 = 8.314_462_618_153_24;
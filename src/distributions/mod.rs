@@ -0,0 +1,1291 @@
+use crate::constants::{PI, SQRT_2};
+use crate::error::MathError;
+use crate::functions::num::{betainc, erfc, gammainc, gammaincc, lbeta, lgamma};
+use crate::random::Rng;
+
+/// ### Continuous
+///
+/// Probability Distribution Trait
+///
+/// The `Continuous` trait is implemented by the continuous distributions of this module
+/// (`Normal`, `Uniform`, `Exponential`, `Gamma`, `Beta`, `ChiSquared`, `StudentT` and `FisherF`).
+/// It gives the density, the cumulative distribution function and its inverse, the moments,
+/// and sampling from a seedable `Rng`, so simulations are reproducible.
+///
+/// `inv_cdf` returns `NaN` for probabilities outside `[0, 1]`, like MATLAB's `*inv` functions.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{Rng, fix64};
+/// use mathlab::distributions::{Continuous, Normal};
+/// let n = Normal::new(0.0, 1.0).unwrap();
+/// assert_eq!(fix64(n.pdf(0.0)), 0.3989423);
+/// assert_eq!(fix64(n.cdf(1.96)), 0.9750021);
+/// assert_eq!(fix64(n.inv_cdf(0.975)), 1.959964);
+/// assert_eq!((n.mean(), n.var(), n.std()), (0.0, 1.0, 1.0));
+/// let mut rng = Rng::seed(42);
+/// let xs = n.sample_vec(&mut rng, 10000);
+/// assert!((xs.iter().sum::<f64>() / 10000.0).abs() < 0.05);
+/// ```
+/// <small>End Type Doc</small>
+pub trait Continuous {
+    /// Returns the probability density at `x`.
+    fn pdf(&self, x: f64) -> f64;
+    /// Returns the probability that a sample is at most `x`.
+    fn cdf(&self, x: f64) -> f64;
+    /// Returns the quantile of probability `p`, the `x` with `cdf(x) = p`.
+    fn inv_cdf(&self, p: f64) -> f64;
+    /// Returns the mean of the distribution.
+    fn mean(&self) -> f64;
+    /// Returns the variance of the distribution.
+    fn var(&self) -> f64;
+    /// Draws one sample using `rng`.
+    fn sample(&self, rng: &mut Rng) -> f64;
+
+    /// Returns the standard deviation of the distribution.
+    fn std(&self) -> f64 {
+        self.var().sqrt()
+    }
+
+    /// Draws `n` samples using `rng`.
+    fn sample_vec(&self, rng: &mut Rng, n: usize) -> Vec<f64> {
+        (0..n).map(|_| self.sample(rng)).collect()
+    }
+}
+
+/// ### Discrete
+///
+/// Probability Distribution Trait
+///
+/// The `Discrete` trait is implemented by the discrete distributions of this module (`Binomial` and `Poisson`).
+/// It gives the probability mass function, the cumulative distribution function and its inverse, the moments,
+/// and sampling from a seedable `Rng`.
+///
+/// `inv_cdf` returns the smallest `k` with `cdf(k) >= p` as an `f64`, so that probabilities outside `[0, 1]` give `NaN`
+/// and `p = 1` on an unbounded support gives infinity, like MATLAB's `*inv` functions.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{Rng, fix64};
+/// use mathlab::distributions::{Discrete, Poisson};
+/// let p = Poisson::new(4.0).unwrap();
+/// assert_eq!(fix64(p.pmf(2)), 0.14652511);
+/// assert_eq!(fix64(p.cdf(2)), 0.2381033);
+/// assert_eq!(p.inv_cdf(0.5), 4.0);
+/// assert_eq!((p.mean(), p.var()), (4.0, 4.0));
+/// let mut rng = Rng::seed(1);
+/// let ks = p.sample_vec(&mut rng, 10000);
+/// assert!((ks.iter().sum::<u64>() as f64 / 10000.0 - 4.0).abs() < 0.1);
+/// ```
+/// <small>End Type Doc</small>
+pub trait Discrete {
+    /// Returns the probability of the outcome `k`.
+    fn pmf(&self, k: u64) -> f64;
+    /// Returns the probability that a sample is at most `k`.
+    fn cdf(&self, k: u64) -> f64;
+    /// Returns the smallest `k` with `cdf(k) >= p`.
+    fn inv_cdf(&self, p: f64) -> f64;
+    /// Returns the mean of the distribution.
+    fn mean(&self) -> f64;
+    /// Returns the variance of the distribution.
+    fn var(&self) -> f64;
+    /// Draws one sample using `rng`.
+    fn sample(&self, rng: &mut Rng) -> u64;
+
+    /// Returns the standard deviation of the distribution.
+    fn std(&self) -> f64 {
+        self.var().sqrt()
+    }
+
+    /// Draws `n` samples using `rng`.
+    fn sample_vec(&self, rng: &mut Rng, n: usize) -> Vec<u64> {
+        (0..n).map(|_| self.sample(rng)).collect()
+    }
+}
+
+/// Checks that a distribution parameter is finite and positive.
+fn check_positive(x: f64, msg: &'static str) -> Result<(), MathError> {
+    if x > 0.0 && x.is_finite() {
+        Ok(())
+    } else {
+        Err(MathError::Domain(msg))
+    }
+}
+
+/// Quantile of the standard normal distribution: Acklam's rational approximation,
+/// refined with one Halley step on `erfc` to full double precision.
+fn std_normal_inv(p: f64) -> f64 {
+    const A: [f64; 6] = [
+        -3.969683028665376e1,
+        2.209460984245205e2,
+        -2.759285104469687e2,
+        1.38357751867269e2,
+        -3.066479806614716e1,
+        2.506628277459239e0,
+    ];
+    const B: [f64; 5] = [
+        -5.447609879822406e1,
+        1.615858368580409e2,
+        -1.556989798598866e2,
+        6.680131188771972e1,
+        -1.328068155288572e1,
+    ];
+    const C: [f64; 6] = [
+        -7.784894002430293e-3,
+        -3.223964580411365e-1,
+        -2.400758277161838e0,
+        -2.549732539343734e0,
+        4.374664141464968e0,
+        2.938163982698783e0,
+    ];
+    const D: [f64; 4] = [
+        7.784695709041462e-3,
+        3.224671290700398e-1,
+        2.445134137142996e0,
+        3.754408661907416e0,
+    ];
+    const P_LOW: f64 = 0.02425;
+    if p.is_nan() || !(0.0..=1.0).contains(&p) {
+        return f64::NAN;
+    }
+    if p == 0.0 {
+        return f64::NEG_INFINITY;
+    }
+    if p == 1.0 {
+        return f64::INFINITY;
+    }
+    let tail = |q: f64| {
+        let r = (-2.0 * q.ln()).sqrt();
+        (((((C[0] * r + C[1]) * r + C[2]) * r + C[3]) * r + C[4]) * r + C[5])
+            / ((((D[0] * r + D[1]) * r + D[2]) * r + D[3]) * r + 1.0)
+    };
+    let x = if p < P_LOW {
+        tail(p)
+    } else if p > 1.0 - P_LOW {
+        -tail(1.0 - p)
+    } else {
+        let q = p - 0.5;
+        let r = q * q;
+        (((((A[0] * r + A[1]) * r + A[2]) * r + A[3]) * r + A[4]) * r + A[5]) * q
+            / (((((B[0] * r + B[1]) * r + B[2]) * r + B[3]) * r + B[4]) * r + 1.0)
+    };
+    // Halley step, measuring the error on the smaller tail to keep it relative
+    let (e, sign) = if p < 0.5 {
+        (0.5 * erfc(-x / SQRT_2) - p, 1.0)
+    } else {
+        (0.5 * erfc(x / SQRT_2) - (1.0 - p), -1.0)
+    };
+    let u = sign * e * (2.0 * PI).sqrt() * (x * x / 2.0).exp();
+    x - u / (1.0 + x * u / 2.0)
+}
+
+/// Inverts a continuous `cdf` on the support `[lo, hi]` by Newton's method on `cdf(x) - p`,
+/// falling back to bisection whenever a Newton step leaves the current bracket.
+fn invert_cdf(
+    cdf: impl Fn(f64) -> f64,
+    pdf: impl Fn(f64) -> f64,
+    p: f64,
+    lo: f64,
+    hi: f64,
+    guess: f64,
+) -> f64 {
+    if p.is_nan() || !(0.0..=1.0).contains(&p) {
+        return f64::NAN;
+    }
+    if p == 0.0 {
+        return lo;
+    }
+    if p == 1.0 {
+        return hi;
+    }
+    let (mut a, mut b) = (lo, hi);
+    if b.is_infinite() {
+        b = guess.max(1.0);
+        while cdf(b) < p {
+            a = b;
+            b *= 2.0;
+            if b.is_infinite() {
+                return b;
+            }
+        }
+    }
+    let mut x = if guess > a && guess < b {
+        guess
+    } else {
+        0.5 * (a + b)
+    };
+    for _ in 0..200 {
+        let f = cdf(x) - p;
+        if f == 0.0 {
+            return x;
+        }
+        if f < 0.0 {
+            a = x;
+        } else {
+            b = x;
+        }
+        let step = x - f / pdf(x);
+        let next = if step > a && step < b {
+            step
+        } else {
+            0.5 * (a + b)
+        };
+        if (next - x).abs() <= 4.0 * f64::EPSILON * next.abs() || b - a <= f64::MIN_POSITIVE {
+            return next;
+        }
+        x = next;
+    }
+    x
+}
+
+/// Smallest `k <= max` with `cdf(k) >= p`, or `max` if there is none. The answer is bracketed by steps
+/// doubling away from `guess` (any value, NaN meaning `0`) and then located by bisection.
+fn search_discrete(cdf: impl Fn(u64) -> f64, p: f64, guess: f64, max: u64) -> u64 {
+    let start = if guess.is_nan() {
+        0
+    } else {
+        guess.clamp(0.0, max as f64).floor() as u64
+    };
+    // Invariant once bracketed: cdf(lo) < p <= cdf(hi)
+    let (mut lo, mut hi);
+    let mut step = 1u64;
+    if cdf(start) >= p {
+        hi = start;
+        loop {
+            if hi == 0 {
+                return 0;
+            }
+            let next = hi.saturating_sub(step);
+            if cdf(next) < p {
+                lo = next;
+                break;
+            }
+            hi = next;
+            step = step.saturating_mul(2);
+        }
+    } else {
+        lo = start;
+        loop {
+            if lo == max {
+                return max;
+            }
+            let next = lo.saturating_add(step).min(max);
+            if cdf(next) >= p {
+                hi = next;
+                break;
+            }
+            lo = next;
+            step = step.saturating_mul(2);
+        }
+    }
+    while hi - lo > 1 {
+        let mid = lo + (hi - lo) / 2;
+        if cdf(mid) >= p {
+            hi = mid;
+        } else {
+            lo = mid;
+        }
+    }
+    hi
+}
+
+/// Samples the standard gamma distribution with shape `k` by the Marsaglia–Tsang method,
+/// boosting shapes below one with `U^(1/k)`.
+fn sample_gamma(rng: &mut Rng, k: f64) -> f64 {
+    if k < 1.0 {
+        let u = 1.0 - rng.next_f64();
+        return sample_gamma(rng, k + 1.0) * u.powf(1.0 / k);
+    }
+    let d = k - 1.0 / 3.0;
+    let c = 1.0 / (9.0 * d).sqrt();
+    loop {
+        let z = rng.normal(0.0, 1.0);
+        let v = 1.0 + c * z;
+        if v <= 0.0 {
+            continue;
+        }
+        let v = v * v * v;
+        let u = 1.0 - rng.next_f64();
+        if u.ln() < 0.5 * z * z + d - d * v + d * v.ln() {
+            return d * v;
+        }
+    }
+}
+
+/// Samples the binomial distribution. Large `n` is reduced with beta-distributed order statistics
+/// (Knuth, TAOCP 3.4.1), and the remainder is sampled by counting Bernoulli trials.
+fn sample_binomial(rng: &mut Rng, mut n: u64, mut p: f64) -> u64 {
+    let mut k = 0;
+    while n > 64 {
+        let a = 1 + n / 2;
+        let b = n + 1 - a;
+        let x = Beta::new(a as f64, b as f64)
+            .map(|d| d.sample(rng))
+            .unwrap_or(0.5);
+        if x >= p {
+            n = a - 1;
+            p /= x;
+        } else {
+            k += a;
+            n = b - 1;
+            p = (p - x) / (1.0 - x);
+        }
+    }
+    k + (0..n).filter(|_| rng.next_f64() < p).count() as u64
+}
+
+/// ### Normal
+///
+/// Probability Distribution
+///
+/// The `Normal` type is the normal (Gaussian) distribution with mean `mu` and standard deviation `sigma`
+/// (MATLAB's `normpdf`, `normcdf`, `norminv` and `normrnd`).
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{Rng, fix64};
+/// use mathlab::distributions::{Continuous, Normal};
+/// let n = Normal::new(10.0, 2.0).unwrap();
+/// assert_eq!(n.cdf(10.0), 0.5);
+/// assert_eq!(fix64(n.cdf(12.0)), 0.8413448);
+/// assert_eq!(fix64(n.inv_cdf(0.8413447460685429)), 12.0);
+/// assert_eq!(fix64(Normal::new(0.0, 1.0).unwrap().inv_cdf(1e-10)), -6.361341);
+/// assert!(Normal::new(0.0, -1.0).is_err());
+/// let x = n.sample(&mut Rng::seed(3));
+/// assert!(x.is_finite());
+/// ```
+/// <small>End Type Doc</small>
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Normal {
+    mu: f64,
+    sigma: f64,
+}
+
+impl Normal {
+    /// ### Normal::new(mu, sigma)
+    ///
+    /// Distribution Constructor
+    ///
+    /// Creates the normal distribution with mean `mu` and standard deviation `sigma`.
+    ///
+    /// ### Errors
+    ///
+    /// Returns `MathError::Domain` if `mu` is not finite or `sigma` is not positive.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::distributions::Normal;
+    /// assert!(Normal::new(0.0, 1.0).is_ok());
+    /// assert!(Normal::new(0.0, 0.0).is_err());
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn new(mu: f64, sigma: f64) -> Result<Normal, MathError> {
+        if !mu.is_finite() {
+            return Err(MathError::Domain("mu must be finite"));
+        }
+        check_positive(sigma, "sigma must be positive")?;
+        Ok(Normal { mu, sigma })
+    }
+}
+
+impl Continuous for Normal {
+    fn pdf(&self, x: f64) -> f64 {
+        let z = (x - self.mu) / self.sigma;
+        (-0.5 * z * z).exp() / (self.sigma * (2.0 * PI).sqrt())
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        0.5 * erfc(-(x - self.mu) / (self.sigma * SQRT_2))
+    }
+
+    fn inv_cdf(&self, p: f64) -> f64 {
+        self.mu + self.sigma * std_normal_inv(p)
+    }
+
+    fn mean(&self) -> f64 {
+        self.mu
+    }
+
+    fn var(&self) -> f64 {
+        self.sigma * self.sigma
+    }
+
+    fn sample(&self, rng: &mut Rng) -> f64 {
+        rng.normal(self.mu, self.sigma)
+    }
+}
+
+/// ### Uniform
+///
+/// Probability Distribution
+///
+/// The `Uniform` type is the continuous uniform distribution on `[a, b]`
+/// (MATLAB's `unifpdf`, `unifcdf`, `unifinv` and `unifrnd`).
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::Rng;
+/// use mathlab::distributions::{Continuous, Uniform};
+/// let u = Uniform::new(2.0, 6.0).unwrap();
+/// assert_eq!(u.pdf(3.0), 0.25);
+/// assert_eq!(u.pdf(7.0), 0.0);
+/// assert_eq!(u.cdf(3.0), 0.25);
+/// assert_eq!(u.inv_cdf(0.5), 4.0);
+/// assert_eq!((u.mean(), u.var()), (4.0, 16.0 / 12.0));
+/// assert!((2.0..6.0).contains(&u.sample(&mut Rng::seed(1))));
+/// assert!(Uniform::new(1.0, 1.0).is_err());
+/// ```
+/// <small>End Type Doc</small>
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Uniform {
+    a: f64,
+    b: f64,
+}
+
+impl Uniform {
+    /// ### Uniform::new(a, b)
+    ///
+    /// Distribution Constructor
+    ///
+    /// Creates the uniform distribution on `[a, b]`.
+    ///
+    /// ### Errors
+    ///
+    /// Returns `MathError::Domain` unless `a < b` and both are finite.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::distributions::Uniform;
+    /// assert!(Uniform::new(0.0, 1.0).is_ok());
+    /// assert!(Uniform::new(1.0, 0.0).is_err());
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn new(a: f64, b: f64) -> Result<Uniform, MathError> {
+        if !(a < b && a.is_finite() && b.is_finite()) {
+            return Err(MathError::Domain("a must be less than b"));
+        }
+        Ok(Uniform { a, b })
+    }
+}
+
+impl Continuous for Uniform {
+    fn pdf(&self, x: f64) -> f64 {
+        if (self.a..=self.b).contains(&x) {
+            1.0 / (self.b - self.a)
+        } else {
+            0.0
+        }
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        ((x - self.a) / (self.b - self.a)).clamp(0.0, 1.0)
+    }
+
+    fn inv_cdf(&self, p: f64) -> f64 {
+        if p.is_nan() || !(0.0..=1.0).contains(&p) {
+            return f64::NAN;
+        }
+        self.a + p * (self.b - self.a)
+    }
+
+    fn mean(&self) -> f64 {
+        0.5 * (self.a + self.b)
+    }
+
+    fn var(&self) -> f64 {
+        (self.b - self.a) * (self.b - self.a) / 12.0
+    }
+
+    fn sample(&self, rng: &mut Rng) -> f64 {
+        rng.uniform(self.a, self.b)
+    }
+}
+
+/// ### Exponential
+///
+/// Probability Distribution
+///
+/// The `Exponential` type is the exponential distribution with rate `lambda`, i.e. mean `1 / lambda`
+/// (MATLAB's `exppdf`, `expcdf`, `expinv` and `exprnd`, which take the mean instead).
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{Rng, fix64};
+/// use mathlab::distributions::{Continuous, Exponential};
+/// let e = Exponential::new(0.5).unwrap();
+/// assert_eq!(e.pdf(0.0), 0.5);
+/// assert_eq!(fix64(e.cdf(2.0)), 0.63212055);
+/// assert_eq!(fix64(e.inv_cdf(0.63212055882855767)), 2.0);
+/// assert_eq!((e.mean(), e.var()), (2.0, 4.0));
+/// assert!(e.sample(&mut Rng::seed(1)) >= 0.0);
+/// ```
+/// <small>End Type Doc</small>
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Exponential {
+    lambda: f64,
+}
+
+impl Exponential {
+    /// ### Exponential::new(lambda)
+    ///
+    /// Distribution Constructor
+    ///
+    /// Creates the exponential distribution with rate `lambda`.
+    ///
+    /// ### Errors
+    ///
+    /// Returns `MathError::Domain` if `lambda` is not positive.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::distributions::Exponential;
+    /// assert!(Exponential::new(2.0).is_ok());
+    /// assert!(Exponential::new(0.0).is_err());
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn new(lambda: f64) -> Result<Exponential, MathError> {
+        check_positive(lambda, "lambda must be positive")?;
+        Ok(Exponential { lambda })
+    }
+}
+
+impl Continuous for Exponential {
+    fn pdf(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            0.0
+        } else {
+            self.lambda * (-self.lambda * x).exp()
+        }
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            0.0
+        } else {
+            -(-self.lambda * x).exp_m1()
+        }
+    }
+
+    fn inv_cdf(&self, p: f64) -> f64 {
+        if p.is_nan() || !(0.0..=1.0).contains(&p) {
+            return f64::NAN;
+        }
+        -(-p).ln_1p() / self.lambda
+    }
+
+    fn mean(&self) -> f64 {
+        1.0 / self.lambda
+    }
+
+    fn var(&self) -> f64 {
+        1.0 / (self.lambda * self.lambda)
+    }
+
+    fn sample(&self, rng: &mut Rng) -> f64 {
+        -(1.0 - rng.next_f64()).ln() / self.lambda
+    }
+}
+
+/// ### Gamma
+///
+/// Probability Distribution
+///
+/// The `Gamma` type is the gamma distribution with shape `k` and scale `theta`
+/// (MATLAB's `gampdf`, `gamcdf`, `gaminv` and `gamrnd`). Samples are drawn with the Marsaglia–Tsang method.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{Rng, fix64};
+/// use mathlab::distributions::{Continuous, Gamma};
+/// let g = Gamma::new(2.0, 1.0).unwrap();
+/// assert_eq!(fix64(g.pdf(1.0)), 0.36787945);
+/// assert_eq!(fix64(g.cdf(2.0)), 0.59399414);
+/// assert_eq!(fix64(g.inv_cdf(0.5)), 1.678347);
+/// assert_eq!((g.mean(), g.var()), (2.0, 2.0));
+/// let xs = g.sample_vec(&mut Rng::seed(5), 10000);
+/// assert!((xs.iter().sum::<f64>() / 10000.0 - 2.0).abs() < 0.05);
+/// ```
+/// <small>End Type Doc</small>
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Gamma {
+    k: f64,
+    theta: f64,
+}
+
+impl Gamma {
+    /// ### Gamma::new(k, theta)
+    ///
+    /// Distribution Constructor
+    ///
+    /// Creates the gamma distribution with shape `k` and scale `theta`.
+    ///
+    /// ### Errors
+    ///
+    /// Returns `MathError::Domain` if `k` or `theta` is not positive.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::distributions::Gamma;
+    /// assert!(Gamma::new(0.5, 2.0).is_ok());
+    /// assert!(Gamma::new(-1.0, 2.0).is_err());
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn new(k: f64, theta: f64) -> Result<Gamma, MathError> {
+        check_positive(k, "shape must be positive")?;
+        check_positive(theta, "scale must be positive")?;
+        Ok(Gamma { k, theta })
+    }
+}
+
+impl Continuous for Gamma {
+    fn pdf(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            0.0
+        } else if x == 0.0 {
+            if self.k < 1.0 {
+                f64::INFINITY
+            } else if self.k == 1.0 {
+                1.0 / self.theta
+            } else {
+                0.0
+            }
+        } else {
+            ((self.k - 1.0) * x.ln() - x / self.theta - lgamma(self.k) - self.k * self.theta.ln())
+                .exp()
+        }
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            0.0
+        } else {
+            gammainc(x / self.theta, self.k)
+        }
+    }
+
+    fn inv_cdf(&self, p: f64) -> f64 {
+        invert_cdf(
+            |x| self.cdf(x),
+            |x| self.pdf(x),
+            p,
+            0.0,
+            f64::INFINITY,
+            self.mean(),
+        )
+    }
+
+    fn mean(&self) -> f64 {
+        self.k * self.theta
+    }
+
+    fn var(&self) -> f64 {
+        self.k * self.theta * self.theta
+    }
+
+    fn sample(&self, rng: &mut Rng) -> f64 {
+        sample_gamma(rng, self.k) * self.theta
+    }
+}
+
+/// ### Beta
+///
+/// Probability Distribution
+///
+/// The `Beta` type is the beta distribution on `[0, 1]` with shape parameters `a` and `b`
+/// (MATLAB's `betapdf`, `betacdf`, `betainv` and `betarnd`).
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{Rng, fix64};
+/// use mathlab::distributions::{Beta, Continuous};
+/// let b = Beta::new(2.0, 3.0).unwrap();
+/// assert_eq!(fix64(b.pdf(0.5)), 1.5);
+/// assert_eq!(fix64(b.cdf(0.5)), 0.6875);
+/// assert_eq!(fix64(b.inv_cdf(0.6875)), 0.5);
+/// assert_eq!((b.mean(), b.var()), (0.4, 0.04));
+/// assert!((0.0..=1.0).contains(&b.sample(&mut Rng::seed(2))));
+/// // The density at the endpoints
+/// assert_eq!(Beta::new(1.0, 1.0).unwrap().pdf(0.0), 1.0);
+/// assert_eq!(fix64(Beta::new(2.0, 1.0).unwrap().pdf(1.0)), 2.0);
+/// assert_eq!(Beta::new(0.5, 1.0).unwrap().pdf(0.0), f64::INFINITY);
+/// assert_eq!(b.pdf(0.0), 0.0);
+/// ```
+/// <small>End Type Doc</small>
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Beta {
+    a: f64,
+    b: f64,
+}
+
+impl Beta {
+    /// ### Beta::new(a, b)
+    ///
+    /// Distribution Constructor
+    ///
+    /// Creates the beta distribution with shape parameters `a` and `b`.
+    ///
+    /// ### Errors
+    ///
+    /// Returns `MathError::Domain` if `a` or `b` is not positive.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::distributions::Beta;
+    /// assert!(Beta::new(0.5, 0.5).is_ok());
+    /// assert!(Beta::new(1.0, 0.0).is_err());
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn new(a: f64, b: f64) -> Result<Beta, MathError> {
+        check_positive(a, "a must be positive")?;
+        check_positive(b, "b must be positive")?;
+        Ok(Beta { a, b })
+    }
+}
+
+impl Continuous for Beta {
+    fn pdf(&self, x: f64) -> f64 {
+        if !(0.0..=1.0).contains(&x) {
+            return 0.0;
+        }
+        // A unit shape parameter drops its factor, which would be 0 * ln(0) at the endpoint
+        let log_term = |c: f64, v: f64| if c == 0.0 { 0.0 } else { c * v.ln() };
+        (log_term(self.a - 1.0, x) + log_term(self.b - 1.0, 1.0 - x) - lbeta(self.a, self.b)).exp()
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        betainc(x.clamp(0.0, 1.0), self.a, self.b)
+    }
+
+    fn inv_cdf(&self, p: f64) -> f64 {
+        invert_cdf(|x| self.cdf(x), |x| self.pdf(x), p, 0.0, 1.0, self.mean())
+    }
+
+    fn mean(&self) -> f64 {
+        self.a / (self.a + self.b)
+    }
+
+    fn var(&self) -> f64 {
+        let s = self.a + self.b;
+        self.a * self.b / (s * s * (s + 1.0))
+    }
+
+    fn sample(&self, rng: &mut Rng) -> f64 {
+        let x = sample_gamma(rng, self.a);
+        let y = sample_gamma(rng, self.b);
+        x / (x + y)
+    }
+}
+
+/// ### ChiSquared
+///
+/// Probability Distribution
+///
+/// The `ChiSquared` type is the chi-squared distribution with `nu` degrees of freedom, the gamma distribution
+/// with shape `nu / 2` and scale `2` (MATLAB's `chi2pdf`, `chi2cdf`, `chi2inv` and `chi2rnd`).
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{Rng, fix64};
+/// use mathlab::distributions::{ChiSquared, Continuous};
+/// let c = ChiSquared::new(1.0).unwrap();
+/// assert_eq!(fix64(c.inv_cdf(0.95)), 3.8414588);
+/// assert_eq!(fix64(ChiSquared::new(10.0).unwrap().cdf(10.0)), 0.5595067);
+/// assert_eq!((c.mean(), c.var()), (1.0, 2.0));
+/// assert!(c.sample(&mut Rng::seed(4)) >= 0.0);
+/// ```
+/// <small>End Type Doc</small>
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChiSquared {
+    gamma: Gamma,
+}
+
+impl ChiSquared {
+    /// ### ChiSquared::new(nu)
+    ///
+    /// Distribution Constructor
+    ///
+    /// Creates the chi-squared distribution with `nu` degrees of freedom.
+    ///
+    /// ### Errors
+    ///
+    /// Returns `MathError::Domain` if `nu` is not positive.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::distributions::ChiSquared;
+    /// assert!(ChiSquared::new(3.0).is_ok());
+    /// assert!(ChiSquared::new(0.0).is_err());
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn new(nu: f64) -> Result<ChiSquared, MathError> {
+        check_positive(nu, "degrees of freedom must be positive")?;
+        Ok(ChiSquared {
+            gamma: Gamma::new(nu / 2.0, 2.0)?,
+        })
+    }
+}
+
+impl Continuous for ChiSquared {
+    fn pdf(&self, x: f64) -> f64 {
+        self.gamma.pdf(x)
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        self.gamma.cdf(x)
+    }
+
+    fn inv_cdf(&self, p: f64) -> f64 {
+        self.gamma.inv_cdf(p)
+    }
+
+    fn mean(&self) -> f64 {
+        self.gamma.mean()
+    }
+
+    fn var(&self) -> f64 {
+        self.gamma.var()
+    }
+
+    fn sample(&self, rng: &mut Rng) -> f64 {
+        self.gamma.sample(rng)
+    }
+}
+
+/// ### StudentT
+///
+/// Probability Distribution
+///
+/// The `StudentT` type is Student's t distribution with `nu` degrees of freedom
+/// (MATLAB's `tpdf`, `tcdf`, `tinv` and `trnd`). The mean is `NaN` for `nu <= 1`,
+/// and the variance is infinite for `1 < nu <= 2` and `NaN` for `nu <= 1`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{Rng, fix64};
+/// use mathlab::distributions::{Continuous, StudentT};
+/// let t = StudentT::new(10.0).unwrap();
+/// assert_eq!(t.cdf(0.0), 0.5);
+/// assert_eq!(fix64(t.inv_cdf(0.975)), 2.228139);
+/// assert_eq!(fix64(t.inv_cdf(0.025)), -2.228139);
+/// assert_eq!(fix64(StudentT::new(5.0).unwrap().cdf(2.0)), 0.9490303);
+/// assert_eq!((t.mean(), t.var()), (0.0, 1.25));
+/// assert!(StudentT::new(1.0).unwrap().mean().is_nan());
+/// assert!(t.sample(&mut Rng::seed(6)).is_finite());
+/// ```
+/// <small>End Type Doc</small>
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StudentT {
+    nu: f64,
+}
+
+impl StudentT {
+    /// ### StudentT::new(nu)
+    ///
+    /// Distribution Constructor
+    ///
+    /// Creates Student's t distribution with `nu` degrees of freedom.
+    ///
+    /// ### Errors
+    ///
+    /// Returns `MathError::Domain` if `nu` is not positive.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::distributions::StudentT;
+    /// assert!(StudentT::new(2.5).is_ok());
+    /// assert!(StudentT::new(-2.0).is_err());
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn new(nu: f64) -> Result<StudentT, MathError> {
+        check_positive(nu, "degrees of freedom must be positive")?;
+        Ok(StudentT { nu })
+    }
+}
+
+impl Continuous for StudentT {
+    fn pdf(&self, x: f64) -> f64 {
+        let nu = self.nu;
+        (lgamma((nu + 1.0) / 2.0)
+            - lgamma(nu / 2.0)
+            - 0.5 * (nu * PI).ln()
+            - (nu + 1.0) / 2.0 * (x * x / nu).ln_1p())
+        .exp()
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        if x.is_nan() {
+            return f64::NAN;
+        }
+        let tail = 0.5 * betainc(self.nu / (self.nu + x * x), self.nu / 2.0, 0.5);
+        if x < 0.0 {
+            tail
+        } else {
+            1.0 - tail
+        }
+    }
+
+    fn inv_cdf(&self, p: f64) -> f64 {
+        if p < 0.5 {
+            return -self.inv_cdf(1.0 - p);
+        }
+        invert_cdf(
+            |x| self.cdf(x),
+            |x| self.pdf(x),
+            p,
+            0.0,
+            f64::INFINITY,
+            std_normal_inv(p),
+        )
+    }
+
+    fn mean(&self) -> f64 {
+        if self.nu > 1.0 {
+            0.0
+        } else {
+            f64::NAN
+        }
+    }
+
+    fn var(&self) -> f64 {
+        if self.nu > 2.0 {
+            self.nu / (self.nu - 2.0)
+        } else if self.nu > 1.0 {
+            f64::INFINITY
+        } else {
+            f64::NAN
+        }
+    }
+
+    fn sample(&self, rng: &mut Rng) -> f64 {
+        let z = rng.normal(0.0, 1.0);
+        let v = 2.0 * sample_gamma(rng, self.nu / 2.0);
+        z / (v / self.nu).sqrt()
+    }
+}
+
+/// ### FisherF
+///
+/// Probability Distribution
+///
+/// The `FisherF` type is the F distribution with `d1` numerator and `d2` denominator degrees of freedom
+/// (MATLAB's `fpdf`, `fcdf`, `finv` and `frnd`). The mean is `NaN` for `d2 <= 2` and the variance is `NaN` for `d2 <= 4`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{Rng, fix64};
+/// use mathlab::distributions::{Continuous, FisherF};
+/// let f = FisherF::new(5.0, 10.0).unwrap();
+/// assert_eq!(fix64(f.inv_cdf(0.95)), 3.3258345);
+/// assert_eq!(fix64(f.cdf(3.3258345304130112)), 0.95);
+/// assert_eq!(f.mean(), 1.25);
+/// assert!(f.sample(&mut Rng::seed(7)) >= 0.0);
+/// ```
+/// <small>End Type Doc</small>
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FisherF {
+    d1: f64,
+    d2: f64,
+}
+
+impl FisherF {
+    /// ### FisherF::new(d1, d2)
+    ///
+    /// Distribution Constructor
+    ///
+    /// Creates the F distribution with `d1` and `d2` degrees of freedom.
+    ///
+    /// ### Errors
+    ///
+    /// Returns `MathError::Domain` if `d1` or `d2` is not positive.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::distributions::FisherF;
+    /// assert!(FisherF::new(3.0, 7.0).is_ok());
+    /// assert!(FisherF::new(3.0, 0.0).is_err());
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn new(d1: f64, d2: f64) -> Result<FisherF, MathError> {
+        check_positive(d1, "degrees of freedom must be positive")?;
+        check_positive(d2, "degrees of freedom must be positive")?;
+        Ok(FisherF { d1, d2 })
+    }
+}
+
+impl Continuous for FisherF {
+    fn pdf(&self, x: f64) -> f64 {
+        let (d1, d2) = (self.d1, self.d2);
+        if x < 0.0 {
+            0.0
+        } else if x == 0.0 {
+            if d1 < 2.0 {
+                f64::INFINITY
+            } else if d1 == 2.0 {
+                1.0
+            } else {
+                0.0
+            }
+        } else {
+            (0.5 * (d1 * (d1 * x).ln() + d2 * d2.ln() - (d1 + d2) * (d1 * x + d2).ln())
+                - x.ln()
+                - lbeta(d1 / 2.0, d2 / 2.0))
+            .exp()
+        }
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            0.0
+        } else {
+            betainc(
+                self.d1 * x / (self.d1 * x + self.d2),
+                self.d1 / 2.0,
+                self.d2 / 2.0,
+            )
+        }
+    }
+
+    fn inv_cdf(&self, p: f64) -> f64 {
+        invert_cdf(|x| self.cdf(x), |x| self.pdf(x), p, 0.0, f64::INFINITY, 1.0)
+    }
+
+    fn mean(&self) -> f64 {
+        if self.d2 > 2.0 {
+            self.d2 / (self.d2 - 2.0)
+        } else {
+            f64::NAN
+        }
+    }
+
+    fn var(&self) -> f64 {
+        let (d1, d2) = (self.d1, self.d2);
+        if d2 > 4.0 {
+            2.0 * d2 * d2 * (d1 + d2 - 2.0) / (d1 * (d2 - 2.0) * (d2 - 2.0) * (d2 - 4.0))
+        } else {
+            f64::NAN
+        }
+    }
+
+    fn sample(&self, rng: &mut Rng) -> f64 {
+        let x = 2.0 * sample_gamma(rng, self.d1 / 2.0) / self.d1;
+        let y = 2.0 * sample_gamma(rng, self.d2 / 2.0) / self.d2;
+        x / y
+    }
+}
+
+/// ### Binomial
+///
+/// Probability Distribution
+///
+/// The `Binomial` type is the binomial distribution of the number of successes in `n` independent trials
+/// with success probability `p` (MATLAB's `binopdf`, `binocdf`, `binoinv` and `binornd`).
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{Rng, fix64};
+/// use mathlab::distributions::{Binomial, Discrete};
+/// let b = Binomial::new(10, 0.3).unwrap();
+/// assert_eq!(fix64(b.pmf(3)), 0.26682794);
+/// assert_eq!(fix64(b.cdf(3)), 0.6496107);
+/// assert_eq!(b.cdf(10), 1.0);
+/// assert_eq!(b.inv_cdf(0.5), 3.0);
+/// assert_eq!(fix64(b.mean()), 3.0);
+/// assert!(b.sample(&mut Rng::seed(8)) <= 10);
+/// let big = Binomial::new(1_000_000, 0.5).unwrap();
+/// let k = big.sample(&mut Rng::seed(9));
+/// assert!((k as f64 - 500_000.0).abs() < 5_000.0);
+/// assert_eq!(big.inv_cdf(big.cdf(499_000)), 499_000.0);
+/// // Degenerate success probabilities
+/// let sure = Binomial::new(1_000_000_000, 1.0).unwrap();
+/// assert_eq!((sure.inv_cdf(0.0), sure.inv_cdf(0.5)), (0.0, 1e9));
+/// assert_eq!(Binomial::new(20, 0.0).unwrap().inv_cdf(0.9), 0.0);
+/// ```
+/// <small>End Type Doc</small>
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Binomial {
+    n: u64,
+    p: f64,
+}
+
+impl Binomial {
+    /// ### Binomial::new(n, p)
+    ///
+    /// Distribution Constructor
+    ///
+    /// Creates the binomial distribution with `n` trials and success probability `p`.
+    ///
+    /// ### Errors
+    ///
+    /// Returns `MathError::Domain` if `p` is outside `[0, 1]`.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::distributions::Binomial;
+    /// assert!(Binomial::new(5, 1.0).is_ok());
+    /// assert!(Binomial::new(5, 1.5).is_err());
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn new(n: u64, p: f64) -> Result<Binomial, MathError> {
+        if !(0.0..=1.0).contains(&p) {
+            return Err(MathError::Domain("p must be in [0, 1]"));
+        }
+        Ok(Binomial { n, p })
+    }
+}
+
+impl Discrete for Binomial {
+    fn pmf(&self, k: u64) -> f64 {
+        let (n, p) = (self.n, self.p);
+        if k > n {
+            return 0.0;
+        }
+        if p == 0.0 || p == 1.0 {
+            let certain = if p == 0.0 { 0 } else { n };
+            return if k == certain { 1.0 } else { 0.0 };
+        }
+        let (n, k) = (n as f64, k as f64);
+        (lgamma(n + 1.0) - lgamma(k + 1.0) - lgamma(n - k + 1.0)
+            + k * p.ln()
+            + (n - k) * (-p).ln_1p())
+        .exp()
+    }
+
+    fn cdf(&self, k: u64) -> f64 {
+        if k >= self.n || self.p == 0.0 {
+            1.0
+        } else if self.p == 1.0 {
+            0.0
+        } else {
+            betainc(1.0 - self.p, (self.n - k) as f64, k as f64 + 1.0)
+        }
+    }
+
+    fn inv_cdf(&self, p: f64) -> f64 {
+        if p.is_nan() || !(0.0..=1.0).contains(&p) {
+            return f64::NAN;
+        }
+        if p == 0.0 || self.p == 0.0 {
+            return 0.0;
+        }
+        if p == 1.0 || self.p == 1.0 {
+            return self.n as f64;
+        }
+        let guess = self.mean() + self.std() * std_normal_inv(p);
+        search_discrete(|k| self.cdf(k), p, guess, self.n) as f64
+    }
+
+    fn mean(&self) -> f64 {
+        self.n as f64 * self.p
+    }
+
+    fn var(&self) -> f64 {
+        self.n as f64 * self.p * (1.0 - self.p)
+    }
+
+    fn sample(&self, rng: &mut Rng) -> u64 {
+        sample_binomial(rng, self.n, self.p)
+    }
+}
+
+/// ### Poisson
+///
+/// Probability Distribution
+///
+/// The `Poisson` type is the Poisson distribution with mean `lambda`
+/// (MATLAB's `poisspdf`, `poisscdf`, `poissinv` and `poissrnd`).
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{Rng, fix64};
+/// use mathlab::distributions::{Discrete, Poisson};
+/// let p = Poisson::new(2.5).unwrap();
+/// assert_eq!(fix64(p.pmf(0)), fix64((-2.5_f64).exp()));
+/// assert_eq!(fix64(p.cdf(4)), 0.891178);
+/// assert_eq!(p.inv_cdf(0.9), 5.0);
+/// assert_eq!(p.inv_cdf(1.0), f64::INFINITY);
+/// let big = Poisson::new(1e6).unwrap();
+/// assert!((big.sample(&mut Rng::seed(10)) as f64 - 1e6).abs() < 1e4);
+/// ```
+/// <small>End Type Doc</small>
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Poisson {
+    lambda: f64,
+}
+
+impl Poisson {
+    /// ### Poisson::new(lambda)
+    ///
+    /// Distribution Constructor
+    ///
+    /// Creates the Poisson distribution with mean `lambda`.
+    ///
+    /// ### Errors
+    ///
+    /// Returns `MathError::Domain` if `lambda` is not positive.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::distributions::Poisson;
+    /// assert!(Poisson::new(0.1).is_ok());
+    /// assert!(Poisson::new(0.0).is_err());
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn new(lambda: f64) -> Result<Poisson, MathError> {
+        check_positive(lambda, "lambda must be positive")?;
+        Ok(Poisson { lambda })
+    }
+}
+
+impl Discrete for Poisson {
+    fn pmf(&self, k: u64) -> f64 {
+        let k = k as f64;
+        (k * self.lambda.ln() - self.lambda - lgamma(k + 1.0)).exp()
+    }
+
+    fn cdf(&self, k: u64) -> f64 {
+        gammaincc(self.lambda, k as f64 + 1.0)
+    }
+
+    fn inv_cdf(&self, p: f64) -> f64 {
+        if p.is_nan() || !(0.0..=1.0).contains(&p) {
+            return f64::NAN;
+        }
+        if p == 1.0 {
+            return f64::INFINITY;
+        }
+        let guess = self.lambda + self.lambda.sqrt() * std_normal_inv(p);
+        search_discrete(|k| self.cdf(k), p, guess, u64::MAX) as f64
+    }
+
+    fn mean(&self) -> f64 {
+        self.lambda
+    }
+
+    fn var(&self) -> f64 {
+        self.lambda
+    }
+
+    // Knuth's multiplication method for small means; large means are reduced
+    // with a gamma-distributed arrival time (Ahrens–Dieter).
+    fn sample(&self, rng: &mut Rng) -> u64 {
+        let mut lambda = self.lambda;
+        let mut k = 0;
+        while lambda > 16.0 {
+            let m = (0.875 * lambda).floor();
+            let x = sample_gamma(rng, m);
+            if x < lambda {
+                k += m as u64;
+                lambda -= x;
+            } else {
+                return k + sample_binomial(rng, m as u64 - 1, lambda / x);
+            }
+        }
+        let limit = (-lambda).exp();
+        let mut prod = rng.next_f64();
+        while prod > limit {
+            k += 1;
+            prod *= rng.next_f64();
+        }
+        k
+    }
+}
@@ -0,0 +1,77 @@
+use std::fmt;
+
+/// ### MathError
+///
+/// Error Type
+///
+/// The `MathError` enum describes why a fallible `mathlab` function could not produce a result,
+/// so that bad input can be reported instead of panicking or returning silently wrong data.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{Matrix, MathError};
+/// use mathlab::linalg::inv;
+/// let a = Matrix::from_rows(&[[1.0, 2.0], [2.0, 4.0]]);
+/// assert_eq!(inv(&a), Err(MathError::Singular));
+/// assert_eq!(MathError::Singular.to_string(), "matrix is singular");
+/// assert_eq!(MathError::EmptyInput.to_string(), "input is empty");
+/// ```
+/// <small>End Type Doc</small>
+#[derive(Debug, Clone, PartialEq)]
+pub enum MathError {
+    /// Two operands do not have compatible sizes.
+    DimensionMismatch { expected: usize, found: usize },
+    /// A square matrix was required.
+    NotSquare { rows: usize, cols: usize },
+    /// The matrix is singular (or numerically singular) and cannot be inverted.
+    Singular,
+    /// A symmetric matrix was required.
+    NotSymmetric,
+    /// A symmetric positive definite matrix was required.
+    NotPositiveDefinite,
+    /// An iterative algorithm did not converge within its iteration limit.
+    NoConvergence { iterations: usize },
+    /// An argument lies outside the domain of the function.
+    Domain(&'static str),
+    /// The result does not fit in the return type.
+    Overflow,
+    /// A non-empty input was required.
+    EmptyInput,
+    /// The requested output size is larger than the allowed maximum.
+    LimitExceeded { limit: usize, requested: usize },
+}
+
+impl fmt::Display for MathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MathError::DimensionMismatch { expected, found } => {
+                write!(
+                    f,
+                    "dimension mismatch: expected {}, found {}",
+                    expected, found
+                )
+            }
+            MathError::NotSquare { rows, cols } => {
+                write!(f, "matrix must be square, found {}x{}", rows, cols)
+            }
+            MathError::Singular => write!(f, "matrix is singular"),
+            MathError::NotSymmetric => write!(f, "matrix must be symmetric"),
+            MathError::NotPositiveDefinite => write!(f, "matrix must be positive definite"),
+            MathError::NoConvergence { iterations } => {
+                write!(f, "no convergence after {} iterations", iterations)
+            }
+            MathError::Domain(reason) => write!(f, "domain error: {}", reason),
+            MathError::Overflow => write!(f, "arithmetic overflow"),
+            MathError::EmptyInput => write!(f, "input is empty"),
+            MathError::LimitExceeded { limit, requested } => {
+                write!(
+                    f,
+                    "requested size {} exceeds the limit of {}",
+                    requested, limit
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for MathError {}
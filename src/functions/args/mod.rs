@@ -1,4 +1,9 @@
-use super::num::{fix, fix64};
+use super::num::{checked_nchoosek, fix, fix64, ln_fact};
+use crate::error::MathError;
+use crate::random::with_thread_rng;
+
+/// The largest number of elements the generating functions will produce.
+const MAX_LEN: usize = 1_000_000;
 
 /// ### cross(a, b)
 ///
@@ -22,6 +27,7 @@ use super::num::{fix, fix64};
 /// ### Panics
 ///
 /// This function will panic if the slices `a` and `b` are not of length 3, due to the `assert_eq!` checks.
+/// Use `try_cross` to get an error instead.
 ///
 /// ### Usage
 ///
@@ -65,6 +71,36 @@ pub fn cross(a: &[f64], b: &[f64]) -> Vec<f64> {
     ]
 }
 
+/// ### try_cross(a, b)
+///
+/// **Mathematical vector operation**
+///
+/// The `try_cross` function calculates the cross product of two 3-dimensional vectors like `cross`,
+/// but returns an error instead of panicking when a slice is not of length 3.
+///
+/// ### Errors
+///
+/// Returns `MathError::DimensionMismatch` if `a` or `b` is not of length 3.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{try_cross, MathError};
+/// assert_eq!(try_cross(&[1.0, 0.0, 0.0], &[0.0, 1.0, 0.0]), Ok(vec![0.0, 0.0, 1.0]));
+/// assert_eq!(try_cross(&[1.0, 0.0], &[0.0, 1.0, 0.0]), Err(MathError::DimensionMismatch { expected: 3, found: 2 }));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn try_cross(a: &[f64], b: &[f64]) -> Result<Vec<f64>, MathError> {
+    for v in [a, b] {
+        if v.len() != 3 {
+            return Err(MathError::DimensionMismatch {
+                expected: 3,
+                found: v.len(),
+            });
+        }
+    }
+    Ok(cross(a, b))
+}
+
 /// ### dot(a, b)
 ///
 /// **Mathematical vector operation**
@@ -74,7 +110,7 @@ pub fn cross(a: &[f64], b: &[f64]) -> Vec<f64> {
 /// ### Panics
 ///
 /// This function will panic if the lengths of the slices `a` and `b` are not equal,
-/// due to the `assert_eq!` check.
+/// due to the `assert_eq!` check. Use `try_dot` to get an error instead.
 ///
 /// ### Usage
 ///
@@ -97,6 +133,34 @@ pub fn dot(a: &[f64], b: &[f64]) -> f64 {
     a.iter().zip(b.iter()).map(|(x, y)| x * y).sum()
 }
 
+/// ### try_dot(a, b)
+///
+/// **Mathematical vector operation**
+///
+/// The `try_dot` function calculates the dot product of two slices like `dot`,
+/// but returns an error instead of panicking when their lengths differ.
+///
+/// ### Errors
+///
+/// Returns `MathError::DimensionMismatch` if `a` and `b` are not of the same length.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{try_dot, MathError};
+/// assert_eq!(try_dot(&[0.0, 1.0, 2.0, -1.0], &[3.0, 4.0, 5.0, 5.0]), Ok(9.0));
+/// assert_eq!(try_dot(&[1.0, 2.0], &[1.0]), Err(MathError::DimensionMismatch { expected: 2, found: 1 }));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn try_dot(a: &[f64], b: &[f64]) -> Result<f64, MathError> {
+    if a.len() != b.len() {
+        return Err(MathError::DimensionMismatch {
+            expected: a.len(),
+            found: b.len(),
+        });
+    }
+    Ok(dot(a, b))
+}
+
 /// ### monolist(x, size)
 ///
 /// Generating function
@@ -117,7 +181,7 @@ pub fn dot(a: &[f64], b: &[f64]) -> f64 {
 /// ```
 /// <small>End Fun Doc</small>
 pub fn monolist(x: f64, mut size: usize) -> Vec<f64> {
-    if size > 1_000_000 {
+    if size > MAX_LEN {
         size = 0
     }
     let mut vector = Vec::with_capacity(size);
@@ -127,6 +191,35 @@ pub fn monolist(x: f64, mut size: usize) -> Vec<f64> {
     vector
 }
 
+/// ### try_monolist(x, size)
+///
+/// Generating function
+///
+/// The `try_monolist` function generates a list of `size` copies of `x` like `monolist`,
+/// but returns an error instead of an empty list when `size` exceeds 1 million.
+///
+/// ### Errors
+///
+/// Returns `MathError::LimitExceeded` if `size` is greater than 1,000,000.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{try_monolist, MathError};
+/// assert_eq!(try_monolist(0.1 + 0.2, 2), Ok(vec![0.3, 0.3]));
+/// assert_eq!(try_monolist(0.1, 0), Ok(vec![]));
+/// assert_eq!(try_monolist(0.1, 1000001), Err(MathError::LimitExceeded { limit: 1000000, requested: 1000001 }));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn try_monolist(x: f64, size: usize) -> Result<Vec<f64>, MathError> {
+    if size > MAX_LEN {
+        return Err(MathError::LimitExceeded {
+            limit: MAX_LEN,
+            requested: size,
+        });
+    }
+    Ok(monolist(x, size))
+}
+
 /// ### range(x, step, size, order)
 ///
 /// Generating function
@@ -169,7 +262,7 @@ pub fn monolist(x: f64, mut size: usize) -> Vec<f64> {
 /// ```
 /// <small>End Fun Doc</small>
 pub fn range(x: f64, step: f64, mut size: usize, order: &str) -> Vec<f64> {
-    if size > 1_000_000 || step <= 0.0 {
+    if size > MAX_LEN || step <= 0.0 {
         size = 0
     }
     let mut vector = Vec::with_capacity(size);
@@ -185,6 +278,45 @@ pub fn range(x: f64, step: f64, mut size: usize, order: &str) -> Vec<f64> {
     vector
 }
 
+/// ### try_range(x, step, size, order)
+///
+/// Generating function
+///
+/// The `try_range` function generates `size` numbers starting from `x` like `range`,
+/// but returns an error instead of an empty vector when the arguments are invalid.
+///
+/// ### Errors
+///
+/// - `MathError::Domain` if `step` is not positive or `order` is not "asc" or "desc".
+/// - `MathError::LimitExceeded` if `size` is greater than 1,000,000.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{try_range, MathError};
+/// assert_eq!(try_range(0.0, 0.1, 4, "asc"), Ok(vec![0.0, 0.1, 0.2, 0.3]));
+/// assert_eq!(try_range(4.0, 2.0, 3, "desc"), Ok(vec![4.0, 2.0, 0.0]));
+/// assert_eq!(try_range(0.0, 0.1, 0, "asc"), Ok(vec![]));
+/// assert!(matches!(try_range(0.0, 0.1, 10, "abcd"), Err(MathError::Domain(_))));
+/// assert!(matches!(try_range(0.0, 0.0, 10, "asc"), Err(MathError::Domain(_))));
+/// assert_eq!(try_range(0.0, 0.1, 1000000000, "asc"), Err(MathError::LimitExceeded { limit: 1000000, requested: 1000000000 }));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn try_range(x: f64, step: f64, size: usize, order: &str) -> Result<Vec<f64>, MathError> {
+    if order != "asc" && order != "desc" {
+        return Err(MathError::Domain("order must be \"asc\" or \"desc\""));
+    }
+    if step.is_nan() || step <= 0.0 {
+        return Err(MathError::Domain("step must be positive"));
+    }
+    if size > MAX_LEN {
+        return Err(MathError::LimitExceeded {
+            limit: MAX_LEN,
+            requested: size,
+        });
+    }
+    Ok(range(x, step, size, order))
+}
+
 /// ### range_from_to(from, to, step)
 ///
 /// Generating function
@@ -259,7 +391,7 @@ pub fn range_from_to(from: f64, to: f64, step: f64) -> Vec<f64> {
     // Calculate the number of steps
     let steps = ((to - from) / step_sign).abs().ceil() as usize;
 
-    if steps > 1_000_000 {
+    if steps > MAX_LEN {
         return Vec::new();
     }
 
@@ -269,6 +401,44 @@ pub fn range_from_to(from: f64, to: f64, step: f64) -> Vec<f64> {
         .collect()
 }
 
+/// ### try_range_from_to(from, to, step)
+///
+/// Generating function
+///
+/// The `try_range_from_to` function generates a sequence from `from` to `to` like `range_from_to`,
+/// but returns an error instead of an empty vector when the arguments are invalid.
+///
+/// ### Errors
+///
+/// - `MathError::Domain` if `step` is not positive or `from` or `to` is not finite.
+/// - `MathError::LimitExceeded` if the sequence would have more than 1,000,000 steps.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{try_range_from_to, MathError, INF_F64};
+/// assert_eq!(try_range_from_to(1.0, 0.0, 0.25), Ok(vec![1.0, 0.75, 0.5, 0.25, 0.0]));
+/// assert!(matches!(try_range_from_to(0.0, 1.0, -0.1), Err(MathError::Domain(_))));
+/// assert!(matches!(try_range_from_to(0.0, INF_F64, 1.0), Err(MathError::Domain(_))));
+/// assert_eq!(try_range_from_to(0.0, 1000000.0, 0.5), Err(MathError::LimitExceeded { limit: 1000000, requested: 2000000 }));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn try_range_from_to(from: f64, to: f64, step: f64) -> Result<Vec<f64>, MathError> {
+    if step.is_nan() || step <= 0.0 {
+        return Err(MathError::Domain("step must be positive"));
+    }
+    if !from.is_finite() || !to.is_finite() {
+        return Err(MathError::Domain("bounds must be finite"));
+    }
+    let steps = ((to - from) / step).abs().ceil();
+    if steps > MAX_LEN as f64 {
+        return Err(MathError::LimitExceeded {
+            limit: MAX_LEN,
+            requested: steps as usize,
+        });
+    }
+    Ok(range_from_to(from, to, step))
+}
+
 /// ### to_fixed(x, decimal_places)
 ///
 /// Fixation Function
@@ -323,6 +493,195 @@ pub fn hypot(x: &[f64]) -> f64 {
     }
 }
 
+/// ### try_hypot(x)
+///
+/// Geometric Function
+///
+/// The `try_hypot` function calculates the Euclidean norm of a vector like `hypot`,
+/// but returns an error instead of `NAN` when the vector is empty.
+///
+/// ### Errors
+///
+/// Returns `MathError::EmptyInput` if `x` is empty.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{try_hypot, MathError};
+/// assert_eq!(try_hypot(&[3.0, 4.0]), Ok(5.0));
+/// assert_eq!(try_hypot(&[]), Err(MathError::EmptyInput));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn try_hypot(x: &[f64]) -> Result<f64, MathError> {
+    if x.is_empty() {
+        return Err(MathError::EmptyInput);
+    }
+    Ok(hypot(x))
+}
+
+/// ### vecnorm(x, p)
+///
+/// Geometric Function
+///
+/// The `vecnorm` function calculates the p-norm `(sum |x_i|^p)^(1/p)` of a vector, like MATLAB's `norm(v, p)`.
+/// `p = INF_F64` gives the largest absolute value and `p = -INF_F64` the smallest; `p = 2.0` matches `hypot`.
+/// An empty vector has a norm of `0.0`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{vecnorm, fix64, INF_F64 as inf};
+/// assert_eq!(vecnorm(&[3.0, -4.0], 1.0), 7.0);
+/// assert_eq!(vecnorm(&[3.0, -4.0], 2.0), 5.0);
+/// assert_eq!(vecnorm(&[3.0, -4.0], inf), 4.0);
+/// assert_eq!(vecnorm(&[3.0, -4.0], -inf), 3.0);
+/// assert_eq!(fix64(vecnorm(&[1.0, 2.0, 3.0], 3.0)), 3.3019273);
+/// assert_eq!(vecnorm(&[], 2.0), 0.0);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn vecnorm(x: &[f64], p: f64) -> f64 {
+    if x.is_empty() {
+        0.0
+    } else if p == f64::INFINITY {
+        x.iter().fold(0.0, |m, v| m.max(v.abs()))
+    } else if p == f64::NEG_INFINITY {
+        x.iter().fold(f64::INFINITY, |m, v| m.min(v.abs()))
+    } else if p == 1.0 {
+        x.iter().map(|v| v.abs()).sum()
+    } else if p == 2.0 {
+        x.iter().map(|v| v * v).sum::<f64>().sqrt()
+    } else {
+        x.iter().map(|v| v.abs().powf(p)).sum::<f64>().powf(1.0 / p)
+    }
+}
+
+/// ### checked_multinomial(k)
+///
+/// Combinatorics Function
+///
+/// The `checked_multinomial` function returns the multinomial coefficient `(k1 + k2 + ... + km)! / (k1! k2! ... km!)`,
+/// the number of ways to split `k1 + ... + km` items into groups of sizes `k1, ..., km`, as `Some(M)`,
+/// or `None` when it does not fit in a `u64`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::checked_multinomial;
+/// assert_eq!(checked_multinomial(&[2, 1, 1]), Some(12));
+/// assert_eq!(checked_multinomial(&[3, 2]), Some(10));
+/// assert_eq!(checked_multinomial(&[]), Some(1));
+/// assert_eq!(checked_multinomial(&[40, 40]), None);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn checked_multinomial(k: &[u64]) -> Option<u64> {
+    // Product of binomials C(k1 + ... + ki, ki)
+    let mut total: u64 = 0;
+    let mut result: u64 = 1;
+    for &ki in k {
+        total = total.checked_add(ki)?;
+        result = result.checked_mul(checked_nchoosek(total, ki)?)?;
+    }
+    Some(result)
+}
+
+/// ### multinomial(k)
+///
+/// Combinatorics Function
+///
+/// The `multinomial` function returns the multinomial coefficient `(k1 + k2 + ... + km)! / (k1! k2! ... km!)`.
+/// The result is exact while it fits in a `u64`, and is computed from `ln_fact` beyond that.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{multinomial, fix64};
+/// assert_eq!(multinomial(&[2, 1, 1]), 12.0);
+/// assert_eq!(multinomial(&[5]), 1.0);
+/// assert_eq!(fix64(multinomial(&[40, 40])), 1.0750721e23);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn multinomial(k: &[u64]) -> f64 {
+    match checked_multinomial(k) {
+        Some(m) => m as f64,
+        None => {
+            let n: f64 = k.iter().map(|&ki| ki as f64).sum();
+            let ln_m = k
+                .iter()
+                .fold(ln_fact(n), |acc, &ki| acc - ln_fact(ki as f64));
+            ln_m.exp().round()
+        }
+    }
+}
+
+/// ### combinations(v, k)
+///
+/// Combinatorics Function
+///
+/// The `combinations` function returns every combination of `k` elements of `v`, in lexicographic order of their positions
+/// (MATLAB's `nchoosek(v, k)`). There are `nchoosek(v.len(), k)` of them; no combinations are returned when `k > v.len()`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::combinations;
+/// assert_eq!(
+///     combinations(&[1, 2, 3, 4], 2),
+///     [[1, 2], [1, 3], [1, 4], [2, 3], [2, 4], [3, 4]]
+/// );
+/// assert_eq!(combinations(&["a", "b", "c"], 3), [["a", "b", "c"]]);
+/// assert_eq!(combinations(&[1.0, 2.0], 0), [Vec::<f64>::new()]);
+/// assert!(combinations(&[1, 2], 3).is_empty());
+/// ```
+/// <small>End Fun Doc</small>
+pub fn combinations<T: Clone>(v: &[T], k: usize) -> Vec<Vec<T>> {
+    let n = v.len();
+    if k > n {
+        return Vec::new();
+    }
+    let mut result = Vec::new();
+    let mut idx: Vec<usize> = (0..k).collect();
+    loop {
+        result.push(idx.iter().map(|&i| v[i].clone()).collect());
+        // Advance the rightmost index that can still move
+        let Some(i) = (0..k).rev().find(|&i| idx[i] < n - k + i) else {
+            return result;
+        };
+        idx[i] += 1;
+        for j in i + 1..k {
+            idx[j] = idx[j - 1] + 1;
+        }
+    }
+}
+
+/// ### perms(v)
+///
+/// Combinatorics Function
+///
+/// The `perms` function returns every permutation of the elements of `v` (MATLAB's `perms(v)`),
+/// in reverse lexicographic order of their positions, so the first permutation is `v` reversed.
+/// There are `v.len()!` of them, so `v` should be short (10 elements already give 3,628,800 permutations).
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::perms;
+/// assert_eq!(
+///     perms(&[1, 2, 3]),
+///     [[3, 2, 1], [3, 1, 2], [2, 3, 1], [2, 1, 3], [1, 3, 2], [1, 2, 3]]
+/// );
+/// assert_eq!(perms(&['a']), [['a']]);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn perms<T: Clone>(v: &[T]) -> Vec<Vec<T>> {
+    let n = v.len();
+    // Start from the reversed order and step to the previous permutation of the positions
+    let mut idx: Vec<usize> = (0..n).rev().collect();
+    let mut result = Vec::new();
+    loop {
+        result.push(idx.iter().map(|&i| v[i].clone()).collect());
+        let Some(i) = (1..n).rev().find(|&i| idx[i - 1] > idx[i]) else {
+            return result;
+        };
+        let j = (i..n).rev().find(|&j| idx[j] < idx[i - 1]).unwrap_or(i);
+        idx.swap(i - 1, j);
+        idx[i..].reverse();
+    }
+}
+
 /////////////////// RAND FUNCTION ///////////////////
 
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
@@ -424,6 +783,9 @@ pub fn formatted() -> String {
 /// The function will cap the `size` at 19 if requested larger to ensure it fits within the
 /// limits of `u64`, preventing overflow.
 ///
+/// The numbers are drawn uniformly from the current thread's `Rng`, which is seeded from the system clock;
+/// call `mathlab::random::seed` first to make the results reproducible.
+///
 /// ### Parameters
 /// - `size`: The desired number of digits for the random number. Must be greater than 0.
 ///
@@ -443,6 +805,15 @@ pub fn formatted() -> String {
 ///    println!("Random number (size 15): {:?}", rand(15));
 ///    println!("Random number (size 19): {:?}", rand(19));
 ///    println!("Random number (size 19, requested 25): {:?}", rand(25)); // Size capped at 19
+///
+///    assert_eq!(rand(0), 0);
+///    assert!((100..1000).contains(&rand(3)));
+///
+///    // Reseeding makes the sequence reproducible
+///    mathlab::random::seed(2024);
+///    let a = rand(10);
+///    mathlab::random::seed(2024);
+///    assert_eq!(rand(10), a);
 /// }
 /// ```
 /// <small>End Fun Doc</small>
@@ -459,24 +830,9 @@ pub fn rand(mut size: usize) -> u64 {
     let min = 10u64.pow((size - 1) as u32); // 10^(size - 1)
     let max = 10u64.pow(size as u32) - 1; // 10^size - 1
 
-    // Generate a seed using system time
-    let nanos = get_nanos();
-    let millis = get_millis();
-    let seconds = get_second();
-
-    // Create a complex random seed using sine and different time-derived components
-    let time_combined = (nanos as f64) + ((millis as f64) * 1e-3) + ((seconds as f64) * 1e-6);
-
-    // Use sine function to create some variability
-    let sine_value = (time_combined * std::f64::consts::PI * 0.1).sin() * 1e19; // Scale to 19-digit range
-
-    // Combine the sine value into the final random seed
-    let random_seed = (sine_value.abs() as u64) % (max - min + 1);
-
-    // Calculate the final random number
-    let random_number = min + random_seed;
-
-    random_number
+    // Draw uniformly from [0, max - min] and shift into range
+    let offset = with_thread_rng(|rng| rng.int_range(0, (max - min) as i64));
+    min + offset as u64
 }
 
 /////////////////// END RAND FUNCTION ///////////////////
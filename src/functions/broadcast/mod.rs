@@ -0,0 +1,252 @@
+use super::num::{add, divi, mult, nrt, perimeter, pow, rem, subt};
+use super::vec_vec::check_len;
+use crate::error::MathError;
+use crate::real::Real;
+
+/// ### broadcast(x, y, f)
+///
+/// Operation Function
+///
+/// The `broadcast` function applies a binary function `f` element-wise to `x` and `y` with MATLAB/NumPy-style broadcasting:
+/// slices of equal length are combined pairwise, and a slice of length 1 is repeated to match the other operand.
+/// Scalars can be passed as one-element slices, so the same call covers the `vec_vec`, `vec_num` and `num_vec` cases.
+///
+/// ### Errors
+///
+/// Returns `MathError::DimensionMismatch` if the lengths differ and neither of them is 1.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{broadcast, MathError};
+/// assert_eq!(broadcast(&[1.0, 2.0, 3.0], &[10.0, 20.0, 30.0], |a, b| a + b), Ok(vec![11.0, 22.0, 33.0]));
+/// assert_eq!(broadcast(&[1.0, 2.0, 3.0], &[10.0], |a, b| a * b), Ok(vec![10.0, 20.0, 30.0]));
+/// assert_eq!(broadcast(&[10.0], &[1.0, 2.0, 3.0], |a, b| a - b), Ok(vec![9.0, 8.0, 7.0]));
+/// assert_eq!(broadcast(&[1.0, 2.0], &[1.0, 2.0, 3.0], |a, b| a + b), Err(MathError::DimensionMismatch { expected: 2, found: 3 }));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn broadcast<T, F>(x: &[T], y: &[T], f: F) -> Result<Vec<T>, MathError>
+where
+    T: Copy,
+    F: Fn(T, T) -> T,
+{
+    match (x.len(), y.len()) {
+        (n, m) if n == m => Ok(x.iter().zip(y.iter()).map(|(&a, &b)| f(a, b)).collect()),
+        (1, _) => Ok(y.iter().map(|&b| f(x[0], b)).collect()),
+        (_, 1) => Ok(x.iter().map(|&a| f(a, y[0])).collect()),
+        (n, m) => Err(MathError::DimensionMismatch {
+            expected: n,
+            found: m,
+        }),
+    }
+}
+
+/// ### elementwise(x, y, f)
+///
+/// Operation Function
+///
+/// The `elementwise` function applies a binary function `f` to corresponding elements of `x` and `y` in strict mode:
+/// unlike `broadcast`, the two slices must have exactly the same length, and length-1 operands are not repeated.
+///
+/// ### Errors
+///
+/// Returns `MathError::DimensionMismatch` if `x` and `y` are not of the same length.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{elementwise, MathError};
+/// assert_eq!(elementwise(&[1.0, 2.0], &[3.0, 4.0], f64::max), Ok(vec![3.0, 4.0]));
+/// assert_eq!(elementwise(&[1.0, 2.0], &[3.0], f64::max), Err(MathError::DimensionMismatch { expected: 2, found: 1 }));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn elementwise<T, F>(x: &[T], y: &[T], f: F) -> Result<Vec<T>, MathError>
+where
+    T: Copy,
+    F: Fn(T, T) -> T,
+{
+    check_len(x, y)?;
+    Ok(x.iter().zip(y.iter()).map(|(&a, &b)| f(a, b)).collect())
+}
+
+/// ### add_broadcast(x, y)
+///
+/// Operation Function
+///
+/// The `add_broadcast` function adds `x` and `y` element-wise using the add function, broadcasting length-1 operands like `broadcast`.
+/// Use `try_add_vec_vec` for the strict mode that requires equal lengths.
+///
+/// ### Errors
+///
+/// Returns `MathError::DimensionMismatch` if the lengths differ and neither of them is 1.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{add_broadcast, MathError};
+/// assert_eq!(add_broadcast(&[1.0, 2.0], &[3.0, 4.0]), Ok(vec![4.0, 6.0]));
+/// assert_eq!(add_broadcast(&[1.0, 2.0], &[0.5]), Ok(vec![1.5, 2.5]));
+/// assert_eq!(add_broadcast(&[1.0, 2.0], &[1.0, 2.0, 3.0]), Err(MathError::DimensionMismatch { expected: 2, found: 3 }));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn add_broadcast<T: Real>(x: &[T], y: &[T]) -> Result<Vec<T>, MathError> {
+    broadcast(x, y, add)
+}
+
+/// ### subt_broadcast(x, y)
+///
+/// Operation Function
+///
+/// The `subt_broadcast` function subtracts `y` from `x` element-wise using the subt function, broadcasting length-1 operands like `broadcast`.
+/// Use `try_subt_vec_vec` for the strict mode that requires equal lengths.
+///
+/// ### Errors
+///
+/// Returns `MathError::DimensionMismatch` if the lengths differ and neither of them is 1.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{subt_broadcast, MathError};
+/// assert_eq!(subt_broadcast(&[1.0, 2.0], &[3.0, 5.0]), Ok(vec![-2.0, -3.0]));
+/// assert_eq!(subt_broadcast(&[10.0], &[1.0, 2.0]), Ok(vec![9.0, 8.0]));
+/// assert_eq!(subt_broadcast(&[1.0, 2.0], &[1.0, 2.0, 3.0]), Err(MathError::DimensionMismatch { expected: 2, found: 3 }));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn subt_broadcast<T: Real>(x: &[T], y: &[T]) -> Result<Vec<T>, MathError> {
+    broadcast(x, y, subt)
+}
+
+/// ### mult_broadcast(x, y)
+///
+/// Operation Function
+///
+/// The `mult_broadcast` function multiplies `x` and `y` element-wise using the mult function, broadcasting length-1 operands like `broadcast`.
+/// Use `try_mult_vec_vec` for the strict mode that requires equal lengths.
+///
+/// ### Errors
+///
+/// Returns `MathError::DimensionMismatch` if the lengths differ and neither of them is 1.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{mult_broadcast, MathError};
+/// assert_eq!(mult_broadcast(&[1.0, 2.0], &[3.0, 4.0]), Ok(vec![3.0, 8.0]));
+/// assert_eq!(mult_broadcast(&[2.0], &[1.0, 2.0, 3.0]), Ok(vec![2.0, 4.0, 6.0]));
+/// assert_eq!(mult_broadcast(&[1.0, 2.0], &[1.0, 2.0, 3.0]), Err(MathError::DimensionMismatch { expected: 2, found: 3 }));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn mult_broadcast<T: Real>(x: &[T], y: &[T]) -> Result<Vec<T>, MathError> {
+    broadcast(x, y, mult)
+}
+
+/// ### divi_broadcast(x, y)
+///
+/// Operation Function
+///
+/// The `divi_broadcast` function divides `x` by `y` element-wise using the divi function, broadcasting length-1 operands like `broadcast`.
+/// Use `try_divi_vec_vec` for the strict mode that requires equal lengths.
+///
+/// ### Errors
+///
+/// Returns `MathError::DimensionMismatch` if the lengths differ and neither of them is 1.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{divi_broadcast, MathError};
+/// assert_eq!(divi_broadcast(&[3.0, 2.0], &[3.0, 4.0]), Ok(vec![1.0, 0.5]));
+/// assert_eq!(divi_broadcast(&[1.0, 2.0], &[4.0]), Ok(vec![0.25, 0.5]));
+/// assert_eq!(divi_broadcast(&[1.0, 2.0], &[1.0, 2.0, 3.0]), Err(MathError::DimensionMismatch { expected: 2, found: 3 }));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn divi_broadcast<T: Real>(x: &[T], y: &[T]) -> Result<Vec<T>, MathError> {
+    broadcast(x, y, divi)
+}
+
+/// ### pow_broadcast(x, y)
+///
+/// Operation Function
+///
+/// The `pow_broadcast` function raises `x` to the powers in `y` element-wise using the pow function, broadcasting length-1 operands like `broadcast`.
+/// Use `try_pow_vec_vec` for the strict mode that requires equal lengths.
+///
+/// ### Errors
+///
+/// Returns `MathError::DimensionMismatch` if the lengths differ and neither of them is 1.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{pow_broadcast, MathError};
+/// assert_eq!(pow_broadcast(&[2.0, 3.0], &[3.0, 2.0]), Ok(vec![8.0, 9.0]));
+/// assert_eq!(pow_broadcast(&[2.0], &[0.0, 1.0, 2.0]), Ok(vec![1.0, 2.0, 4.0]));
+/// assert_eq!(pow_broadcast(&[1.0, 2.0], &[1.0, 2.0, 3.0]), Err(MathError::DimensionMismatch { expected: 2, found: 3 }));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn pow_broadcast<T: Real>(x: &[T], y: &[T]) -> Result<Vec<T>, MathError> {
+    broadcast(x, y, pow)
+}
+
+/// ### rem_broadcast(x, y)
+///
+/// Operation Function
+///
+/// The `rem_broadcast` function computes the remainders of `x` divided by `y` element-wise using the rem function, broadcasting length-1 operands like `broadcast`.
+/// Use `try_rem_vec_vec` for the strict mode that requires equal lengths.
+///
+/// ### Errors
+///
+/// Returns `MathError::DimensionMismatch` if the lengths differ and neither of them is 1.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{rem_broadcast, MathError};
+/// assert_eq!(rem_broadcast(&[5.0, 7.0], &[3.0, 4.0]), Ok(vec![2.0, 3.0]));
+/// assert_eq!(rem_broadcast(&[5.0, 7.0, 9.0], &[2.0]), Ok(vec![1.0, 1.0, 1.0]));
+/// assert_eq!(rem_broadcast(&[1.0, 2.0], &[1.0, 2.0, 3.0]), Err(MathError::DimensionMismatch { expected: 2, found: 3 }));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn rem_broadcast<T: Real>(x: &[T], y: &[T]) -> Result<Vec<T>, MathError> {
+    broadcast(x, y, rem)
+}
+
+/// ### nrt_broadcast(x, n)
+///
+/// Operation Function
+///
+/// The `nrt_broadcast` function computes the `n-th` roots of `x` element-wise using the nrt function, broadcasting length-1 operands like `broadcast`.
+/// Use `try_nrt_vec_vec` for the strict mode that requires equal lengths.
+///
+/// ### Errors
+///
+/// Returns `MathError::DimensionMismatch` if the lengths differ and neither of them is 1.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{nrt_broadcast, MathError};
+/// assert_eq!(nrt_broadcast(&[4.0, 27.0], &[2.0, 3.0]), Ok(vec![2.0, 3.0]));
+/// assert_eq!(nrt_broadcast(&[4.0, 9.0], &[2.0]), Ok(vec![2.0, 3.0]));
+/// assert_eq!(nrt_broadcast(&[1.0, 2.0], &[1.0, 2.0, 3.0]), Err(MathError::DimensionMismatch { expected: 2, found: 3 }));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn nrt_broadcast<T: Real>(x: &[T], n: &[T]) -> Result<Vec<T>, MathError> {
+    broadcast(x, n, nrt)
+}
+
+/// ### perimeter_broadcast(x, y)
+///
+/// Geometry Function
+///
+/// The `perimeter_broadcast` function computes the rectangle perimeters of `x` and `y` element-wise using the perimeter function, broadcasting length-1 operands like `broadcast`.
+/// Use `try_perimeter_vec_vec` for the strict mode that requires equal lengths.
+///
+/// ### Errors
+///
+/// Returns `MathError::DimensionMismatch` if the lengths differ and neither of them is 1.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{perimeter_broadcast, MathError};
+/// assert_eq!(perimeter_broadcast(&[1.0, 2.0], &[1.0, 1.0]), Ok(vec![4.0, 6.0]));
+/// assert_eq!(perimeter_broadcast(&[1.0], &[0.0, 1.0]), Ok(vec![2.0, 4.0]));
+/// assert_eq!(perimeter_broadcast(&[1.0, 2.0], &[1.0, 2.0, 3.0]), Err(MathError::DimensionMismatch { expected: 2, found: 3 }));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn perimeter_broadcast<T: Real>(x: &[T], y: &[T]) -> Result<Vec<T>, MathError> {
+    broadcast(x, y, perimeter)
+}
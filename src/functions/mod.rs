@@ -1,11 +1,20 @@
+//! Elementwise mathematical functions on numbers and vectors.
+//!
+//! The trigonometric and hyperbolic functions here, and the angle conversions `deg_to_rad` and `rad_to_deg`,
+//! round their results for display, so that for example `sin(PI)` is exactly `0.0`.
+//! The [`precise`] module has the same functions returning the unrounded IEEE value.
+
 pub mod args;
+pub mod broadcast;
 pub mod num;
 pub mod num_vec;
+pub mod precise;
 pub mod vec;
 pub mod vec_num;
 pub mod vec_vec;
 
 pub use args::*;
+pub use broadcast::*;
 pub use num::*;
 pub use num_vec::*;
 pub use vec::*;
@@ -1,4 +1,5 @@
 use super::num::{add, divi, mult, nrt, perimeter, pow, rem, subt};
+use crate::real::Real;
 
 /// ### add_num_vec(x, y)
 ///
@@ -16,7 +17,7 @@ use super::num::{add, divi, mult, nrt, perimeter, pow, rem, subt};
 /// assert_eq!(fround_vec(&add_num_vec(0.1, &[0.0, 0.1, 0.2])), [0.1, 0.2, 0.3]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn add_num_vec(x: f64, y: &[f64]) -> Vec<f64> {
+pub fn add_num_vec<T: Real>(x: T, y: &[T]) -> Vec<T> {
     y.iter().map(|&y| add(x, y)).collect()
 }
 
@@ -36,7 +37,7 @@ pub fn add_num_vec(x: f64, y: &[f64]) -> Vec<f64> {
 /// assert_eq!(fround_vec(&subt_num_vec(0.1, &[0.0, 0.1, 0.2, 0.3])), [0.1, 0.0, -0.1, -0.2]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn subt_num_vec(x: f64, y: &[f64]) -> Vec<f64> {
+pub fn subt_num_vec<T: Real>(x: T, y: &[T]) -> Vec<T> {
     y.iter().map(|&y| subt(x, y)).collect()
 }
 
@@ -56,7 +57,7 @@ pub fn subt_num_vec(x: f64, y: &[f64]) -> Vec<f64> {
 /// assert_eq!(fround_vec(&mult_num_vec(0.1, &[0.0, 0.1, 0.2])), [0.0, 0.01, 0.02]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn mult_num_vec(x: f64, y: &[f64]) -> Vec<f64> {
+pub fn mult_num_vec<T: Real>(x: T, y: &[T]) -> Vec<T> {
     y.iter().map(|&y| mult(x, y)).collect()
 }
 
@@ -76,7 +77,7 @@ pub fn mult_num_vec(x: f64, y: &[f64]) -> Vec<f64> {
 /// //assert_eq!(fround_vec(&divi_num_vec(0.1, &[0.0, 0.1, 0.2, 0.3])), [inf, 2.0, 1.0, 0.6666667]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn divi_num_vec(x: f64, y: &[f64]) -> Vec<f64> {
+pub fn divi_num_vec<T: Real>(x: T, y: &[T]) -> Vec<T> {
     y.iter().map(|&y| divi(x, y)).collect()
 }
 
@@ -95,7 +96,7 @@ pub fn divi_num_vec(x: f64, y: &[f64]) -> Vec<f64> {
 /// assert_eq!(pow_num_vec(2.0, &[-3.0, 0.0, 4.0, INF_F64]), [0.125, 1.0, 16.0, INF_F64]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn pow_num_vec(x: f64, y: &[f64]) -> Vec<f64> {
+pub fn pow_num_vec<T: Real>(x: T, y: &[T]) -> Vec<T> {
     y.iter().map(|&y| pow(x, y)).collect()
 }
 
@@ -117,7 +118,7 @@ pub fn pow_num_vec(x: f64, y: &[f64]) -> Vec<f64> {
 /// assert_eq!(rem_num_vec(3.0, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0]), [0.0, 1.0, 0.0, 3.0, 3.0, 3.0, 3.0]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn rem_num_vec(x: f64, y: &[f64]) -> Vec<f64> {
+pub fn rem_num_vec<T: Real>(x: T, y: &[T]) -> Vec<T> {
     y.iter().map(|&y| rem(x, y)).collect()
 }
 
@@ -135,7 +136,7 @@ pub fn rem_num_vec(x: f64, y: &[f64]) -> Vec<f64> {
 /// assert_eq!(nrt_num_vec(81.0, &[1.0, 2.0, 4.0]), [81.0, 9.0, 3.0]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn nrt_num_vec(x: f64, n: &[f64]) -> Vec<f64> {
+pub fn nrt_num_vec<T: Real>(x: T, n: &[T]) -> Vec<T> {
     n.iter().map(|&n| nrt(x, n)).collect()
 }
 
@@ -151,6 +152,6 @@ pub fn nrt_num_vec(x: f64, n: &[f64]) -> Vec<f64> {
 /// assert_eq!(perimeter_num_vec(1.0, &[0.0, 1.0, 2.0, inf]), [2.0, 4.0, 6.0, inf]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn perimeter_num_vec(x: f64, y: &[f64]) -> Vec<f64> {
+pub fn perimeter_num_vec<T: Real>(x: T, y: &[T]) -> Vec<T> {
     y.iter().map(|&y| perimeter(x, y)).collect()
 }
@@ -0,0 +1,847 @@
+//! Trigonometric and hyperbolic functions and angle conversions without display rounding.
+//!
+//! Each function mirrors the one of the same name in `functions::num`, but returns the unrounded IEEE result
+//! for `f32` or `f64`, as the standard library would.
+
+use crate::real::Real;
+
+/// Converts degrees to radians in the precision of `T`.
+fn deg<T: Real>() -> T {
+    T::from_f64(std::f64::consts::PI / 180.0)
+}
+
+/// Converts radians to degrees in the precision of `T`.
+fn rad<T: Real>() -> T {
+    T::from_f64(180.0 / std::f64::consts::PI)
+}
+
+/// ### deg_to_rad(x)
+///
+/// Conversion Function
+///
+/// The precise `deg_to_rad` function converts an angle from degrees to radians.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::functions::precise::deg_to_rad;
+/// assert_eq!(deg_to_rad(180.0), std::f64::consts::PI);
+/// assert_eq!(deg_to_rad(90.0_f32), std::f32::consts::FRAC_PI_2);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn deg_to_rad<T: Real>(x: T) -> T {
+    x * deg::<T>()
+}
+
+/// ### rad_to_deg(x)
+///
+/// Conversion Function
+///
+/// The precise `rad_to_deg` function converts an angle from radians to degrees.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::functions::precise::rad_to_deg;
+/// assert_eq!(rad_to_deg(std::f64::consts::PI), 180.0);
+/// assert_eq!(rad_to_deg(1.0), 57.29577951308232);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn rad_to_deg<T: Real>(x: T) -> T {
+    x * rad::<T>()
+}
+
+/// ### sin(x)
+///
+/// Trigonometric Function
+///
+/// The precise `sin` function returns the sine of `x` (in radians).
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::functions::precise::sin;
+/// assert_eq!(sin(1.0), 1.0_f64.sin());
+/// assert_eq!(sin(std::f64::consts::PI), 1.2246467991473532e-16);
+/// assert_eq!(mathlab::math::sin(std::f64::consts::PI), 0.0); // rounded display mode
+/// ```
+/// <small>End Fun Doc</small>
+pub fn sin<T: Real>(x: T) -> T {
+    x.sin()
+}
+
+/// ### sin_deg(x)
+///
+/// Trigonometric Function
+///
+/// The precise `sin_deg` function returns the sine of `x` (in degrees).
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::functions::precise::sin_deg;
+/// assert_eq!(sin_deg(90.0), 1.0);
+/// assert_eq!(sin_deg(30.0), 0.49999999999999994);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn sin_deg<T: Real>(x: T) -> T {
+    (x * deg::<T>()).sin()
+}
+
+/// ### cos(x)
+///
+/// Trigonometric Function
+///
+/// The precise `cos` function returns the cosine of `x` (in radians).
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::functions::precise::cos;
+/// assert_eq!(cos(0.0), 1.0);
+/// assert_eq!(cos(1.0), 1.0_f64.cos());
+/// assert_eq!(cos(std::f64::consts::FRAC_PI_2), 6.123233995736766e-17);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn cos<T: Real>(x: T) -> T {
+    x.cos()
+}
+
+/// ### cos_deg(x)
+///
+/// Trigonometric Function
+///
+/// The precise `cos_deg` function returns the cosine of `x` (in degrees).
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::functions::precise::cos_deg;
+/// assert_eq!(cos_deg(0.0), 1.0);
+/// assert_eq!(cos_deg(60.0), 0.5000000000000001);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn cos_deg<T: Real>(x: T) -> T {
+    (x * deg::<T>()).cos()
+}
+
+/// ### tan(x)
+///
+/// Trigonometric Function
+///
+/// The precise `tan` function returns the tangent of `x` (in radians).
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::functions::precise::tan;
+/// assert_eq!(tan(0.0), 0.0);
+/// assert_eq!(tan(1.0), 1.0_f64.tan());
+/// ```
+/// <small>End Fun Doc</small>
+pub fn tan<T: Real>(x: T) -> T {
+    x.tan()
+}
+
+/// ### tan_deg(x)
+///
+/// Trigonometric Function
+///
+/// The precise `tan_deg` function returns the tangent of `x` (in degrees).
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::functions::precise::tan_deg;
+/// assert_eq!(tan_deg(0.0), 0.0);
+/// assert_eq!(tan_deg(45.0), 0.9999999999999999);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn tan_deg<T: Real>(x: T) -> T {
+    (x * deg::<T>()).tan()
+}
+
+/// ### csc(x)
+///
+/// Trigonometric Function
+///
+/// The precise `csc` function returns the cosecant `1 / sin(x)` of `x` (in radians).
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::functions::precise::csc;
+/// assert_eq!(csc(1.0), 1.0 / 1.0_f64.sin());
+/// assert_eq!(csc(0.0), f64::INFINITY);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn csc<T: Real>(x: T) -> T {
+    x.sin().recip()
+}
+
+/// ### csc_deg(x)
+///
+/// Trigonometric Function
+///
+/// The precise `csc_deg` function returns the cosecant of `x` (in degrees).
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::functions::precise::csc_deg;
+/// assert_eq!(csc_deg(90.0), 1.0);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn csc_deg<T: Real>(x: T) -> T {
+    (x * deg::<T>()).sin().recip()
+}
+
+/// ### sec(x)
+///
+/// Trigonometric Function
+///
+/// The precise `sec` function returns the secant `1 / cos(x)` of `x` (in radians).
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::functions::precise::sec;
+/// assert_eq!(sec(0.0), 1.0);
+/// assert_eq!(sec(1.0), 1.0 / 1.0_f64.cos());
+/// ```
+/// <small>End Fun Doc</small>
+pub fn sec<T: Real>(x: T) -> T {
+    x.cos().recip()
+}
+
+/// ### sec_deg(x)
+///
+/// Trigonometric Function
+///
+/// The precise `sec_deg` function returns the secant of `x` (in degrees).
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::functions::precise::sec_deg;
+/// assert_eq!(sec_deg(0.0), 1.0);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn sec_deg<T: Real>(x: T) -> T {
+    (x * deg::<T>()).cos().recip()
+}
+
+/// ### cot(x)
+///
+/// Trigonometric Function
+///
+/// The precise `cot` function returns the cotangent `1 / tan(x)` of `x` (in radians).
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::functions::precise::cot;
+/// assert_eq!(cot(1.0), 1.0 / 1.0_f64.tan());
+/// assert_eq!(cot(0.0), f64::INFINITY);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn cot<T: Real>(x: T) -> T {
+    x.tan().recip()
+}
+
+/// ### cot_deg(x)
+///
+/// Trigonometric Function
+///
+/// The precise `cot_deg` function returns the cotangent of `x` (in degrees).
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::functions::precise::cot_deg;
+/// assert_eq!(cot_deg(45.0), 1.0000000000000002);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn cot_deg<T: Real>(x: T) -> T {
+    (x * deg::<T>()).tan().recip()
+}
+
+/// ### asin(x)
+///
+/// Inverse Trigonometric Function
+///
+/// The precise `asin` function returns the arcsine of `x` in radians.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::functions::precise::asin;
+/// assert_eq!(asin(1.0), std::f64::consts::FRAC_PI_2);
+/// assert!(asin(2.0_f64).is_nan());
+/// ```
+/// <small>End Fun Doc</small>
+pub fn asin<T: Real>(x: T) -> T {
+    x.asin()
+}
+
+/// ### asin_deg(x)
+///
+/// Inverse Trigonometric Function
+///
+/// The precise `asin_deg` function returns the arcsine of `x` in degrees.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::functions::precise::asin_deg;
+/// assert_eq!(asin_deg(1.0), 90.0);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn asin_deg<T: Real>(x: T) -> T {
+    x.asin() * rad::<T>()
+}
+
+/// ### acos(x)
+///
+/// Inverse Trigonometric Function
+///
+/// The precise `acos` function returns the arccosine of `x` in radians.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::functions::precise::acos;
+/// assert_eq!(acos(0.0), std::f64::consts::FRAC_PI_2);
+/// assert_eq!(acos(1.0), 0.0);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn acos<T: Real>(x: T) -> T {
+    x.acos()
+}
+
+/// ### acos_deg(x)
+///
+/// Inverse Trigonometric Function
+///
+/// The precise `acos_deg` function returns the arccosine of `x` in degrees.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::functions::precise::acos_deg;
+/// assert_eq!(acos_deg(-1.0), 180.0);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn acos_deg<T: Real>(x: T) -> T {
+    x.acos() * rad::<T>()
+}
+
+/// ### atan(x)
+///
+/// Inverse Trigonometric Function
+///
+/// The precise `atan` function returns the arctangent of `x` in radians.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::functions::precise::atan;
+/// assert_eq!(atan(1.0), std::f64::consts::FRAC_PI_4);
+/// assert_eq!(atan(f64::INFINITY), std::f64::consts::FRAC_PI_2);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn atan<T: Real>(x: T) -> T {
+    x.atan()
+}
+
+/// ### atan_deg(x)
+///
+/// Inverse Trigonometric Function
+///
+/// The precise `atan_deg` function returns the arctangent of `x` in degrees.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::functions::precise::atan_deg;
+/// assert_eq!(atan_deg(1.0), 45.0);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn atan_deg<T: Real>(x: T) -> T {
+    x.atan() * rad::<T>()
+}
+
+/// ### acsc(x)
+///
+/// Inverse Trigonometric Function
+///
+/// The precise `acsc` function returns the arccosecant `asin(1 / x)` of `x` in radians.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::functions::precise::acsc;
+/// assert_eq!(acsc(1.0), std::f64::consts::FRAC_PI_2);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn acsc<T: Real>(x: T) -> T {
+    x.recip().asin()
+}
+
+/// ### acsc_deg(x)
+///
+/// Inverse Trigonometric Function
+///
+/// The precise `acsc_deg` function returns the arccosecant of `x` in degrees.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::functions::precise::acsc_deg;
+/// assert_eq!(acsc_deg(1.0), 90.0);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn acsc_deg<T: Real>(x: T) -> T {
+    x.recip().asin() * rad::<T>()
+}
+
+/// ### asec(x)
+///
+/// Inverse Trigonometric Function
+///
+/// The precise `asec` function returns the arcsecant `acos(1 / x)` of `x` in radians.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::functions::precise::asec;
+/// assert_eq!(asec(1.0), 0.0);
+/// assert_eq!(asec(-1.0), std::f64::consts::PI);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn asec<T: Real>(x: T) -> T {
+    x.recip().acos()
+}
+
+/// ### asec_deg(x)
+///
+/// Inverse Trigonometric Function
+///
+/// The precise `asec_deg` function returns the arcsecant of `x` in degrees.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::functions::precise::asec_deg;
+/// assert_eq!(asec_deg(-1.0), 180.0);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn asec_deg<T: Real>(x: T) -> T {
+    x.recip().acos() * rad::<T>()
+}
+
+/// ### acot(x)
+///
+/// Inverse Trigonometric Function
+///
+/// The precise `acot` function returns the arccotangent `atan(1 / x)` of `x` in radians.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::functions::precise::acot;
+/// assert_eq!(acot(1.0), std::f64::consts::FRAC_PI_4);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn acot<T: Real>(x: T) -> T {
+    x.recip().atan()
+}
+
+/// ### acot_deg(x)
+///
+/// Inverse Trigonometric Function
+///
+/// The precise `acot_deg` function returns the arccotangent of `x` in degrees.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::functions::precise::acot_deg;
+/// assert_eq!(acot_deg(1.0), 45.0);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn acot_deg<T: Real>(x: T) -> T {
+    x.recip().atan() * rad::<T>()
+}
+
+/// ### sinh(x)
+///
+/// Hyperbolic Function
+///
+/// The precise `sinh` function returns the hyperbolic sine of `x`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::functions::precise::sinh;
+/// assert_eq!(sinh(0.0), 0.0);
+/// assert_eq!(sinh(1.0), 1.0_f64.sinh());
+/// assert_eq!(sinh(1e-20), 1e-20);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn sinh<T: Real>(x: T) -> T {
+    x.sinh()
+}
+
+/// ### sinh_deg(x)
+///
+/// Hyperbolic Function
+///
+/// The precise `sinh_deg` function returns the hyperbolic sine of `x` given in degrees.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::functions::precise::sinh_deg;
+/// assert_eq!(sinh_deg(180.0), std::f64::consts::PI.sinh());
+/// ```
+/// <small>End Fun Doc</small>
+pub fn sinh_deg<T: Real>(x: T) -> T {
+    (x * deg::<T>()).sinh()
+}
+
+/// ### cosh(x)
+///
+/// Hyperbolic Function
+///
+/// The precise `cosh` function returns the hyperbolic cosine of `x`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::functions::precise::cosh;
+/// assert_eq!(cosh(0.0), 1.0);
+/// assert_eq!(cosh(1.0), 1.0_f64.cosh());
+/// ```
+/// <small>End Fun Doc</small>
+pub fn cosh<T: Real>(x: T) -> T {
+    x.cosh()
+}
+
+/// ### cosh_deg(x)
+///
+/// Hyperbolic Function
+///
+/// The precise `cosh_deg` function returns the hyperbolic cosine of `x` given in degrees.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::functions::precise::cosh_deg;
+/// assert_eq!(cosh_deg(180.0), std::f64::consts::PI.cosh());
+/// ```
+/// <small>End Fun Doc</small>
+pub fn cosh_deg<T: Real>(x: T) -> T {
+    (x * deg::<T>()).cosh()
+}
+
+/// ### tanh(x)
+///
+/// Hyperbolic Function
+///
+/// The precise `tanh` function returns the hyperbolic tangent of `x`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::functions::precise::tanh;
+/// assert_eq!(tanh(0.0), 0.0);
+/// assert_eq!(tanh(1.0), 1.0_f64.tanh());
+/// assert_eq!(tanh(f64::INFINITY), 1.0);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn tanh<T: Real>(x: T) -> T {
+    x.tanh()
+}
+
+/// ### tanh_deg(x)
+///
+/// Hyperbolic Function
+///
+/// The precise `tanh_deg` function returns the hyperbolic tangent of `x` given in degrees.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::functions::precise::tanh_deg;
+/// assert_eq!(tanh_deg(180.0), std::f64::consts::PI.tanh());
+/// ```
+/// <small>End Fun Doc</small>
+pub fn tanh_deg<T: Real>(x: T) -> T {
+    (x * deg::<T>()).tanh()
+}
+
+/// ### csch(x)
+///
+/// Hyperbolic Function
+///
+/// The precise `csch` function returns the hyperbolic cosecant `1 / sinh(x)` of `x`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::functions::precise::csch;
+/// assert_eq!(csch(1.0), 1.0 / 1.0_f64.sinh());
+/// assert_eq!(csch(0.0), f64::INFINITY);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn csch<T: Real>(x: T) -> T {
+    x.sinh().recip()
+}
+
+/// ### csch_deg(x)
+///
+/// Hyperbolic Function
+///
+/// The precise `csch_deg` function returns the hyperbolic cosecant of `x` given in degrees.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::functions::precise::csch_deg;
+/// assert_eq!(csch_deg(180.0), 1.0 / std::f64::consts::PI.sinh());
+/// ```
+/// <small>End Fun Doc</small>
+pub fn csch_deg<T: Real>(x: T) -> T {
+    (x * deg::<T>()).sinh().recip()
+}
+
+/// ### sech(x)
+///
+/// Hyperbolic Function
+///
+/// The precise `sech` function returns the hyperbolic secant `1 / cosh(x)` of `x`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::functions::precise::sech;
+/// assert_eq!(sech(0.0), 1.0);
+/// assert_eq!(sech(1.0), 1.0 / 1.0_f64.cosh());
+/// ```
+/// <small>End Fun Doc</small>
+pub fn sech<T: Real>(x: T) -> T {
+    x.cosh().recip()
+}
+
+/// ### sech_deg(x)
+///
+/// Hyperbolic Function
+///
+/// The precise `sech_deg` function returns the hyperbolic secant of `x` given in degrees.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::functions::precise::sech_deg;
+/// assert_eq!(sech_deg(180.0), 1.0 / std::f64::consts::PI.cosh());
+/// ```
+/// <small>End Fun Doc</small>
+pub fn sech_deg<T: Real>(x: T) -> T {
+    (x * deg::<T>()).cosh().recip()
+}
+
+/// ### coth(x)
+///
+/// Hyperbolic Function
+///
+/// The precise `coth` function returns the hyperbolic cotangent `1 / tanh(x)` of `x`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::functions::precise::coth;
+/// assert_eq!(coth(1.0), 1.0 / 1.0_f64.tanh());
+/// assert_eq!(coth(f64::INFINITY), 1.0);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn coth<T: Real>(x: T) -> T {
+    x.tanh().recip()
+}
+
+/// ### coth_deg(x)
+///
+/// Hyperbolic Function
+///
+/// The precise `coth_deg` function returns the hyperbolic cotangent of `x` given in degrees.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::functions::precise::coth_deg;
+/// assert_eq!(coth_deg(180.0), 1.0 / std::f64::consts::PI.tanh());
+/// ```
+/// <small>End Fun Doc</small>
+pub fn coth_deg<T: Real>(x: T) -> T {
+    (x * deg::<T>()).tanh().recip()
+}
+
+/// ### asinh(x)
+///
+/// Inverse Hyperbolic Function
+///
+/// The precise `asinh` function returns the inverse hyperbolic sine of `x`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::functions::precise::asinh;
+/// assert_eq!(asinh(0.0), 0.0);
+/// assert_eq!(asinh(1.0), 1.0_f64.asinh());
+/// ```
+/// <small>End Fun Doc</small>
+pub fn asinh<T: Real>(x: T) -> T {
+    x.asinh()
+}
+
+/// ### asinh_deg(x)
+///
+/// Inverse Hyperbolic Function
+///
+/// The precise `asinh_deg` function returns the inverse hyperbolic sine of `x` in degrees.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::functions::precise::asinh_deg;
+/// assert_eq!(asinh_deg(1.0), 1.0_f64.asinh().to_degrees());
+/// ```
+/// <small>End Fun Doc</small>
+pub fn asinh_deg<T: Real>(x: T) -> T {
+    x.asinh() * rad::<T>()
+}
+
+/// ### acosh(x)
+///
+/// Inverse Hyperbolic Function
+///
+/// The precise `acosh` function returns the inverse hyperbolic cosine of `x`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::functions::precise::acosh;
+/// assert_eq!(acosh(1.0), 0.0);
+/// assert!(acosh(0.5_f64).is_nan());
+/// ```
+/// <small>End Fun Doc</small>
+pub fn acosh<T: Real>(x: T) -> T {
+    x.acosh()
+}
+
+/// ### acosh_deg(x)
+///
+/// Inverse Hyperbolic Function
+///
+/// The precise `acosh_deg` function returns the inverse hyperbolic cosine of `x` in degrees.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::functions::precise::acosh_deg;
+/// assert_eq!(acosh_deg(2.0), 2.0_f64.acosh().to_degrees());
+/// ```
+/// <small>End Fun Doc</small>
+pub fn acosh_deg<T: Real>(x: T) -> T {
+    x.acosh() * rad::<T>()
+}
+
+/// ### atanh(x)
+///
+/// Inverse Hyperbolic Function
+///
+/// The precise `atanh` function returns the inverse hyperbolic tangent of `x`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::functions::precise::atanh;
+/// assert_eq!(atanh(0.0), 0.0);
+/// assert_eq!(atanh(1.0), f64::INFINITY);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn atanh<T: Real>(x: T) -> T {
+    x.atanh()
+}
+
+/// ### atanh_deg(x)
+///
+/// Inverse Hyperbolic Function
+///
+/// The precise `atanh_deg` function returns the inverse hyperbolic tangent of `x` in degrees.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::functions::precise::atanh_deg;
+/// assert_eq!(atanh_deg(0.5), 0.5_f64.atanh().to_degrees());
+/// ```
+/// <small>End Fun Doc</small>
+pub fn atanh_deg<T: Real>(x: T) -> T {
+    x.atanh() * rad::<T>()
+}
+
+/// ### acsch(x)
+///
+/// Inverse Hyperbolic Function
+///
+/// The precise `acsch` function returns the inverse hyperbolic cosecant `asinh(1 / x)` of `x`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::functions::precise::acsch;
+/// assert_eq!(acsch(2.0), 0.5_f64.asinh());
+/// assert_eq!(acsch(f64::INFINITY), 0.0);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn acsch<T: Real>(x: T) -> T {
+    x.recip().asinh()
+}
+
+/// ### acsch_deg(x)
+///
+/// Inverse Hyperbolic Function
+///
+/// The precise `acsch_deg` function returns the inverse hyperbolic cosecant of `x` in degrees.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::functions::precise::acsch_deg;
+/// assert_eq!(acsch_deg(2.0), 0.5_f64.asinh().to_degrees());
+/// ```
+/// <small>End Fun Doc</small>
+pub fn acsch_deg<T: Real>(x: T) -> T {
+    x.recip().asinh() * rad::<T>()
+}
+
+/// ### asech(x)
+///
+/// Inverse Hyperbolic Function
+///
+/// The precise `asech` function returns the inverse hyperbolic secant `acosh(1 / x)` of `x`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::functions::precise::asech;
+/// assert_eq!(asech(1.0), 0.0);
+/// assert_eq!(asech(0.5), 2.0_f64.acosh());
+/// ```
+/// <small>End Fun Doc</small>
+pub fn asech<T: Real>(x: T) -> T {
+    x.recip().acosh()
+}
+
+/// ### asech_deg(x)
+///
+/// Inverse Hyperbolic Function
+///
+/// The precise `asech_deg` function returns the inverse hyperbolic secant of `x` in degrees.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::functions::precise::asech_deg;
+/// assert_eq!(asech_deg(0.5), 2.0_f64.acosh().to_degrees());
+/// ```
+/// <small>End Fun Doc</small>
+pub fn asech_deg<T: Real>(x: T) -> T {
+    x.recip().acosh() * rad::<T>()
+}
+
+/// ### acoth(x)
+///
+/// Inverse Hyperbolic Function
+///
+/// The precise `acoth` function returns the inverse hyperbolic cotangent `atanh(1 / x)` of `x`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::functions::precise::acoth;
+/// assert_eq!(acoth(2.0), 0.5_f64.atanh());
+/// assert_eq!(acoth(1.0), f64::INFINITY);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn acoth<T: Real>(x: T) -> T {
+    x.recip().atanh()
+}
+
+/// ### acoth_deg(x)
+///
+/// Inverse Hyperbolic Function
+///
+/// The precise `acoth_deg` function returns the inverse hyperbolic cotangent of `x` in degrees.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::functions::precise::acoth_deg;
+/// assert_eq!(acoth_deg(2.0), 0.5_f64.atanh().to_degrees());
+/// ```
+/// <small>End Fun Doc</small>
+pub fn acoth_deg<T: Real>(x: T) -> T {
+    x.recip().atanh() * rad::<T>()
+}
@@ -1,15 +1,19 @@
 use super::{
     num::{
         abs, acos, acos_deg, acosh, acosh_deg, acot, acot_deg, acoth, acoth_deg, acsc, acsc_deg,
-        acsch, acsch_deg, asec, asec_deg, asech, asech_deg, asin, asin_deg, asinh, asinh_deg, atan,
-        atan_deg, atanh, atanh_deg, cbrt, ceil, cos, cos_deg, cosh, cosh_deg, cot, cot_deg, coth,
-        coth_deg, csc, csc_deg, csch, csch_deg, cube, deg_to_rad, exp, f64_to_f32, fact, fix64,
-        floor, fround, gamma, i64_to_f64, inv, ln, ln1p, log10, log2, rad_to_deg, round, sec,
-        sec_deg, sech, sech_deg, sign, sin, sin_deg, sinh, sinh_deg, sqr, sqrt, tan, tan_deg, tanh,
-        tanh_deg, trunc, u64_to_f64,
+        acsch, acsch_deg, airy_ai, airy_aip, airy_bi, airy_bip, asec, asec_deg, asech, asech_deg,
+        asin, asin_deg, asinh, asinh_deg, atan, atan_deg, atanh, atanh_deg, besseli, besselj,
+        besselk, bessely, cbrt, ceil, cos, cos_deg, cosh, cosh_deg, cot, cot_deg, coth, coth_deg,
+        csc, csc_deg, csch, csch_deg, cube, deg_to_rad, ellipe, ellipeinc, ellipk, ellipkinc, erf,
+        erfc, erfcinv, erfinv, exp, expm1, f64_to_f32, fact, fix64, floor, fround, gamma,
+        gamma_f64, i64_to_f64, inv, lambertw, lambertw_m1, ln, ln1p, log10, log2, rad_to_deg,
+        round, sec, sec_deg, sech, sech_deg, sign, sin, sin_deg, sinh, sinh_deg, sqr, sqrt, tan,
+        tan_deg, tanh, tanh_deg, trunc, u64_to_f64, zeta,
     },
     rand, string_to_u64,
 };
+use crate::real::Real;
+
 /// ### abs_vec(x)
 ///
 /// Native Function
@@ -57,7 +61,7 @@ use super::{
 /// assert_eq!(abs_vec(&my_x_f64_array), [0.0, 1.0, 3.33, 3.33]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn abs_vec(x: &[f64]) -> Vec<f64> {
+pub fn abs_vec<T: Real>(x: &[T]) -> Vec<T> {
     x.iter().map(|&x| abs(x)).collect()
 }
 
@@ -77,7 +81,7 @@ pub fn abs_vec(x: &[f64]) -> Vec<f64> {
 /// assert_eq!(sign_vec(&my_x_f64_array), [-1.0, 1.0, 1.0, -1.0, 0.0, 0.0]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn sign_vec(x: &[f64]) -> Vec<f64> {
+pub fn sign_vec<T: Real>(x: &[T]) -> Vec<T> {
     x.iter().map(|&x| sign(x)).collect()
 }
 
@@ -128,6 +132,351 @@ pub fn gamma_vec(x: &[u64]) -> Vec<u64> {
     x.iter().map(|&x| gamma(x)).collect()
 }
 
+/// ### gamma_f64_vec(x)
+///
+/// Special Function
+///
+/// The `gamma_f64_vec` function takes a slice of floating-point numbers (`&[f64]`)
+/// and returns a vector (`Vec<f64>`) containing the real-valued Gamma function of each element,
+/// computed with `gamma_f64`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{gamma_f64_vec, fix64_vec, INF_F64};
+/// assert_eq!(gamma_f64_vec(&[1.0, 2.0, 5.0, 0.0]), [1.0, 1.0, 24.0, INF_F64]);
+/// assert_eq!(fix64_vec(&gamma_f64_vec(&[0.5, 1.5, -0.5])), [1.7724539, 0.88622695, -3.5449078]);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn gamma_f64_vec(x: &[f64]) -> Vec<f64> {
+    x.iter().map(|&x| gamma_f64(x)).collect()
+}
+
+/// ### erf_vec(x)
+///
+/// Special Function
+///
+/// The `erf_vec` function takes a slice of floating-point numbers (`&[f64]`)
+/// and returns a vector (`Vec<f64>`) containing the error function of each element, computed with `erf`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{erf_vec, fix64_vec};
+/// assert_eq!(fix64_vec(&erf_vec(&[-1.0, 0.0, 0.5])), [-0.8427008, 0.0, 0.5204999]);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn erf_vec(x: &[f64]) -> Vec<f64> {
+    x.iter().map(|&x| erf(x)).collect()
+}
+
+/// ### erfc_vec(x)
+///
+/// Special Function
+///
+/// The `erfc_vec` function takes a slice of floating-point numbers (`&[f64]`)
+/// and returns a vector (`Vec<f64>`) containing the complementary error function of each element, computed with `erfc`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{erfc_vec, fix64_vec};
+/// assert_eq!(fix64_vec(&erfc_vec(&[-1.0, 0.0, 2.0])), [1.8427008, 1.0, 0.004677735]);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn erfc_vec(x: &[f64]) -> Vec<f64> {
+    x.iter().map(|&x| erfc(x)).collect()
+}
+
+/// ### erfinv_vec(x)
+///
+/// Special Function
+///
+/// The `erfinv_vec` function takes a slice of floating-point numbers (`&[f64]`)
+/// and returns a vector (`Vec<f64>`) containing the inverse error function of each element, computed with `erfinv`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{erfinv_vec, fix64_vec, INF_F64};
+/// assert_eq!(fix64_vec(&erfinv_vec(&[-0.9, 0.0, 0.5, 1.0])), [-1.1630871, 0.0, 0.47693628, INF_F64]);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn erfinv_vec(x: &[f64]) -> Vec<f64> {
+    x.iter().map(|&x| erfinv(x)).collect()
+}
+
+/// ### erfcinv_vec(x)
+///
+/// Special Function
+///
+/// The `erfcinv_vec` function takes a slice of floating-point numbers (`&[f64]`)
+/// and returns a vector (`Vec<f64>`) containing the inverse complementary error function of each element, computed with `erfcinv`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{erfcinv_vec, fix64_vec};
+/// assert_eq!(fix64_vec(&erfcinv_vec(&[1e-10, 1.0, 1.5])), [4.572825, 0.0, -0.47693628]);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn erfcinv_vec(x: &[f64]) -> Vec<f64> {
+    x.iter().map(|&x| erfcinv(x)).collect()
+}
+
+/// ### besselj_vec(nu, x)
+///
+/// Special Function
+///
+/// The `besselj_vec` function takes order `nu` and a slice of floating-point numbers (`&[f64]`),
+/// and returns a vector (`Vec<f64>`) containing the Bessel function of the first kind `J_ν` at each element, computed with `besselj`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{besselj_vec, fix64_vec};
+/// assert_eq!(fix64_vec(&besselj_vec(0.0, &[0.0, 1.0, -1.0])), [1.0, 0.7651977, 0.7651977]);
+/// assert_eq!(fix64_vec(&besselj_vec(1.0, &[0.0, 10.0])), [0.0, 0.043472745]);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn besselj_vec(nu: f64, x: &[f64]) -> Vec<f64> {
+    x.iter().map(|&x| besselj(nu, x)).collect()
+}
+
+/// ### bessely_vec(nu, x)
+///
+/// Special Function
+///
+/// The `bessely_vec` function takes order `nu` and a slice of floating-point numbers (`&[f64]`),
+/// and returns a vector (`Vec<f64>`) containing the Bessel function of the second kind `Y_ν` at each element, computed with `bessely`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{bessely_vec, fix64_vec, NINF_F64};
+/// assert_eq!(fix64_vec(&bessely_vec(0.0, &[0.0, 1.0])), [NINF_F64, 0.08825696]);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn bessely_vec(nu: f64, x: &[f64]) -> Vec<f64> {
+    x.iter().map(|&x| bessely(nu, x)).collect()
+}
+
+/// ### besseli_vec(nu, x)
+///
+/// Special Function
+///
+/// The `besseli_vec` function takes order `nu` and a slice of floating-point numbers (`&[f64]`),
+/// and returns a vector (`Vec<f64>`) containing the modified Bessel function of the first kind `I_ν` at each element, computed with `besseli`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{besseli_vec, fix64_vec};
+/// assert_eq!(fix64_vec(&besseli_vec(0.0, &[0.0, 1.0])), [1.0, 1.2660658]);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn besseli_vec(nu: f64, x: &[f64]) -> Vec<f64> {
+    x.iter().map(|&x| besseli(nu, x)).collect()
+}
+
+/// ### besselk_vec(nu, x)
+///
+/// Special Function
+///
+/// The `besselk_vec` function takes order `nu` and a slice of floating-point numbers (`&[f64]`),
+/// and returns a vector (`Vec<f64>`) containing the modified Bessel function of the second kind `K_ν` at each element, computed with `besselk`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{besselk_vec, fix64_vec, INF_F64};
+/// assert_eq!(fix64_vec(&besselk_vec(0.0, &[0.0, 1.0])), [INF_F64, 0.42102444]);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn besselk_vec(nu: f64, x: &[f64]) -> Vec<f64> {
+    x.iter().map(|&x| besselk(nu, x)).collect()
+}
+
+/// ### airy_ai_vec(x)
+///
+/// Special Function
+///
+/// The `airy_ai_vec` function takes a slice of floating-point numbers (`&[f64]`)
+/// and returns a vector (`Vec<f64>`) containing the Airy function `Ai` of each element, computed with `airy_ai`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{airy_ai_vec, fix64_vec};
+/// assert_eq!(fix64_vec(&airy_ai_vec(&[-1.0, 0.0, 1.0])), [0.5355609, 0.35502806, 0.13529241]);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn airy_ai_vec(x: &[f64]) -> Vec<f64> {
+    x.iter().map(|&x| airy_ai(x)).collect()
+}
+
+/// ### airy_aip_vec(x)
+///
+/// Special Function
+///
+/// The `airy_aip_vec` function takes a slice of floating-point numbers (`&[f64]`)
+/// and returns a vector (`Vec<f64>`) containing the derivative of the Airy function `Ai'` of each element, computed with `airy_aip`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{airy_aip_vec, fix64_vec};
+/// assert_eq!(fix64_vec(&airy_aip_vec(&[-1.0, 0.0, 1.0])), [-0.010160567, -0.2588194, -0.15914744]);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn airy_aip_vec(x: &[f64]) -> Vec<f64> {
+    x.iter().map(|&x| airy_aip(x)).collect()
+}
+
+/// ### airy_bi_vec(x)
+///
+/// Special Function
+///
+/// The `airy_bi_vec` function takes a slice of floating-point numbers (`&[f64]`)
+/// and returns a vector (`Vec<f64>`) containing the Airy function `Bi` of each element, computed with `airy_bi`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{airy_bi_vec, fix64_vec};
+/// assert_eq!(fix64_vec(&airy_bi_vec(&[-1.0, 0.0, 1.0])), [0.10399739, 0.61492664, 1.2074236]);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn airy_bi_vec(x: &[f64]) -> Vec<f64> {
+    x.iter().map(|&x| airy_bi(x)).collect()
+}
+
+/// ### airy_bip_vec(x)
+///
+/// Special Function
+///
+/// The `airy_bip_vec` function takes a slice of floating-point numbers (`&[f64]`)
+/// and returns a vector (`Vec<f64>`) containing the derivative of the Airy function `Bi'` of each element, computed with `airy_bip`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{airy_bip_vec, fix64_vec};
+/// assert_eq!(fix64_vec(&airy_bip_vec(&[-1.0, 0.0, 1.0])), [0.59237564, 0.44828835, 0.9324359]);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn airy_bip_vec(x: &[f64]) -> Vec<f64> {
+    x.iter().map(|&x| airy_bip(x)).collect()
+}
+
+/// ### ellipk_vec(x)
+///
+/// Special Function
+///
+/// The `ellipk_vec` function takes a slice of floating-point numbers (`&[f64]`)
+/// and returns a vector (`Vec<f64>`) containing the complete elliptic integral of the first kind of each element, computed with `ellipk`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{ellipk_vec, fix64_vec, INF_F64};
+/// assert_eq!(fix64_vec(&ellipk_vec(&[-1.0, 0.5, 1.0])), [1.3110287, 1.8540747, INF_F64]);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn ellipk_vec(x: &[f64]) -> Vec<f64> {
+    x.iter().map(|&x| ellipk(x)).collect()
+}
+
+/// ### ellipe_vec(x)
+///
+/// Special Function
+///
+/// The `ellipe_vec` function takes a slice of floating-point numbers (`&[f64]`)
+/// and returns a vector (`Vec<f64>`) containing the complete elliptic integral of the second kind of each element, computed with `ellipe`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{ellipe_vec, fix64_vec};
+/// assert_eq!(fix64_vec(&ellipe_vec(&[0.5, 1.0])), [1.3506439, 1.0]);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn ellipe_vec(x: &[f64]) -> Vec<f64> {
+    x.iter().map(|&x| ellipe(x)).collect()
+}
+
+/// ### ellipkinc_vec(phi, m)
+///
+/// Special Function
+///
+/// The `ellipkinc_vec` function takes a slice of amplitudes `phi` and a parameter `m`,
+/// and returns a vector (`Vec<f64>`) containing the incomplete elliptic integral of the first kind at each amplitude,
+/// computed with `ellipkinc`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{ellipkinc_vec, fix64_vec, PI};
+/// assert_eq!(fix64_vec(&ellipkinc_vec(&[0.0, PI / 4.0], 0.5)), [0.0, 0.82601786]);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn ellipkinc_vec(phi: &[f64], m: f64) -> Vec<f64> {
+    phi.iter().map(|&phi| ellipkinc(phi, m)).collect()
+}
+
+/// ### ellipeinc_vec(phi, m)
+///
+/// Special Function
+///
+/// The `ellipeinc_vec` function takes a slice of amplitudes `phi` and a parameter `m`,
+/// and returns a vector (`Vec<f64>`) containing the incomplete elliptic integral of the second kind at each amplitude,
+/// computed with `ellipeinc`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{ellipeinc_vec, fix64_vec, PI};
+/// assert_eq!(fix64_vec(&ellipeinc_vec(&[0.0, PI / 4.0], 0.5)), [0.0, 0.7481865]);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn ellipeinc_vec(phi: &[f64], m: f64) -> Vec<f64> {
+    phi.iter().map(|&phi| ellipeinc(phi, m)).collect()
+}
+
+/// ### zeta_vec(x)
+///
+/// Special Function
+///
+/// The `zeta_vec` function takes a slice of floating-point numbers (`&[f64]`)
+/// and returns a vector (`Vec<f64>`) containing the Riemann zeta function of each element, computed with `zeta`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{zeta_vec, fix64_vec, INF_F64};
+/// assert_eq!(fix64_vec(&zeta_vec(&[-2.0, 0.0, 1.0, 3.0])), [0.0, -0.5, INF_F64, 1.2020569]);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn zeta_vec(x: &[f64]) -> Vec<f64> {
+    x.iter().map(|&x| zeta(x)).collect()
+}
+
+/// ### lambertw_vec(x)
+///
+/// Special Function
+///
+/// The `lambertw_vec` function takes a slice of floating-point numbers (`&[f64]`)
+/// and returns a vector (`Vec<f64>`) containing the principal branch of the Lambert W function of each element, computed with `lambertw`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{lambertw_vec, fix64_vec};
+/// assert_eq!(fix64_vec(&lambertw_vec(&[0.0, 1.0, -0.3])), [0.0, 0.56714326, -0.48940223]);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn lambertw_vec(x: &[f64]) -> Vec<f64> {
+    x.iter().map(|&x| lambertw(x)).collect()
+}
+
+/// ### lambertw_m1_vec(x)
+///
+/// Special Function
+///
+/// The `lambertw_m1_vec` function takes a slice of floating-point numbers (`&[f64]`)
+/// and returns a vector (`Vec<f64>`) containing the lower branch of the Lambert W function of each element, computed with `lambertw_m1`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{lambertw_m1_vec, fix64_vec};
+/// assert_eq!(fix64_vec(&lambertw_m1_vec(&[-0.1, -0.3])), [-3.577152, -1.781337]);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn lambertw_m1_vec(x: &[f64]) -> Vec<f64> {
+    x.iter().map(|&x| lambertw_m1(x)).collect()
+}
+
 /// ### inv_vec(x)
 ///
 /// Native Function
@@ -145,7 +494,7 @@ pub fn gamma_vec(x: &[u64]) -> Vec<u64> {
 /// assert_eq!(fround_vec(&inv_vec(&[0.0, 0.1, 0.2, 0.3])), [INF_F32, 10.0, 5.0, 3.3333333333333335]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn inv_vec(x: &[f64]) -> Vec<f64> {
+pub fn inv_vec<T: Real>(x: &[T]) -> Vec<T> {
     x.iter().map(|&x| inv(x)).collect()
 }
 
@@ -165,7 +514,7 @@ pub fn inv_vec(x: &[f64]) -> Vec<f64> {
 /// assert_eq!(floor_vec(&my_x_f64_array), [0.0, 0.0, 1.0, 1.0, -1.0, -2.0, -2.0]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn floor_vec(x: &[f64]) -> Vec<f64> {
+pub fn floor_vec<T: Real>(x: &[T]) -> Vec<T> {
     x.iter().map(|&x| floor(x)).collect()
 }
 
@@ -185,7 +534,7 @@ pub fn floor_vec(x: &[f64]) -> Vec<f64> {
 /// assert_eq!(ceil_vec(&my_x_f64_array), [0.0, 1.0, 2.0, 2.0, 0.0, -1.0, -1.0]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn ceil_vec(x: &[f64]) -> Vec<f64> {
+pub fn ceil_vec<T: Real>(x: &[T]) -> Vec<T> {
     x.iter().map(|&x| ceil(x)).collect()
 }
 
@@ -207,7 +556,7 @@ pub fn ceil_vec(x: &[f64]) -> Vec<f64> {
 /// assert_eq!(round_vec(&my_x_f64_array), [0.0, 1.0, 1.0, 1.0, 2.0, -1.0, -1.0, -1.0, -2.0]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn round_vec(x: &[f64]) -> Vec<f64> {
+pub fn round_vec<T: Real>(x: &[T]) -> Vec<T> {
     x.iter().map(|&x| round(x)).collect()
 }
 
@@ -314,7 +663,7 @@ pub fn i64_to_f64_vec(x: &[i64]) -> Vec<f64> {
 /// assert_eq!(deg_to_rad_vec(&my_x_f64_array), [0.0, 0.0174532925, 0.5235987756, 0.7853981634, 1.0471975512, 1.5707963268, 3.1415926536, 6.2831853072, -6.2831853072]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn deg_to_rad_vec(x: &[f64]) -> Vec<f64> {
+pub fn deg_to_rad_vec<T: Real>(x: &[T]) -> Vec<T> {
     x.iter().map(|&x| deg_to_rad(x)).collect()
 }
 
@@ -335,7 +684,7 @@ pub fn deg_to_rad_vec(x: &[f64]) -> Vec<f64> {
 /// assert_eq!(rad_to_deg_vec(&my_x_f64_array), [0.0, 1.0, 30.0, 45.0, 60.0, 90.0, 180.0, 360.0, -360.0]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn rad_to_deg_vec(x: &[f64]) -> Vec<f64> {
+pub fn rad_to_deg_vec<T: Real>(x: &[T]) -> Vec<T> {
     x.iter().map(|&x| rad_to_deg(x)).collect()
 }
 
@@ -355,7 +704,7 @@ pub fn rad_to_deg_vec(x: &[f64]) -> Vec<f64> {
 /// assert_eq!(fround_vec(&sqr_vec(&[0.0, 0.1, 1.0, 2.0, 10.0, INF_F64])), [0.0, 0.01, 1.0, 4.0, 100.0, INF_F32]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn sqr_vec(x: &[f64]) -> Vec<f64> {
+pub fn sqr_vec<T: Real>(x: &[T]) -> Vec<T> {
     x.iter().map(|&x| sqr(x)).collect()
 }
 
@@ -373,7 +722,7 @@ pub fn sqr_vec(x: &[f64]) -> Vec<f64> {
 /// assert_eq!(sqrt_vec(&[0.0, 0.01, 1.0, 4.0, 9.0, 100.0, inf]), [0.0, 0.1, 1.0, 2.0, 3.0, 10.0, inf]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn sqrt_vec(x: &[f64]) -> Vec<f64> {
+pub fn sqrt_vec<T: Real>(x: &[T]) -> Vec<T> {
     x.iter().map(|&x| sqrt(x)).collect()
 }
 
@@ -392,7 +741,7 @@ pub fn sqrt_vec(x: &[f64]) -> Vec<f64> {
 /// assert_eq!(exp_vec(&[-1.0, 0.0, 1.0]), &[0.36787944117144233, 1.0, E]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn exp_vec(x: &[f64]) -> Vec<f64> {
+pub fn exp_vec<T: Real>(x: &[T]) -> Vec<T> {
     x.iter().map(|&x| exp(x)).collect()
 }
 
@@ -411,7 +760,7 @@ pub fn exp_vec(x: &[f64]) -> Vec<f64> {
 /// assert_eq!(ln_vec(&[0.0, 1.0, E, 10.0, 1.5]), &[-inf, 0.0, 1.0, LN10, 0.4054651081081644]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn ln_vec(x: &[f64]) -> Vec<f64> {
+pub fn ln_vec<T: Real>(x: &[T]) -> Vec<T> {
     x.iter().map(|&x| ln(x)).collect()
 }
 
@@ -432,10 +781,27 @@ pub fn ln_vec(x: &[f64]) -> Vec<f64> {
 /// assert_eq!(ln1p_vec(&[0.0, 1.0, E, 10.0]), &[0.0, 0.6931471805599453, 1.3132616875182228, 2.3978952727983707]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn ln1p_vec(x: &[f64]) -> Vec<f64> {
+pub fn ln1p_vec<T: Real>(x: &[T]) -> Vec<T> {
     x.iter().map(|&x| ln1p(x)).collect()
 }
 
+/// ### expm1_vec(x)
+///
+/// Exponential Function
+///
+/// The `expm1_vec` function computes `e^x - 1` for each element of a slice,
+/// keeping full accuracy for elements near zero, and returns the results as a new vector.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::expm1_vec;
+/// assert_eq!(expm1_vec(&[0.0, 1e-10, -1e-300]), [0.0, 1.00000000005e-10, -1e-300]);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn expm1_vec<T: Real>(x: &[T]) -> Vec<T> {
+    x.iter().map(|&x| expm1(x)).collect()
+}
+
 /// ### log2_vec(x)
 ///
 /// Logarithm Function
@@ -453,7 +819,7 @@ pub fn ln1p_vec(x: &[f64]) -> Vec<f64> {
 /// assert_eq!(log2_vec(&[0.0, 1.0, E, LN10]), &[-inf, 0.0, 1.4426950408889634, 1.2032544726997219]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn log2_vec(x: &[f64]) -> Vec<f64> {
+pub fn log2_vec<T: Real>(x: &[T]) -> Vec<T> {
     x.iter().map(|&x| log2(x)).collect()
 }
 
@@ -472,7 +838,7 @@ pub fn log2_vec(x: &[f64]) -> Vec<f64> {
 /// assert_eq!(log10_vec(&[0.0, 1.5, 10.0]), &[-inf, 0.17609125905568124, 1.0]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn log10_vec(x: &[f64]) -> Vec<f64> {
+pub fn log10_vec<T: Real>(x: &[T]) -> Vec<T> {
     x.iter().map(|&x| log10(x)).collect()
 }
 
@@ -494,7 +860,7 @@ pub fn log10_vec(x: &[f64]) -> Vec<f64> {
 /// assert_eq!(fix64_vec(&[0.3, 0.30000000000000004, 0.1 + 0.2]), [0.3, 0.3, 0.3]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn fix64_vec(x: &[f64]) -> Vec<f64> {
+pub fn fix64_vec<T: Real>(x: &[T]) -> Vec<T> {
     x.iter().map(|&x| fix64(x)).collect()
 }
 
@@ -512,7 +878,7 @@ pub fn fix64_vec(x: &[f64]) -> Vec<f64> {
 /// assert_eq!(fix64_vec(&cube_vec(&[0.0, 0.1, 0.2])), [0.0, 0.001, 0.008]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn cube_vec(x: &[f64]) -> Vec<f64> {
+pub fn cube_vec<T: Real>(x: &[T]) -> Vec<T> {
     x.iter().map(|&x| cube(x)).collect()
 }
 
@@ -531,7 +897,7 @@ pub fn cube_vec(x: &[f64]) -> Vec<f64> {
 /// assert_eq!(fix64_vec(&cbrt_vec(&[8.0, 27.0])), [2.0, 3.0]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn cbrt_vec(x: &[f64]) -> Vec<f64> {
+pub fn cbrt_vec<T: Real>(x: &[T]) -> Vec<T> {
     x.iter().map(|&x| cbrt(x)).collect()
 }
 
@@ -548,7 +914,7 @@ pub fn cbrt_vec(x: &[f64]) -> Vec<f64> {
 /// assert_eq!(trunc_vec(&[-0.37, 0.37, -3.7, 3.7]), [0.0, 0.0, -3.0, 3.0]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn trunc_vec(x: &[f64]) -> Vec<f64> {
+pub fn trunc_vec<T: Real>(x: &[T]) -> Vec<T> {
     x.iter().map(|&x| trunc(x)).collect()
 }
 
@@ -563,9 +929,10 @@ pub fn trunc_vec(x: &[f64]) -> Vec<f64> {
 /// ```rust
 /// use mathlab::math::sin_vec;
 /// assert_eq!(sin_vec(&[0.0, 1e-10, 0.5235987756]), [0.0, 1e-10, 0.5]);
+/// assert_eq!(sin_vec(&[0.0_f32, 0.5235988_f32]), [0.0_f32, 0.5_f32]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn sin_vec(x: &[f64]) -> Vec<f64> {
+pub fn sin_vec<T: Real>(x: &[T]) -> Vec<T> {
     x.iter().map(|&x| sin(x)).collect()
 }
 
@@ -582,7 +949,7 @@ pub fn sin_vec(x: &[f64]) -> Vec<f64> {
 /// assert_eq!(sin_deg_vec(&[0.0, 30.0, 45.0, 60.0, 90.0]), [0.0, 0.5, 0.7071067812, 0.8660254038, 1.0]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn sin_deg_vec(x: &[f64]) -> Vec<f64> {
+pub fn sin_deg_vec<T: Real>(x: &[T]) -> Vec<T> {
     x.iter().map(|&x| sin_deg(x)).collect()
 }
 
@@ -598,7 +965,7 @@ pub fn sin_deg_vec(x: &[f64]) -> Vec<f64> {
 /// assert_eq!(asin_vec(&[0.0, 0.5, 0.7071067812, 0.8660254038, 1.0]), [0.0, 0.5235987756, 0.7853981634, 1.0471975512, 1.5707963268]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn asin_vec(x: &[f64]) -> Vec<f64> {
+pub fn asin_vec<T: Real>(x: &[T]) -> Vec<T> {
     x.iter().map(|&x| asin(x)).collect()
 }
 
@@ -614,7 +981,7 @@ pub fn asin_vec(x: &[f64]) -> Vec<f64> {
 /// assert_eq!(asin_deg_vec(&[0.0, 0.5, 0.7071067812, 0.8660254038, 1.0]), [0.0, 30.0, 45.0, 60.0, 90.0]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn asin_deg_vec(x: &[f64]) -> Vec<f64> {
+pub fn asin_deg_vec<T: Real>(x: &[T]) -> Vec<T> {
     x.iter().map(|&x| asin_deg(x)).collect()
 }
 
@@ -631,7 +998,7 @@ pub fn asin_deg_vec(x: &[f64]) -> Vec<f64> {
 /// assert_eq!(cos_vec(&[0.0, 1e-10, 1.0471975512]), [1.0, 1.0, 0.5]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn cos_vec(x: &[f64]) -> Vec<f64> {
+pub fn cos_vec<T: Real>(x: &[T]) -> Vec<T> {
     x.iter().map(|&x| cos(x)).collect()
 }
 
@@ -648,7 +1015,7 @@ pub fn cos_vec(x: &[f64]) -> Vec<f64> {
 /// assert_eq!(cos_deg_vec(&[0.0, 30.0, 45.0, 60.0, 90.0]), [1.0, 0.8660254038, 0.7071067812, 0.5, 0.0]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn cos_deg_vec(x: &[f64]) -> Vec<f64> {
+pub fn cos_deg_vec<T: Real>(x: &[T]) -> Vec<T> {
     x.iter().map(|&x| cos_deg(x)).collect()
 }
 
@@ -664,7 +1031,7 @@ pub fn cos_deg_vec(x: &[f64]) -> Vec<f64> {
 /// assert_eq!(acos_vec(&[0.0, 0.5, 0.7071067812, 0.8660254038, 1.0]), [1.5707963268, 1.0471975512, 0.7853981634, 0.5235987756, 0.0]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn acos_vec(x: &[f64]) -> Vec<f64> {
+pub fn acos_vec<T: Real>(x: &[T]) -> Vec<T> {
     x.iter().map(|&x| acos(x)).collect()
 }
 
@@ -680,7 +1047,7 @@ pub fn acos_vec(x: &[f64]) -> Vec<f64> {
 /// assert_eq!(acos_deg_vec(&[0.0, 0.5, 0.7071067812, 0.8660254038, 1.0]), [90.0, 60.0, 45.0, 30.0, 0.0]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn acos_deg_vec(x: &[f64]) -> Vec<f64> {
+pub fn acos_deg_vec<T: Real>(x: &[T]) -> Vec<T> {
     x.iter().map(|&x| acos_deg(x)).collect()
 }
 
@@ -697,7 +1064,7 @@ pub fn acos_deg_vec(x: &[f64]) -> Vec<f64> {
 /// assert_eq!(tan_vec(&[0.0, 1e-10, 0.7853981634]), [0.0, 1e-10, 1.0]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn tan_vec(x: &[f64]) -> Vec<f64> {
+pub fn tan_vec<T: Real>(x: &[T]) -> Vec<T> {
     x.iter().map(|&x| tan(x)).collect()
 }
 
@@ -714,7 +1081,7 @@ pub fn tan_vec(x: &[f64]) -> Vec<f64> {
 /// assert_eq!(tan_deg_vec(&[0.0, 30.0, 45.0, 60.0, 90.0]), [0.0, 0.5773502692, 1.0, 1.7320508076, -inf]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn tan_deg_vec(x: &[f64]) -> Vec<f64> {
+pub fn tan_deg_vec<T: Real>(x: &[T]) -> Vec<T> {
     x.iter().map(|&x| tan_deg(x)).collect()
 }
 
@@ -730,7 +1097,7 @@ pub fn tan_deg_vec(x: &[f64]) -> Vec<f64> {
 /// assert_eq!(atan_vec(&[0.0, 0.5773502692, 1.0, 1.7320508076, inf]), [0.0, 0.5235987756, 0.7853981634, 1.0471975512, 1.5707963268]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn atan_vec(x: &[f64]) -> Vec<f64> {
+pub fn atan_vec<T: Real>(x: &[T]) -> Vec<T> {
     x.iter().map(|&x| atan(x)).collect()
 }
 
@@ -746,7 +1113,7 @@ pub fn atan_vec(x: &[f64]) -> Vec<f64> {
 /// assert_eq!(atan_deg_vec(&[0.0, 0.5773502692, 1.0, 1.7320508076, inf]), [0.0, 30.0, 45.0, 60.0, 90.0]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn atan_deg_vec(x: &[f64]) -> Vec<f64> {
+pub fn atan_deg_vec<T: Real>(x: &[T]) -> Vec<T> {
     x.iter().map(|&x| atan_deg(x)).collect()
 }
 
@@ -763,7 +1130,7 @@ pub fn atan_deg_vec(x: &[f64]) -> Vec<f64> {
 /// assert_eq!(csc_vec(&[0.0, 1e-10, 0.5235987756]), [inf, 10000000000.0, 2.0]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn csc_vec(x: &[f64]) -> Vec<f64> {
+pub fn csc_vec<T: Real>(x: &[T]) -> Vec<T> {
     x.iter().map(|&x| csc(x)).collect()
 }
 
@@ -780,7 +1147,7 @@ pub fn csc_vec(x: &[f64]) -> Vec<f64> {
 /// assert_eq!(csc_deg_vec(&[0.0, 30.0, 45.0, 60.0, 90.0]), [inf, 2.0, 1.4142135623, 1.1547005384, 1.0]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn csc_deg_vec(x: &[f64]) -> Vec<f64> {
+pub fn csc_deg_vec<T: Real>(x: &[T]) -> Vec<T> {
     x.iter().map(|&x| csc_deg(x)).collect()
 }
 
@@ -796,7 +1163,7 @@ pub fn csc_deg_vec(x: &[f64]) -> Vec<f64> {
 /// assert_eq!(acsc_vec(&[inf, 10000000000.0, 2.0, 1.4142135623, 1.1547005384, 1.0]), [0.0, 1e-10, 0.5235987756, 0.7853981634, 1.0471975512, 1.5707963268]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn acsc_vec(x: &[f64]) -> Vec<f64> {
+pub fn acsc_vec<T: Real>(x: &[T]) -> Vec<T> {
     x.iter().map(|&x| acsc(x)).collect()
 }
 
@@ -812,7 +1179,7 @@ pub fn acsc_vec(x: &[f64]) -> Vec<f64> {
 /// assert_eq!(acsc_deg_vec(&[inf, 10000000000.0, 2.0, 1.4142135623, 1.1547005384, 1.0]), [0.0, 5.729578e-9, 30.0, 45.0, 60.0, 90.0]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn acsc_deg_vec(x: &[f64]) -> Vec<f64> {
+pub fn acsc_deg_vec<T: Real>(x: &[T]) -> Vec<T> {
     x.iter().map(|&x| acsc_deg(x)).collect()
 }
 
@@ -829,7 +1196,7 @@ pub fn acsc_deg_vec(x: &[f64]) -> Vec<f64> {
 /// assert_eq!(sec_vec(&[0.0, 1e-10, 1.0471975512]), [1.0, 1.0, 2.0]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn sec_vec(x: &[f64]) -> Vec<f64> {
+pub fn sec_vec<T: Real>(x: &[T]) -> Vec<T> {
     x.iter().map(|&x| sec(x)).collect()
 }
 
@@ -846,7 +1213,7 @@ pub fn sec_vec(x: &[f64]) -> Vec<f64> {
 /// assert_eq!(sec_deg_vec(&[0.0, 30.0, 45.0, 60.0, 90.0]), [1.0, 1.1547005384, 1.4142135623, 2.0, -inf]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn sec_deg_vec(x: &[f64]) -> Vec<f64> {
+pub fn sec_deg_vec<T: Real>(x: &[T]) -> Vec<T> {
     x.iter().map(|&x| sec_deg(x)).collect()
 }
 
@@ -862,7 +1229,7 @@ pub fn sec_deg_vec(x: &[f64]) -> Vec<f64> {
 /// assert_eq!(asec_vec(&[1.0, 1.1547005384, 1.4142135623, 2.0, -inf, -1.0]), [0.0, 0.5235987756, 0.7853981634, 1.0471975512, 1.5707963268, 3.1415926536]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn asec_vec(x: &[f64]) -> Vec<f64> {
+pub fn asec_vec<T: Real>(x: &[T]) -> Vec<T> {
     x.iter().map(|&x| asec(x)).collect()
 }
 
@@ -878,7 +1245,7 @@ pub fn asec_vec(x: &[f64]) -> Vec<f64> {
 /// assert_eq!(asec_deg_vec(&[1.0, 1.1547005384, 1.4142135623, 2.0, -inf, -1.0]), [0.0, 30.0, 45.0, 60.0, 90.0, 180.0]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn asec_deg_vec(x: &[f64]) -> Vec<f64> {
+pub fn asec_deg_vec<T: Real>(x: &[T]) -> Vec<T> {
     x.iter().map(|&x| asec_deg(x)).collect()
 }
 
@@ -895,7 +1262,7 @@ pub fn asec_deg_vec(x: &[f64]) -> Vec<f64> {
 /// assert_eq!(cot_vec(&[0.0, 1e-10, 1.0471975512]), [inf, 10000000000.0, 0.5773502692]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn cot_vec(x: &[f64]) -> Vec<f64> {
+pub fn cot_vec<T: Real>(x: &[T]) -> Vec<T> {
     x.iter().map(|&x| cot(x)).collect()
 }
 
@@ -912,7 +1279,7 @@ pub fn cot_vec(x: &[f64]) -> Vec<f64> {
 /// assert_eq!(cot_deg_vec(&[0.0, 30.0, 45.0, 60.0, 90.0]), [inf, 1.7320508076, 1.0, 0.5773502692, 0.0]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn cot_deg_vec(x: &[f64]) -> Vec<f64> {
+pub fn cot_deg_vec<T: Real>(x: &[T]) -> Vec<T> {
     x.iter().map(|&x| cot_deg(x)).collect()
 }
 
@@ -928,7 +1295,7 @@ pub fn cot_deg_vec(x: &[f64]) -> Vec<f64> {
 /// assert_eq!(acot_vec(&[inf, 1.7320508076, 1.0, 0.5773502692, 0.0]), [0.0, 0.5235987756, 0.7853981634, 1.0471975512, 1.5707963268]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn acot_vec(x: &[f64]) -> Vec<f64> {
+pub fn acot_vec<T: Real>(x: &[T]) -> Vec<T> {
     x.iter().map(|&x| acot(x)).collect()
 }
 
@@ -944,7 +1311,7 @@ pub fn acot_vec(x: &[f64]) -> Vec<f64> {
 /// assert_eq!(acot_deg_vec(&[inf, 1.7320508076, 1.0, 0.5773502692, 0.0]), [0.0, 30.0, 45.0, 60.0, 90.0]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn acot_deg_vec(x: &[f64]) -> Vec<f64> {
+pub fn acot_deg_vec<T: Real>(x: &[T]) -> Vec<T> {
     x.iter().map(|&x| acot_deg(x)).collect()
 }
 
@@ -961,7 +1328,7 @@ pub fn acot_deg_vec(x: &[f64]) -> Vec<f64> {
 /// assert_eq!(sinh_vec(&[0.0, 0.523598775598299, 3.141592653589793]), [0.0, 0.547853473888040, 11.548739357257748]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn sinh_vec(x: &[f64]) -> Vec<f64> {
+pub fn sinh_vec<T: Real>(x: &[T]) -> Vec<T> {
     x.iter().map(|&x| sinh(x)).collect()
 }
 
@@ -978,7 +1345,7 @@ pub fn sinh_vec(x: &[f64]) -> Vec<f64> {
 /// assert_eq!(sinh_deg_vec(&[0.0, 30.0, 180.0]), [0.0, 0.547853473888040, 11.548739357257748]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn sinh_deg_vec(x: &[f64]) -> Vec<f64> {
+pub fn sinh_deg_vec<T: Real>(x: &[T]) -> Vec<T> {
     x.iter().map(|&x| sinh_deg(x)).collect()
 }
 
@@ -995,7 +1362,7 @@ pub fn sinh_deg_vec(x: &[f64]) -> Vec<f64> {
 /// assert_eq!(cosh_vec(&[0.0, 0.523598775598299, 3.141592653589793]), [1.0, 1.140238321076429, 11.591953275521519]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn cosh_vec(x: &[f64]) -> Vec<f64> {
+pub fn cosh_vec<T: Real>(x: &[T]) -> Vec<T> {
     x.iter().map(|&x| cosh(x)).collect()
 }
 
@@ -1012,7 +1379,7 @@ pub fn cosh_vec(x: &[f64]) -> Vec<f64> {
 /// assert_eq!(cosh_deg_vec(&[0.0, 30.0, 180.0]), [1.0, 1.140238321076429, 11.591953275521519]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn cosh_deg_vec(x: &[f64]) -> Vec<f64> {
+pub fn cosh_deg_vec<T: Real>(x: &[T]) -> Vec<T> {
     x.iter().map(|&x| cosh_deg(x)).collect()
 }
 
@@ -1029,7 +1396,7 @@ pub fn cosh_deg_vec(x: &[f64]) -> Vec<f64> {
 /// assert_eq!(tanh_vec(&[0.0, 0.523598775598299, 3.141592653589793]), [0.0, 0.480472778156452, 0.99627207622075]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn tanh_vec(x: &[f64]) -> Vec<f64> {
+pub fn tanh_vec<T: Real>(x: &[T]) -> Vec<T> {
     x.iter().map(|&x| tanh(x)).collect()
 }
 
@@ -1046,7 +1413,7 @@ pub fn tanh_vec(x: &[f64]) -> Vec<f64> {
 /// assert_eq!(tanh_deg_vec(&[0.0, 30.0, 180.0]), [0.0, 0.480472778156452, 0.99627207622075]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn tanh_deg_vec(x: &[f64]) -> Vec<f64> {
+pub fn tanh_deg_vec<T: Real>(x: &[T]) -> Vec<T> {
     x.iter().map(|&x| tanh_deg(x)).collect()
 }
 
@@ -1063,7 +1430,7 @@ pub fn tanh_deg_vec(x: &[f64]) -> Vec<f64> {
 /// assert_eq!(csch_vec(&[0.0, 0.523598775598299, 3.141592653589793]), [inf, 1.825305574687952, 0.086589537530047]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn csch_vec(x: &[f64]) -> Vec<f64> {
+pub fn csch_vec<T: Real>(x: &[T]) -> Vec<T> {
     x.iter().map(|&x| csch(x)).collect()
 }
 
@@ -1080,7 +1447,7 @@ pub fn csch_vec(x: &[f64]) -> Vec<f64> {
 /// assert_eq!(csch_deg_vec(&[0.0, 30.0, 180.0]), [inf, 1.825305574687954, 0.086589537530047]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn csch_deg_vec(x: &[f64]) -> Vec<f64> {
+pub fn csch_deg_vec<T: Real>(x: &[T]) -> Vec<T> {
     x.iter().map(|&x| csch_deg(x)).collect()
 }
 
@@ -1097,7 +1464,7 @@ pub fn csch_deg_vec(x: &[f64]) -> Vec<f64> {
 /// assert_eq!(sech_vec(&[0.0, 0.523598775598299, 3.141592653589793]), [1.0, 0.877009640454779, 0.086266738334054]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn sech_vec(x: &[f64]) -> Vec<f64> {
+pub fn sech_vec<T: Real>(x: &[T]) -> Vec<T> {
     x.iter().map(|&x| sech(x)).collect()
 }
 
@@ -1114,7 +1481,7 @@ pub fn sech_vec(x: &[f64]) -> Vec<f64> {
 /// assert_eq!(sech_deg_vec(&[0.0, 30.0, 180.0]), [1.0, 0.877009640454779, 0.086266738334054]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn sech_deg_vec(x: &[f64]) -> Vec<f64> {
+pub fn sech_deg_vec<T: Real>(x: &[T]) -> Vec<T> {
     x.iter().map(|&x| sech_deg(x)).collect()
 }
 
@@ -1131,7 +1498,7 @@ pub fn sech_deg_vec(x: &[f64]) -> Vec<f64> {
 /// assert_eq!(coth_vec(&[0.0, 0.523598775598299, 3.141592653589793]), [inf, 2.081283363933637, 1.003741873197321]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn coth_vec(x: &[f64]) -> Vec<f64> {
+pub fn coth_vec<T: Real>(x: &[T]) -> Vec<T> {
     x.iter().map(|&x| coth(x)).collect()
 }
 
@@ -1148,7 +1515,7 @@ pub fn coth_vec(x: &[f64]) -> Vec<f64> {
 /// assert_eq!(coth_deg_vec(&[0.0, 30.0, 180.0]), [inf, 2.081283363933638, 1.003741873197321]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn coth_deg_vec(x: &[f64]) -> Vec<f64> {
+pub fn coth_deg_vec<T: Real>(x: &[T]) -> Vec<T> {
     x.iter().map(|&x| coth_deg(x)).collect()
 }
 
@@ -1165,7 +1532,7 @@ pub fn coth_deg_vec(x: &[f64]) -> Vec<f64> {
 /// assert_eq!(asinh_vec(&[0.0, 0.547853473888040, 11.548739357257748]), [0.0, 0.523598775598299, 3.141592653589793]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn asinh_vec(x: &[f64]) -> Vec<f64> {
+pub fn asinh_vec<T: Real>(x: &[T]) -> Vec<T> {
     x.iter().map(|&x| asinh(x)).collect()
 }
 
@@ -1182,7 +1549,7 @@ pub fn asinh_vec(x: &[f64]) -> Vec<f64> {
 /// assert_eq!(asinh_deg_vec(&[0.0, 0.547853473888040, 11.548739357257748]), [0.0, 30.0, 180.0]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn asinh_deg_vec(x: &[f64]) -> Vec<f64> {
+pub fn asinh_deg_vec<T: Real>(x: &[T]) -> Vec<T> {
     x.iter().map(|&x| asinh_deg(x)).collect()
 }
 
@@ -1199,7 +1566,7 @@ pub fn asinh_deg_vec(x: &[f64]) -> Vec<f64> {
 /// assert_eq!(acosh_vec(&[1.0, 1.140238321076429, 11.591953275521519]), [0.0, 0.523598775598299, 3.141592653589793]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn acosh_vec(x: &[f64]) -> Vec<f64> {
+pub fn acosh_vec<T: Real>(x: &[T]) -> Vec<T> {
     x.iter().map(|&x| acosh(x)).collect()
 }
 
@@ -1216,7 +1583,7 @@ pub fn acosh_vec(x: &[f64]) -> Vec<f64> {
 /// assert_eq!(acosh_deg_vec(&[1.0, 1.140238321076429, 11.591953275521519]), [0.0, 30.0, 180.0]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn acosh_deg_vec(x: &[f64]) -> Vec<f64> {
+pub fn acosh_deg_vec<T: Real>(x: &[T]) -> Vec<T> {
     x.iter().map(|&x| acosh_deg(x)).collect()
 }
 
@@ -1233,7 +1600,7 @@ pub fn acosh_deg_vec(x: &[f64]) -> Vec<f64> {
 /// assert_eq!(atanh_vec(&[0.0, 0.480472778156452, 0.99627207622075]), [0.0, 0.523598775598299, 3.141592653589798]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn atanh_vec(x: &[f64]) -> Vec<f64> {
+pub fn atanh_vec<T: Real>(x: &[T]) -> Vec<T> {
     x.iter().map(|&x| atanh(x)).collect()
 }
 
@@ -1250,7 +1617,7 @@ pub fn atanh_vec(x: &[f64]) -> Vec<f64> {
 /// assert_eq!(atanh_deg_vec(&[0.0, 0.480472778156452, 0.99627207622075]), [0.0, 30.0, 180.0]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn atanh_deg_vec(x: &[f64]) -> Vec<f64> {
+pub fn atanh_deg_vec<T: Real>(x: &[T]) -> Vec<T> {
     x.iter().map(|&x| atanh_deg(x)).collect()
 }
 
@@ -1267,7 +1634,7 @@ pub fn atanh_deg_vec(x: &[f64]) -> Vec<f64> {
 /// assert_eq!(acsch_vec(&[0.0, 1.825305574687952, 0.086589537530047]), [inf, 0.523598775598299, 3.141592653589793]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn acsch_vec(x: &[f64]) -> Vec<f64> {
+pub fn acsch_vec<T: Real>(x: &[T]) -> Vec<T> {
     x.iter().map(|&x| acsch(x)).collect()
 }
 
@@ -1284,7 +1651,7 @@ pub fn acsch_vec(x: &[f64]) -> Vec<f64> {
 /// assert_eq!(acsch_deg_vec(&[0.0, 1.825305574687952, 0.086589537530047]), [inf, 30.0, 180.0]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn acsch_deg_vec(x: &[f64]) -> Vec<f64> {
+pub fn acsch_deg_vec<T: Real>(x: &[T]) -> Vec<T> {
     x.iter().map(|&x| acsch_deg(x)).collect()
 }
 
@@ -1301,7 +1668,7 @@ pub fn acsch_deg_vec(x: &[f64]) -> Vec<f64> {
 /// assert_eq!(asech_vec(&[0.0, 0.877009640454779, 0.086266738334054]), [inf, 0.523598775598299, 3.141592653589798]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn asech_vec(x: &[f64]) -> Vec<f64> {
+pub fn asech_vec<T: Real>(x: &[T]) -> Vec<T> {
     x.iter().map(|&x| asech(x)).collect()
 }
 
@@ -1318,7 +1685,7 @@ pub fn asech_vec(x: &[f64]) -> Vec<f64> {
 /// assert_eq!(asech_deg_vec(&[0.0, 0.877009640454779, 0.086266738334054]), [inf, 30.0, 180.0]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn asech_deg_vec(x: &[f64]) -> Vec<f64> {
+pub fn asech_deg_vec<T: Real>(x: &[T]) -> Vec<T> {
     x.iter().map(|&x| asech_deg(x)).collect()
 }
 
@@ -1335,7 +1702,7 @@ pub fn asech_deg_vec(x: &[f64]) -> Vec<f64> {
 /// assert_eq!(acoth_vec(&[inf, 2.081283363933637, 1.003741873197321]), [0.0, 0.523598775598299, 3.141592653589813]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn acoth_vec(x: &[f64]) -> Vec<f64> {
+pub fn acoth_vec<T: Real>(x: &[T]) -> Vec<T> {
     x.iter().map(|&x| acoth(x)).collect()
 }
 
@@ -1352,7 +1719,7 @@ pub fn acoth_vec(x: &[f64]) -> Vec<f64> {
 /// assert_eq!(acoth_deg_vec(&[inf, 2.081283363933637, 1.003741873197321]), [0.0, 30.0, 180.0]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn acoth_deg_vec(x: &[f64]) -> Vec<f64> {
+pub fn acoth_deg_vec<T: Real>(x: &[T]) -> Vec<T> {
     x.iter().map(|&x| acoth_deg(x)).collect()
 }
 
@@ -1364,6 +1731,8 @@ pub fn acoth_deg_vec(x: &[f64]) -> Vec<f64> {
 /// random number to be generated. The function will cap each size at 19 to ensure the generated numbers fit
 /// within the limits of `u64`, preventing overflow.
 ///
+/// The numbers come from `rand`, so `mathlab::random::seed` makes the whole vector reproducible.
+///
 /// ### Parameters
 /// - `size`: A slice containing desired digit counts for each random number. Each value must be greater than 0.
 ///
@@ -1,4 +1,5 @@
 use super::num::{add, divi, mult, nrt, perimeter, pow, rem, subt};
+use crate::real::Real;
 
 /// ### add_vec_num(x, y)
 ///
@@ -16,7 +17,7 @@ use super::num::{add, divi, mult, nrt, perimeter, pow, rem, subt};
 /// assert_eq!(fround_vec(&add_vec_num(&[0.0, 0.1, 0.2], 0.1)), [0.1, 0.2, 0.3]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn add_vec_num(x: &[f64], y: f64) -> Vec<f64> {
+pub fn add_vec_num<T: Real>(x: &[T], y: T) -> Vec<T> {
     x.iter().map(|&x| add(x, y)).collect()
 }
 
@@ -36,7 +37,7 @@ pub fn add_vec_num(x: &[f64], y: f64) -> Vec<f64> {
 /// assert_eq!(fround_vec(&subt_vec_num(&[0.0, 0.1, 0.2, 0.3], 0.3)), [-0.3, -0.2, -0.1, 0.0]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn subt_vec_num(x: &[f64], y: f64) -> Vec<f64> {
+pub fn subt_vec_num<T: Real>(x: &[T], y: T) -> Vec<T> {
     x.iter().map(|&x| subt(x, y)).collect()
 }
 
@@ -56,7 +57,7 @@ pub fn subt_vec_num(x: &[f64], y: f64) -> Vec<f64> {
 /// assert_eq!(fround_vec(&mult_vec_num(&[0.0, 0.1, 0.2], 0.1)), [0.0, 0.01, 0.02]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn mult_vec_num(x: &[f64], y: f64) -> Vec<f64> {
+pub fn mult_vec_num<T: Real>(x: &[T], y: T) -> Vec<T> {
     x.iter().map(|&x| mult(x, y)).collect()
 }
 
@@ -76,7 +77,7 @@ pub fn mult_vec_num(x: &[f64], y: f64) -> Vec<f64> {
 /// //assert_eq!(fround_vec(divi_vec_num(&[0.0, 0.1, 0.2, 0.3], 0.2)), [0.0, 0.5, 1.0, 1.5]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn divi_vec_num(x: &[f64], y: f64) -> Vec<f64> {
+pub fn divi_vec_num<T: Real>(x: &[T], y: T) -> Vec<T> {
     x.iter().map(|&x| divi(x, y)).collect()
 }
 
@@ -95,7 +96,7 @@ pub fn divi_vec_num(x: &[f64], y: f64) -> Vec<f64> {
 /// assert_eq!(pow_vec_num(&[3.0, 0.0, 4.0, INF_F64], 2.0), [9.0, 0.0, 16.0, INF_F64]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn pow_vec_num(x: &[f64], y: f64) -> Vec<f64> {
+pub fn pow_vec_num<T: Real>(x: &[T], y: T) -> Vec<T> {
     x.iter().map(|&x| pow(x, y)).collect()
 }
 
@@ -117,7 +118,7 @@ pub fn pow_vec_num(x: &[f64], y: f64) -> Vec<f64> {
 /// assert_eq!(rem_vec_num(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0], 3.0), [1.0, 2.0, 0.0, 1.0, 2.0, 0.0, 1.0]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn rem_vec_num(x: &[f64], y: f64) -> Vec<f64> {
+pub fn rem_vec_num<T: Real>(x: &[T], y: T) -> Vec<T> {
     x.iter().map(|&x| rem(x, y)).collect()
 }
 
@@ -136,7 +137,7 @@ pub fn rem_vec_num(x: &[f64], y: f64) -> Vec<f64> {
 /// assert_eq!(fix64_vec(&nrt_vec_num(&[27.0, 64.0, 125.0], 3.0)), [3.0, 4.0, 5.0]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn nrt_vec_num(x: &[f64], n: f64) -> Vec<f64> {
+pub fn nrt_vec_num<T: Real>(x: &[T], n: T) -> Vec<T> {
     x.iter().map(|&x| nrt(x, n)).collect()
 }
 
@@ -152,6 +153,6 @@ pub fn nrt_vec_num(x: &[f64], n: f64) -> Vec<f64> {
 /// assert_eq!(perimeter_vec_num(&[0.0, 1.0, 2.0, inf], 1.0), [2.0, 4.0, 6.0, inf]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn perimeter_vec_num(x: &[f64], y: f64) -> Vec<f64> {
+pub fn perimeter_vec_num<T: Real>(x: &[T], y: T) -> Vec<T> {
     x.iter().map(|&x| perimeter(x, y)).collect()
 }
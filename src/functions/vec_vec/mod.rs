@@ -1,4 +1,18 @@
 use super::num::{add, divi, mult, nrt, perimeter, pow, rem, subt};
+use crate::error::MathError;
+use crate::real::Real;
+
+/// Returns an error unless `x` and `y` have the same length.
+pub(crate) fn check_len<T>(x: &[T], y: &[T]) -> Result<(), MathError> {
+    if x.len() == y.len() {
+        Ok(())
+    } else {
+        Err(MathError::DimensionMismatch {
+            expected: x.len(),
+            found: y.len(),
+        })
+    }
+}
 
 /// ### add_vec_vec(x, y)
 ///
@@ -16,10 +30,33 @@ use super::num::{add, divi, mult, nrt, perimeter, pow, rem, subt};
 /// assert_eq!(fround_vec(&add_vec_vec(&[0.0, 0.1, 0.2, 0.3], &[0.3, 0.2, 0.1, 0.0])), [0.3, 0.3, 0.3, 0.3]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn add_vec_vec(x: &[f64], y: &[f64]) -> Vec<f64> {
+pub fn add_vec_vec<T: Real>(x: &[T], y: &[T]) -> Vec<T> {
     x.iter().zip(y.iter()).map(|(&x, &y)| add(x, y)).collect()
 }
 
+/// ### try_add_vec_vec(x, y)
+///
+/// Operation Function
+///
+/// The `try_add_vec_vec` function computes the element-wise sums like `add_vec_vec`,
+/// but returns an error instead of silently truncating to the shorter slice when the lengths differ.
+///
+/// ### Errors
+///
+/// Returns `MathError::DimensionMismatch` if `x` and `y` are not of the same length.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{try_add_vec_vec, MathError};
+/// assert_eq!(try_add_vec_vec(&[1.0, 2.0], &[3.0, 4.0]), Ok(vec![4.0, 6.0]));
+/// assert_eq!(try_add_vec_vec(&[1.0, 2.0], &[1.0]), Err(MathError::DimensionMismatch { expected: 2, found: 1 }));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn try_add_vec_vec<T: Real>(x: &[T], y: &[T]) -> Result<Vec<T>, MathError> {
+    check_len(x, y)?;
+    Ok(add_vec_vec(x, y))
+}
+
 /// ### subt_vec_vec(x, y)
 ///
 /// Operation Function
@@ -34,10 +71,33 @@ pub fn add_vec_vec(x: &[f64], y: &[f64]) -> Vec<f64> {
 /// assert_eq!(subt_vec_vec(&[0.0, 0.1, 0.2, 0.3], &[0.3, 0.2, 0.1, 0.0]), [-0.3, -0.1, 0.1, 0.3]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn subt_vec_vec(x: &[f64], y: &[f64]) -> Vec<f64> {
+pub fn subt_vec_vec<T: Real>(x: &[T], y: &[T]) -> Vec<T> {
     x.iter().zip(y.iter()).map(|(&x, &y)| subt(x, y)).collect()
 }
 
+/// ### try_subt_vec_vec(x, y)
+///
+/// Operation Function
+///
+/// The `try_subt_vec_vec` function computes the element-wise differences like `subt_vec_vec`,
+/// but returns an error instead of silently truncating to the shorter slice when the lengths differ.
+///
+/// ### Errors
+///
+/// Returns `MathError::DimensionMismatch` if `x` and `y` are not of the same length.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{try_subt_vec_vec, MathError};
+/// assert_eq!(try_subt_vec_vec(&[1.0, 2.0], &[3.0, 4.0]), Ok(vec![-2.0, -2.0]));
+/// assert_eq!(try_subt_vec_vec(&[1.0, 2.0], &[1.0]), Err(MathError::DimensionMismatch { expected: 2, found: 1 }));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn try_subt_vec_vec<T: Real>(x: &[T], y: &[T]) -> Result<Vec<T>, MathError> {
+    check_len(x, y)?;
+    Ok(subt_vec_vec(x, y))
+}
+
 /// ### mult_vec_vec(x, y)
 ///
 /// Operation Function
@@ -54,10 +114,33 @@ pub fn subt_vec_vec(x: &[f64], y: &[f64]) -> Vec<f64> {
 /// assert_eq!(fround_vec(&mult_vec_vec(&[0.0, 0.1, 0.2, 0.3], &[0.3, 0.2, 0.1, 0.0])), [0.0, 0.02, 0.02, 0.0]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn mult_vec_vec(x: &[f64], y: &[f64]) -> Vec<f64> {
+pub fn mult_vec_vec<T: Real>(x: &[T], y: &[T]) -> Vec<T> {
     x.iter().zip(y.iter()).map(|(&x, &y)| mult(x, y)).collect()
 }
 
+/// ### try_mult_vec_vec(x, y)
+///
+/// Operation Function
+///
+/// The `try_mult_vec_vec` function computes the element-wise products like `mult_vec_vec`,
+/// but returns an error instead of silently truncating to the shorter slice when the lengths differ.
+///
+/// ### Errors
+///
+/// Returns `MathError::DimensionMismatch` if `x` and `y` are not of the same length.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{try_mult_vec_vec, MathError};
+/// assert_eq!(try_mult_vec_vec(&[1.0, 2.0], &[3.0, 4.0]), Ok(vec![3.0, 8.0]));
+/// assert_eq!(try_mult_vec_vec(&[1.0, 2.0], &[1.0]), Err(MathError::DimensionMismatch { expected: 2, found: 1 }));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn try_mult_vec_vec<T: Real>(x: &[T], y: &[T]) -> Result<Vec<T>, MathError> {
+    check_len(x, y)?;
+    Ok(mult_vec_vec(x, y))
+}
+
 /// ### divi_vec_vec(x, y)
 ///
 /// Operation Function
@@ -72,10 +155,33 @@ pub fn mult_vec_vec(x: &[f64], y: &[f64]) -> Vec<f64> {
 /// assert_eq!(divi_vec_vec(&[0.0, 0.1, 0.2, 0.3], &[0.3, 0.2, 0.1, 0.0]), [0.0, 0.5, 2.0, INF_F64]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn divi_vec_vec(x: &[f64], y: &[f64]) -> Vec<f64> {
+pub fn divi_vec_vec<T: Real>(x: &[T], y: &[T]) -> Vec<T> {
     x.iter().zip(y.iter()).map(|(&x, &y)| divi(x, y)).collect()
 }
 
+/// ### try_divi_vec_vec(x, y)
+///
+/// Operation Function
+///
+/// The `try_divi_vec_vec` function computes the element-wise quotients like `divi_vec_vec`,
+/// but returns an error instead of silently truncating to the shorter slice when the lengths differ.
+///
+/// ### Errors
+///
+/// Returns `MathError::DimensionMismatch` if `x` and `y` are not of the same length.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{try_divi_vec_vec, MathError};
+/// assert_eq!(try_divi_vec_vec(&[3.0, 2.0], &[3.0, 4.0]), Ok(vec![1.0, 0.5]));
+/// assert_eq!(try_divi_vec_vec(&[1.0, 2.0], &[1.0]), Err(MathError::DimensionMismatch { expected: 2, found: 1 }));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn try_divi_vec_vec<T: Real>(x: &[T], y: &[T]) -> Result<Vec<T>, MathError> {
+    check_len(x, y)?;
+    Ok(divi_vec_vec(x, y))
+}
+
 /// ### pow_vec_vec(x, y)
 ///
 /// Operation Function
@@ -90,10 +196,33 @@ pub fn divi_vec_vec(x: &[f64], y: &[f64]) -> Vec<f64> {
 /// assert_eq!(pow_vec_vec(&[0.0, 0.1, 0.2, 0.3], &[0.3, 0.2, 0.1, 0.0]), [0.0, 0.6309573444801932, 0.8513399225207846, 1.0]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn pow_vec_vec(x: &[f64], y: &[f64]) -> Vec<f64> {
+pub fn pow_vec_vec<T: Real>(x: &[T], y: &[T]) -> Vec<T> {
     x.iter().zip(y.iter()).map(|(&x, &y)| pow(x, y)).collect()
 }
 
+/// ### try_pow_vec_vec(x, y)
+///
+/// Operation Function
+///
+/// The `try_pow_vec_vec` function computes the element-wise powers like `pow_vec_vec`,
+/// but returns an error instead of silently truncating to the shorter slice when the lengths differ.
+///
+/// ### Errors
+///
+/// Returns `MathError::DimensionMismatch` if `x` and `y` are not of the same length.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{try_pow_vec_vec, MathError};
+/// assert_eq!(try_pow_vec_vec(&[2.0, 3.0], &[3.0, 2.0]), Ok(vec![8.0, 9.0]));
+/// assert_eq!(try_pow_vec_vec(&[1.0, 2.0], &[1.0]), Err(MathError::DimensionMismatch { expected: 2, found: 1 }));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn try_pow_vec_vec<T: Real>(x: &[T], y: &[T]) -> Result<Vec<T>, MathError> {
+    check_len(x, y)?;
+    Ok(pow_vec_vec(x, y))
+}
+
 /// ### rem_vec_vec(x, y)
 ///
 /// Operation Function
@@ -113,10 +242,33 @@ pub fn pow_vec_vec(x: &[f64], y: &[f64]) -> Vec<f64> {
 /// assert_eq!(rem_vec_vec(&[1.0, 2.0, 3.0, 4.0], &[4.0, 3.0, 2.0, 1.0]), [1.0, 2.0, 1.0, 0.0]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn rem_vec_vec(x: &[f64], y: &[f64]) -> Vec<f64> {
+pub fn rem_vec_vec<T: Real>(x: &[T], y: &[T]) -> Vec<T> {
     x.iter().zip(y.iter()).map(|(&x, &y)| rem(x, y)).collect()
 }
 
+/// ### try_rem_vec_vec(x, y)
+///
+/// Operation Function
+///
+/// The `try_rem_vec_vec` function computes the element-wise remainders like `rem_vec_vec`,
+/// but returns an error instead of silently truncating to the shorter slice when the lengths differ.
+///
+/// ### Errors
+///
+/// Returns `MathError::DimensionMismatch` if `x` and `y` are not of the same length.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{try_rem_vec_vec, MathError};
+/// assert_eq!(try_rem_vec_vec(&[5.0, 7.0], &[3.0, 4.0]), Ok(vec![2.0, 3.0]));
+/// assert_eq!(try_rem_vec_vec(&[1.0, 2.0], &[1.0]), Err(MathError::DimensionMismatch { expected: 2, found: 1 }));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn try_rem_vec_vec<T: Real>(x: &[T], y: &[T]) -> Result<Vec<T>, MathError> {
+    check_len(x, y)?;
+    Ok(rem_vec_vec(x, y))
+}
+
 /// ### nrt_vec_vec(x, n)
 ///
 /// Operation Function
@@ -131,10 +283,33 @@ pub fn rem_vec_vec(x: &[f64], y: &[f64]) -> Vec<f64> {
 /// assert_eq!(nrt_vec_vec(&[1.0, 4.0, 27.0, 9.0], &[1.0, 2.0, 3.0, 2.0]), [1.0, 2.0, 3.0, 3.0]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn nrt_vec_vec(x: &[f64], n: &[f64]) -> Vec<f64> {
+pub fn nrt_vec_vec<T: Real>(x: &[T], n: &[T]) -> Vec<T> {
     x.iter().zip(n.iter()).map(|(&x, &n)| nrt(x, n)).collect()
 }
 
+/// ### try_nrt_vec_vec(x, n)
+///
+/// Operation Function
+///
+/// The `try_nrt_vec_vec` function computes the element-wise roots like `nrt_vec_vec`,
+/// but returns an error instead of silently truncating to the shorter slice when the lengths differ.
+///
+/// ### Errors
+///
+/// Returns `MathError::DimensionMismatch` if `x` and `n` are not of the same length.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{try_nrt_vec_vec, MathError};
+/// assert_eq!(try_nrt_vec_vec(&[4.0, 27.0], &[2.0, 3.0]), Ok(vec![2.0, 3.0]));
+/// assert_eq!(try_nrt_vec_vec(&[1.0, 2.0], &[1.0]), Err(MathError::DimensionMismatch { expected: 2, found: 1 }));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn try_nrt_vec_vec<T: Real>(x: &[T], n: &[T]) -> Result<Vec<T>, MathError> {
+    check_len(x, n)?;
+    Ok(nrt_vec_vec(x, n))
+}
+
 /// ### perimeter_vec_vec(x, y)
 ///
 /// Geometry Function
@@ -147,9 +322,32 @@ pub fn nrt_vec_vec(x: &[f64], n: &[f64]) -> Vec<f64> {
 /// assert_eq!(perimeter_vec_vec(&[0.0, 1.0, 1.0, 2.0, inf], &[0.0, 0.0, 1.0, 1.0, 1.0]), [0.0, 2.0, 4.0, 6.0, inf]);
 /// ```
 /// <small>End Fun Doc</small>
-pub fn perimeter_vec_vec(x: &[f64], y: &[f64]) -> Vec<f64> {
+pub fn perimeter_vec_vec<T: Real>(x: &[T], y: &[T]) -> Vec<T> {
     x.iter()
         .zip(y.iter())
         .map(|(&x, &y)| perimeter(x, y))
         .collect()
 }
+
+/// ### try_perimeter_vec_vec(x, y)
+///
+/// Geometry Function
+///
+/// The `try_perimeter_vec_vec` function computes the element-wise perimeters like `perimeter_vec_vec`,
+/// but returns an error instead of silently truncating to the shorter slice when the lengths differ.
+///
+/// ### Errors
+///
+/// Returns `MathError::DimensionMismatch` if `x` and `y` are not of the same length.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{try_perimeter_vec_vec, MathError};
+/// assert_eq!(try_perimeter_vec_vec(&[1.0, 2.0], &[1.0, 1.0]), Ok(vec![4.0, 6.0]));
+/// assert_eq!(try_perimeter_vec_vec(&[1.0, 2.0], &[1.0]), Err(MathError::DimensionMismatch { expected: 2, found: 1 }));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn try_perimeter_vec_vec<T: Real>(x: &[T], y: &[T]) -> Result<Vec<T>, MathError> {
+    check_len(x, y)?;
+    Ok(perimeter_vec_vec(x, y))
+}
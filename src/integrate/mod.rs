@@ -0,0 +1,437 @@
+use crate::error::MathError;
+use std::cell::RefCell;
+
+/// The largest number of subintervals `quad` and `dblquad` split an integral into (MATLAB's `MaxIntervalCount`).
+const MAX_INTERVALS: usize = 650;
+
+/// Abscissae of the 15-point Kronrod rule on `[-1, 1]`; the odd entries are the nodes of the 7-point Gauss rule.
+const XGK: [f64; 8] = [
+    0.9914553711208126,
+    0.9491079123427585,
+    0.8648644233597691,
+    0.7415311855993945,
+    0.5860872354676911,
+    0.4058451513773972,
+    0.2077849550078985,
+    0.0,
+];
+
+/// Weights of the 15-point Kronrod rule.
+const WGK: [f64; 8] = [
+    0.02293532201052922,
+    0.06309209262997855,
+    0.1047900103222502,
+    0.1406532597155259,
+    0.1690047266392679,
+    0.1903505780647854,
+    0.2044329400752989,
+    0.2094821410847278,
+];
+
+/// Weights of the 7-point Gauss rule.
+const WG: [f64; 4] = [
+    0.1294849661688697,
+    0.2797053914892767,
+    0.3818300505051189,
+    0.4179591836734694,
+];
+
+/// Checks that `x` and `y` are non-empty samples of the same length.
+fn check_samples(x: &[f64], y: &[f64]) -> Result<(), MathError> {
+    if x.len() != y.len() {
+        Err(MathError::DimensionMismatch {
+            expected: x.len(),
+            found: y.len(),
+        })
+    } else if x.is_empty() {
+        Err(MathError::EmptyInput)
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that `tol` is a positive tolerance.
+fn check_tol(tol: f64) -> Result<(), MathError> {
+    if tol > 0.0 {
+        Ok(())
+    } else {
+        Err(MathError::Domain("tolerance must be positive"))
+    }
+}
+
+/// Integrates the samples with piecewise quadratics through consecutive triples of points,
+/// which handles unevenly spaced `x`. An odd number of intervals closes with the last quadratic over the last interval.
+fn simpson_samples(x: &[f64], y: &[f64]) -> f64 {
+    let n = x.len() - 1;
+    let mut sum = 0.0;
+    let mut i = 0;
+    while i + 2 <= n {
+        let (h0, h1) = (x[i + 1] - x[i], x[i + 2] - x[i + 1]);
+        let hs = h0 + h1;
+        sum += hs / 6.0
+            * ((2.0 - h1 / h0) * y[i]
+                + hs * hs / (h0 * h1) * y[i + 1]
+                + (2.0 - h0 / h1) * y[i + 2]);
+        i += 2;
+    }
+    if n % 2 == 1 {
+        let (h0, h1) = (x[n - 1] - x[n - 2], x[n] - x[n - 1]);
+        let alpha = (2.0 * h1 * h1 + 3.0 * h0 * h1) / (6.0 * (h0 + h1));
+        let beta = (h1 * h1 + 3.0 * h0 * h1) / (6.0 * h0);
+        let eta = h1 * h1 * h1 / (6.0 * h0 * (h0 + h1));
+        sum += alpha * y[n] + beta * y[n - 1] - eta * y[n - 2];
+    }
+    sum
+}
+
+/// ### trapz(x, y)
+///
+/// Integration Function
+///
+/// The `trapz` function integrates the samples `y` taken at the points `x` with the trapezoidal rule
+/// (MATLAB's `trapz(x, y)`). The points may be unevenly spaced and in either order.
+///
+/// It returns `(value, error)`, where `error` estimates the error of `value` by its difference from the
+/// piecewise quadratic (Simpson) rule on the same samples. The estimate is `NaN` for fewer than three samples.
+///
+/// ### Errors
+///
+/// Returns `MathError::DimensionMismatch` if `x` and `y` are not of the same length,
+/// and `MathError::EmptyInput` if they are empty.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::integrate::trapz;
+/// use mathlab::math::{fix64, range_from_to, sqr_vec, MathError};
+/// let (value, error) = trapz(&[0.0, 1.0, 2.0], &[0.0, 1.0, 4.0]).unwrap();
+/// assert_eq!((value, fix64(error)), (3.0, fix64(1.0 / 3.0)));
+/// let x = range_from_to(0.0, 1.0, 0.01);
+/// let (value, error) = trapz(&x, &sqr_vec(&x)).unwrap();
+/// assert_eq!(fix64(value), 0.33335);
+/// assert!(error < 1e-4 && (value - 1.0 / 3.0).abs() <= error);
+/// assert_eq!(trapz(&[1.0, 2.0], &[3.0]), Err(MathError::DimensionMismatch { expected: 2, found: 1 }));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn trapz(x: &[f64], y: &[f64]) -> Result<(f64, f64), MathError> {
+    check_samples(x, y)?;
+    let value = x
+        .windows(2)
+        .zip(y.windows(2))
+        .map(|(x, y)| 0.5 * (x[1] - x[0]) * (y[0] + y[1]))
+        .sum::<f64>();
+    let error = if x.len() < 3 {
+        f64::NAN
+    } else {
+        (value - simpson_samples(x, y)).abs()
+    };
+    Ok((value, error))
+}
+
+/// ### cumtrapz(x, y)
+///
+/// Integration Function
+///
+/// The `cumtrapz` function returns the cumulative integral of the samples `y` taken at the points `x`
+/// with the trapezoidal rule (MATLAB's `cumtrapz(x, y)`). The first element is `0` and the last one equals `trapz(x, y)`.
+///
+/// ### Errors
+///
+/// Returns `MathError::DimensionMismatch` if `x` and `y` are not of the same length,
+/// and `MathError::EmptyInput` if they are empty.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::integrate::cumtrapz;
+/// assert_eq!(cumtrapz(&[0.0, 1.0, 2.0, 3.0], &[1.0, 1.0, 3.0, 3.0]), Ok(vec![0.0, 1.0, 3.0, 6.0]));
+/// assert_eq!(cumtrapz(&[5.0], &[2.0]), Ok(vec![0.0]));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn cumtrapz(x: &[f64], y: &[f64]) -> Result<Vec<f64>, MathError> {
+    check_samples(x, y)?;
+    let mut sum = 0.0;
+    let mut out = Vec::with_capacity(x.len());
+    out.push(0.0);
+    for (x, y) in x.windows(2).zip(y.windows(2)) {
+        sum += 0.5 * (x[1] - x[0]) * (y[0] + y[1]);
+        out.push(sum);
+    }
+    Ok(out)
+}
+
+/// ### simpson(f, a, b, n)
+///
+/// Integration Function
+///
+/// The `simpson` function integrates `f` over `[a, b]` with the composite Simpson's rule on `n` equal subintervals,
+/// where `n` is rounded up to a multiple of four.
+///
+/// It returns `(value, error)`, where `error` is the Richardson estimate `|S(n) - S(n/2)| / 15`
+/// from the rule on every other node, which costs no extra evaluations of `f`.
+///
+/// ### Errors
+///
+/// Returns `MathError::Domain` if `a` or `b` is not finite.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::integrate::simpson;
+/// use mathlab::math::{fix64, sin, PI};
+/// assert_eq!(simpson(|x| x * x * x, 0.0, 2.0, 4), Ok((4.0, 0.0)));
+/// let (value, error) = simpson(sin, 0.0, PI, 100).unwrap();
+/// assert_eq!(fix64(value), 2.0);
+/// assert!(error < 1e-6);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn simpson<F: Fn(f64) -> f64>(f: F, a: f64, b: f64, n: usize) -> Result<(f64, f64), MathError> {
+    if !a.is_finite() || !b.is_finite() {
+        return Err(MathError::Domain("bounds must be finite"));
+    }
+    let n = n.max(4).div_ceil(4) * 4;
+    let h = (b - a) / n as f64;
+    let (mut fine, mut coarse) = (0.0, 0.0);
+    for i in 0..=n {
+        let fx = f(if i == n { b } else { a + i as f64 * h });
+        let (wf, wc) = if i == 0 || i == n {
+            (1.0, 1.0)
+        } else if i % 2 == 1 {
+            (4.0, 0.0)
+        } else if i % 4 == 2 {
+            (2.0, 4.0)
+        } else {
+            (2.0, 2.0)
+        };
+        fine += wf * fx;
+        coarse += wc * fx;
+    }
+    let fine = fine * h / 3.0;
+    let coarse = coarse * 2.0 * h / 3.0;
+    Ok((fine, (fine - coarse).abs() / 15.0))
+}
+
+/// The 15-point Gauss–Kronrod rule on `[a, b]` for an integrand returning `(value, aux)`.
+///
+/// Returns the Kronrod estimate of the integral of `value`, its error estimate from the embedded
+/// 7-point Gauss rule (scaled as in QUADPACK's `qk15`), the Kronrod integral of `aux`, and the
+/// rounding error level `50 * eps * integral(|value|)` below which the error estimate never falls.
+fn kronrod<F: Fn(f64) -> (f64, f64)>(f: &F, a: f64, b: f64) -> (f64, f64, f64, f64) {
+    let center = 0.5 * (a + b);
+    let half = 0.5 * (b - a);
+    let (fc, ac) = f(center);
+    let mut res_g = fc * WG[3];
+    let mut res_k = fc * WGK[7];
+    let mut res_abs = fc.abs() * WGK[7];
+    let mut aux = ac * WGK[7];
+    let mut fv = [(0.0, 0.0); 7];
+    for (j, &x) in XGK[..7].iter().enumerate() {
+        let dx = half * x;
+        let (f1, a1) = f(center - dx);
+        let (f2, a2) = f(center + dx);
+        fv[j] = (f1, f2);
+        res_k += WGK[j] * (f1 + f2);
+        res_abs += WGK[j] * (f1.abs() + f2.abs());
+        aux += WGK[j] * (a1 + a2);
+        if j % 2 == 1 {
+            res_g += WG[j / 2] * (f1 + f2);
+        }
+    }
+    let mean = 0.5 * res_k;
+    let res_asc = WGK[7] * (fc - mean).abs()
+        + fv.iter()
+            .zip(WGK.iter())
+            .map(|(&(f1, f2), &w)| w * ((f1 - mean).abs() + (f2 - mean).abs()))
+            .sum::<f64>();
+    let (res_abs, res_asc) = (res_abs * half.abs(), res_asc * half.abs());
+    let mut err = ((res_k - res_g) * half).abs();
+    if res_asc != 0.0 && err != 0.0 {
+        err = res_asc * (200.0 * err / res_asc).powf(1.5).min(1.0);
+    }
+    let roundoff = 50.0 * f64::EPSILON * res_abs;
+    if res_abs > f64::MIN_POSITIVE / (50.0 * f64::EPSILON) {
+        err = err.max(roundoff);
+    }
+    (res_k * half, err, aux * half, roundoff)
+}
+
+/// Global adaptive Gauss–Kronrod quadrature over a finite interval: the subinterval with the largest
+/// error estimate is bisected until the total error meets `tol` (QUADPACK's `qag`), or is down to the
+/// rounding error level of the rule, where bisecting further cannot help (QUADPACK's roundoff exit).
+/// Returns the integral, its error estimate and the integral of the auxiliary output.
+fn adapt<F: Fn(f64) -> (f64, f64)>(
+    f: &F,
+    a: f64,
+    b: f64,
+    tol: f64,
+) -> Result<(f64, f64, f64), MathError> {
+    let (v, e, x, r) = kronrod(f, a, b);
+    let mut parts = vec![(a, b, v, e, x, r)];
+    loop {
+        let (value, error, aux, roundoff) = parts.iter().fold((0.0, 0.0, 0.0, 0.0), |s, p| {
+            (s.0 + p.2, s.1 + p.3, s.2 + p.4, s.3 + p.5)
+        });
+        if !value.is_finite() || !error.is_finite() {
+            return Err(MathError::Domain("integrand is not finite"));
+        }
+        if error <= tol.max(tol * value.abs()).max(2.0 * roundoff) {
+            return Ok((value, error, aux));
+        }
+        let worst = (0..parts.len())
+            .max_by(|&i, &j| parts[i].3.total_cmp(&parts[j].3))
+            .unwrap_or(0);
+        let (lo, hi, ..) = parts[worst];
+        let mid = 0.5 * (lo + hi);
+        if parts.len() >= MAX_INTERVALS || mid <= lo.min(hi) || mid >= lo.max(hi) {
+            return Err(MathError::NoConvergence {
+                iterations: parts.len(),
+            });
+        }
+        let (v1, e1, x1, r1) = kronrod(f, lo, mid);
+        let (v2, e2, x2, r2) = kronrod(f, mid, hi);
+        parts[worst] = (lo, mid, v1, e1, x1, r1);
+        parts.push((mid, hi, v2, e2, x2, r2));
+    }
+}
+
+/// Adaptive quadrature over any interval, mapping infinite bounds to a finite one.
+fn adapt_any<F: Fn(f64) -> (f64, f64)>(
+    f: &F,
+    a: f64,
+    b: f64,
+    tol: f64,
+) -> Result<(f64, f64, f64), MathError> {
+    check_tol(tol)?;
+    if a.is_nan() || b.is_nan() {
+        return Err(MathError::Domain("bounds must not be NaN"));
+    }
+    if a == b {
+        return Ok((0.0, 0.0, 0.0));
+    }
+    if a > b {
+        let (v, e, x) = adapt_any(f, b, a, tol)?;
+        return Ok((-v, e, -x));
+    }
+    let scale = |(v, x): (f64, f64), d: f64| (v * d, x * d);
+    match (a.is_finite(), b.is_finite()) {
+        (true, true) => adapt(f, a, b, tol),
+        // x = a + t / (1 - t) on [0, 1)
+        (true, false) => adapt(
+            &|t: f64| {
+                let s = 1.0 / (1.0 - t);
+                scale(f(a + t * s), s * s)
+            },
+            0.0,
+            1.0,
+            tol,
+        ),
+        // x = b - (1 - t) / t on (0, 1]
+        (false, true) => adapt(
+            &|t: f64| scale(f(b - (1.0 - t) / t), 1.0 / (t * t)),
+            0.0,
+            1.0,
+            tol,
+        ),
+        // x = t / (1 - t²) on (-1, 1)
+        (false, false) => adapt(
+            &|t: f64| {
+                let s = 1.0 / (1.0 - t * t);
+                scale(f(t * s), (1.0 + t * t) * s * s)
+            },
+            -1.0,
+            1.0,
+            tol,
+        ),
+    }
+}
+
+/// ### quad(f, a, b, tol)
+///
+/// Integration Function
+///
+/// The `quad` function integrates `f` over `[a, b]` with global adaptive 15-point Gauss–Kronrod quadrature
+/// (MATLAB's `integral(f, a, b)`). The subinterval with the largest error estimate is bisected until the total
+/// error estimate is at most `max(tol, tol * |value|)`, so `tol` acts as both an absolute and a relative tolerance.
+/// Rounding limits the attainable accuracy to about `50 * eps` times the integral of `|f|` (`eps` is the machine
+/// epsilon, so about `1e-14` relative); smaller tolerances stop at that level, with `error` reporting it.
+///
+/// Either bound may be infinite: the integral is then mapped to a finite interval by a change of variable.
+/// Reversed bounds give the negated integral. The nodes never touch the bounds, so integrable endpoint
+/// singularities such as `1/√x` at `0` are handled.
+///
+/// It returns `(value, error)`, where `error` estimates the absolute error of `value`.
+///
+/// ### Errors
+///
+/// Returns `MathError::Domain` if `tol` is not positive, a bound is `NaN` or `f` returns a non-finite value,
+/// and `MathError::NoConvergence` if the tolerance is not met within 650 subintervals.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::integrate::quad;
+/// use mathlab::math::{exp, fix64, sin, sqrt, INF_F64, NINF_F64, PI};
+/// let (value, error) = quad(sin, 0.0, PI, 1e-10).unwrap();
+/// assert_eq!(fix64(value), 2.0);
+/// assert!(error <= 1e-10);
+/// assert_eq!(fix64(quad(|x| 1.0 / sqrt(x), 0.0, 1.0, 1e-10).unwrap().0), 2.0);
+/// assert_eq!(fix64(quad(|x| exp(-x * x), NINF_F64, INF_F64, 1e-10).unwrap().0), fix64(sqrt(PI)));
+/// assert_eq!(fix64(quad(|x| 1.0 / (1.0 + x * x), 0.0, INF_F64, 1e-10).unwrap().0), fix64(PI / 2.0));
+/// assert_eq!(fix64(quad(|x| x, 1.0, 0.0, 1e-10).unwrap().0), -0.5);
+/// assert!(quad(|x| 1.0 / x, 0.0, 1.0, 1e-10).is_err());
+/// // A tolerance below the rounding error is met as closely as rounding allows
+/// let (value, error) = quad(|x: f64| x.exp(), 0.0, 1.0, 1e-15).unwrap();
+/// assert!((value - (1.0f64.exp() - 1.0)).abs() < 1e-14 && error < 1e-13);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn quad<F: Fn(f64) -> f64>(f: F, a: f64, b: f64, tol: f64) -> Result<(f64, f64), MathError> {
+    let (value, error, _) = adapt_any(&|x| (f(x), 0.0), a, b, tol)?;
+    Ok((value, error))
+}
+
+/// ### dblquad(f, xa, xb, ya, yb, tol)
+///
+/// Integration Function
+///
+/// The `dblquad` function integrates `f(x, y)` over the rectangle `[xa, xb] × [ya, yb]` (MATLAB's `dblquad`)
+/// by nested adaptive Gauss–Kronrod quadrature: an inner `quad` over `y` for every node `x` of an outer `quad`.
+/// The bounds may be infinite, and both levels use the tolerance `tol` as in `quad`.
+///
+/// It returns `(value, error)`, where `error` adds the outer error estimate to the integral over `x`
+/// of the inner error estimates.
+///
+/// ### Errors
+///
+/// Returns the errors of `quad`, from either level.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::integrate::dblquad;
+/// use mathlab::math::{exp, fix64, INF_F64, PI};
+/// let (value, error) = dblquad(|x, y| x * y, 0.0, 1.0, 0.0, 2.0, 1e-10).unwrap();
+/// assert_eq!(fix64(value), 1.0);
+/// assert!(error <= 1e-8);
+/// let gauss = |x: f64, y: f64| exp(-(x * x + y * y));
+/// assert_eq!(fix64(dblquad(gauss, 0.0, INF_F64, 0.0, INF_F64, 1e-10).unwrap().0), fix64(PI / 4.0));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn dblquad<F: Fn(f64, f64) -> f64>(
+    f: F,
+    xa: f64,
+    xb: f64,
+    ya: f64,
+    yb: f64,
+    tol: f64,
+) -> Result<(f64, f64), MathError> {
+    check_tol(tol)?;
+    let failure = RefCell::new(None);
+    let inner = |x: f64| match adapt_any(&|y| (f(x, y), 0.0), ya, yb, tol) {
+        Ok((value, error, _)) => (value, error),
+        Err(e) => {
+            failure.borrow_mut().get_or_insert(e);
+            (f64::NAN, f64::NAN)
+        }
+    };
+    let outer = adapt_any(&inner, xa, xb, tol);
+    if let Some(e) = failure.into_inner() {
+        return Err(e);
+    }
+    let (value, error, inner_error) = outer?;
+    Ok((value, error + inner_error.abs()))
+}
@@ -0,0 +1,587 @@
+use crate::error::MathError;
+use crate::matrix::Matrix;
+
+/// ### Method
+///
+/// Interpolation Method
+///
+/// The `Method` enum selects how `interp1` and `interp2` estimate values between the sample points:
+/// - `Nearest`: the value at the nearest sample point, ties going to the right
+/// - `Linear`: linear interpolation between the neighbouring samples
+/// - `Previous` / `Next`: the value at the sample point just left / right of the query
+/// - `Pchip`: the shape-preserving piecewise cubic Hermite interpolant (MATLAB's `pchip`), which does not overshoot
+/// - `Spline`: the not-a-knot cubic spline (MATLAB's `spline`, see `CubicSpline`), which has a continuous second derivative
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::fix;
+/// use mathlab::interpolate::{interp1, Extrapolation, Method};
+/// let (x, y) = ([0.0, 1.0, 2.0], [0.0, 10.0, 20.0]);
+/// let at = |m| fix(interp1(&x, &y, &[0.4], m, Extrapolation::Nan).unwrap()[0], 12);
+/// assert_eq!((at(Method::Nearest), at(Method::Previous), at(Method::Next)), (0.0, 0.0, 10.0));
+/// assert_eq!((at(Method::Linear), at(Method::Pchip), at(Method::Spline)), (4.0, 4.0, 4.0));
+/// ```
+/// <small>End Type Doc</small>
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Method {
+    Nearest,
+    Linear,
+    Previous,
+    Next,
+    Pchip,
+    Spline,
+}
+
+/// ### Extrapolation
+///
+/// Extrapolation Type
+///
+/// The `Extrapolation` enum selects what `interp1` and `interp2` return for queries outside the sample points:
+/// - `Nan`: `NaN` (MATLAB's default)
+/// - `Clamp`: the value at the nearest end point
+/// - `Extrapolate`: the end piece of the interpolant continued (MATLAB's `"extrap"`);
+///   the piecewise-constant methods `Nearest`, `Previous` and `Next` repeat the end values
+/// - `Value(v)`: the constant `v`
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::interpolate::{interp1, Extrapolation, Method};
+/// let (x, y) = ([0.0, 1.0], [0.0, 2.0]);
+/// let at = |e| interp1(&x, &y, &[3.0], Method::Linear, e).unwrap()[0];
+/// assert!(at(Extrapolation::Nan).is_nan());
+/// assert_eq!(at(Extrapolation::Clamp), 2.0);
+/// assert_eq!(at(Extrapolation::Extrapolate), 6.0);
+/// assert_eq!(at(Extrapolation::Value(-1.0)), -1.0);
+/// ```
+/// <small>End Type Doc</small>
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Extrapolation {
+    Nan,
+    Clamp,
+    Extrapolate,
+    Value(f64),
+}
+
+/// ### Boundary
+///
+/// Spline Boundary Condition
+///
+/// The `Boundary` enum selects the end conditions of a `CubicSpline`:
+/// - `NotAKnot`: the third derivative is continuous at the second and the second-to-last points (MATLAB's `spline`)
+/// - `Natural`: the second derivative is zero at both ends
+/// - `Clamped(a, b)`: the first derivative is `a` at the first point and `b` at the last one
+///
+/// With two points, `NotAKnot` and `Natural` give the straight line; with three, `NotAKnot` gives the parabola
+/// through them.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{fix, CubicSpline};
+/// use mathlab::interpolate::Boundary;
+/// let (x, y) = ([0.0, 1.0, 2.0, 3.0], [0.0, 1.0, 8.0, 27.0]);
+/// // Not-a-knot reproduces any cubic, as do the exact end slopes
+/// assert_eq!(fix(CubicSpline::new(&x, &y, Boundary::NotAKnot).unwrap().eval(1.5), 12), 3.375);
+/// assert_eq!(fix(CubicSpline::new(&x, &y, Boundary::Clamped(0.0, 27.0)).unwrap().eval(1.5), 12), 3.375);
+/// assert_eq!(fix(CubicSpline::new(&x, &y, Boundary::Natural).unwrap().slope(0.0), 12), 0.2);
+/// ```
+/// <small>End Type Doc</small>
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Boundary {
+    NotAKnot,
+    Natural,
+    Clamped(f64, f64),
+}
+
+/// ### CubicSpline
+///
+/// Spline Type
+///
+/// The `CubicSpline` type is a piecewise cubic interpolant of the samples `(x[i], y[i])` with a continuous second derivative.
+/// It is built once with `CubicSpline::new` and then evaluated any number of times with `eval`, `eval_vec` and `slope`;
+/// outside the sample points the end cubics are continued. The end conditions are chosen with `Boundary`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{fix, range_from_to, CubicSpline};
+/// use mathlab::interpolate::Boundary;
+/// let x = range_from_to(0.0, 3.0, 0.25);
+/// let y: Vec<f64> = x.iter().map(|x| x.sin()).collect();
+/// let s = CubicSpline::new(&x, &y, Boundary::NotAKnot).unwrap();
+/// assert_eq!(fix(s.eval(1.1), 4), fix(1.1_f64.sin(), 4));
+/// assert_eq!(fix(s.slope(1.1), 3), fix(1.1_f64.cos(), 3));
+/// assert_eq!(s.knots(), &x[..]);
+/// ```
+/// <small>End Type Doc</small>
+#[derive(Debug, Clone, PartialEq)]
+pub struct CubicSpline {
+    x: Vec<f64>,
+    y: Vec<f64>,
+    slopes: Vec<f64>,
+}
+
+impl CubicSpline {
+    /// ### new(x, y, boundary)
+    ///
+    /// Spline Constructor
+    ///
+    /// The `new` function builds the cubic spline through the points `(x[i], y[i])` with the given end conditions,
+    /// solving a tridiagonal system for the slopes at the sample points.
+    ///
+    /// ### Errors
+    ///
+    /// Returns `MathError::DimensionMismatch` if `x` and `y` are not of the same length,
+    /// and `MathError::Domain` if there are fewer than two points or `x` is not strictly increasing.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::math::{CubicSpline, MathError};
+    /// use mathlab::interpolate::Boundary;
+    /// let s = CubicSpline::new(&[0.0, 1.0, 2.0], &[1.0, 0.0, 1.0], Boundary::NotAKnot).unwrap();
+    /// assert_eq!(s.eval(0.5), 0.25);
+    /// assert!(CubicSpline::new(&[0.0, 0.0], &[1.0, 2.0], Boundary::Natural).is_err());
+    /// assert_eq!(
+    ///     CubicSpline::new(&[0.0, 1.0], &[1.0], Boundary::Natural),
+    ///     Err(MathError::DimensionMismatch { expected: 2, found: 1 })
+    /// );
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn new(x: &[f64], y: &[f64], boundary: Boundary) -> Result<CubicSpline, MathError> {
+        check_samples(x, y)?;
+        Ok(CubicSpline {
+            x: x.to_vec(),
+            y: y.to_vec(),
+            slopes: spline_slopes(x, y, boundary),
+        })
+    }
+
+    /// ### knots()
+    ///
+    /// Spline Property
+    ///
+    /// The `knots` function returns the sample points the spline was built from.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::math::CubicSpline;
+    /// use mathlab::interpolate::Boundary;
+    /// let s = CubicSpline::new(&[0.0, 1.0], &[2.0, 3.0], Boundary::Natural).unwrap();
+    /// assert_eq!(s.knots(), [0.0, 1.0]);
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn knots(&self) -> &[f64] {
+        &self.x
+    }
+
+    /// ### eval(x)
+    ///
+    /// Spline Function
+    ///
+    /// The `eval` function evaluates the spline at `x`, continuing the end cubics outside the sample points.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::math::CubicSpline;
+    /// use mathlab::interpolate::Boundary;
+    /// let s = CubicSpline::new(&[0.0, 1.0, 2.0], &[0.0, 1.0, 4.0], Boundary::NotAKnot).unwrap();
+    /// assert_eq!(s.eval(1.0), 1.0);
+    /// assert_eq!(s.eval(3.0), 9.0);
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn eval(&self, x: f64) -> f64 {
+        hermite(&self.x, &self.y, &self.slopes, x)
+    }
+
+    /// ### eval_vec(x)
+    ///
+    /// Spline Function
+    ///
+    /// The `eval_vec` function evaluates the spline at every element of `x`.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::math::CubicSpline;
+    /// use mathlab::interpolate::Boundary;
+    /// let s = CubicSpline::new(&[0.0, 1.0, 2.0], &[0.0, 1.0, 4.0], Boundary::NotAKnot).unwrap();
+    /// assert_eq!(s.eval_vec(&[0.5, 1.5]), [0.25, 2.25]);
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn eval_vec(&self, x: &[f64]) -> Vec<f64> {
+        x.iter().map(|&xi| self.eval(xi)).collect()
+    }
+
+    /// ### slope(x)
+    ///
+    /// Spline Function
+    ///
+    /// The `slope` function evaluates the first derivative of the spline at `x`.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::math::CubicSpline;
+    /// use mathlab::interpolate::Boundary;
+    /// let s = CubicSpline::new(&[0.0, 1.0, 2.0], &[0.0, 1.0, 4.0], Boundary::NotAKnot).unwrap();
+    /// assert_eq!(s.slope(1.5), 3.0);
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn slope(&self, x: f64) -> f64 {
+        hermite_slope(&self.x, &self.y, &self.slopes, x)
+    }
+}
+
+/// Checks that `x` and `y` have the same length, at least two points and strictly increasing `x`.
+fn check_samples(x: &[f64], y: &[f64]) -> Result<(), MathError> {
+    if x.len() != y.len() {
+        Err(MathError::DimensionMismatch {
+            expected: x.len(),
+            found: y.len(),
+        })
+    } else {
+        check_points(x)
+    }
+}
+
+/// Checks that there are at least two sample points, finite and strictly increasing.
+fn check_points(x: &[f64]) -> Result<(), MathError> {
+    if x.len() < 2 {
+        Err(MathError::Domain("at least two sample points are required"))
+    } else if x.iter().any(|v| !v.is_finite()) || x.windows(2).any(|w| w[1] <= w[0]) {
+        Err(MathError::Domain(
+            "sample points must be finite and strictly increasing",
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Returns the index `k` of the interval `[x[k], x[k + 1]]` used for `xi`, the end intervals covering the outside.
+fn interval(x: &[f64], xi: f64) -> usize {
+    x.partition_point(|&v| v <= xi).clamp(1, x.len() - 1) - 1
+}
+
+/// Evaluates the piecewise cubic Hermite interpolant with values `y` and slopes `m` at the points `x`.
+fn hermite(x: &[f64], y: &[f64], m: &[f64], xi: f64) -> f64 {
+    let k = interval(x, xi);
+    let h = x[k + 1] - x[k];
+    let t = (xi - x[k]) / h;
+    let s = 1.0 - t;
+    (1.0 + 2.0 * t) * s * s * y[k] + t * s * s * h * m[k] + t * t * (3.0 - 2.0 * t) * y[k + 1]
+        - t * t * s * h * m[k + 1]
+}
+
+/// Evaluates the first derivative of the piecewise cubic Hermite interpolant.
+fn hermite_slope(x: &[f64], y: &[f64], m: &[f64], xi: f64) -> f64 {
+    let k = interval(x, xi);
+    let h = x[k + 1] - x[k];
+    let t = (xi - x[k]) / h;
+    6.0 * t * (1.0 - t) * (y[k + 1] - y[k]) / h
+        + (1.0 - t) * (1.0 - 3.0 * t) * m[k]
+        + t * (3.0 * t - 2.0) * m[k + 1]
+}
+
+/// The interval widths and divided differences of the samples.
+fn secants(x: &[f64], y: &[f64]) -> (Vec<f64>, Vec<f64>) {
+    let h: Vec<f64> = x.windows(2).map(|w| w[1] - w[0]).collect();
+    let d = (0..h.len()).map(|k| (y[k + 1] - y[k]) / h[k]).collect();
+    (h, d)
+}
+
+/// The slopes of the cubic spline with the given end conditions, from the tridiagonal system that makes
+/// the second derivative continuous at the interior points.
+fn spline_slopes(x: &[f64], y: &[f64], boundary: Boundary) -> Vec<f64> {
+    let n = x.len();
+    let (h, d) = secants(x, y);
+    match boundary {
+        Boundary::NotAKnot | Boundary::Natural if n == 2 => return vec![d[0]; 2],
+        Boundary::NotAKnot if n == 3 => {
+            // The parabola through the three points
+            let c = (d[1] - d[0]) / (h[0] + h[1]);
+            return vec![d[0] - c * h[0], d[0] + c * h[0], d[1] + c * h[1]];
+        }
+        _ => {}
+    }
+    let (mut sub, mut diag, mut sup, mut rhs) =
+        (vec![0.0; n], vec![0.0; n], vec![0.0; n], vec![0.0; n]);
+    for k in 1..n - 1 {
+        sub[k] = h[k];
+        diag[k] = 2.0 * (h[k - 1] + h[k]);
+        sup[k] = h[k - 1];
+        rhs[k] = 3.0 * (h[k] * d[k - 1] + h[k - 1] * d[k]);
+    }
+    match boundary {
+        Boundary::Clamped(a, b) => {
+            diag[0] = 1.0;
+            rhs[0] = a;
+            diag[n - 1] = 1.0;
+            rhs[n - 1] = b;
+        }
+        Boundary::Natural => {
+            diag[0] = 2.0;
+            sup[0] = 1.0;
+            rhs[0] = 3.0 * d[0];
+            sub[n - 1] = 1.0;
+            diag[n - 1] = 2.0;
+            rhs[n - 1] = 3.0 * d[n - 2];
+        }
+        Boundary::NotAKnot => {
+            let (h0, h1) = (h[0], h[1]);
+            diag[0] = h1;
+            sup[0] = h0 + h1;
+            rhs[0] = (h1 * (3.0 * h0 + 2.0 * h1) * d[0] + h0 * h0 * d[1]) / (h0 + h1);
+            let (a, b) = (h[n - 3], h[n - 2]);
+            sub[n - 1] = a + b;
+            diag[n - 1] = a;
+            rhs[n - 1] = (a * (2.0 * a + 3.0 * b) * d[n - 2] + b * b * d[n - 3]) / (a + b);
+        }
+    }
+    // Thomas algorithm
+    for k in 1..n {
+        let w = sub[k] / diag[k - 1];
+        diag[k] -= w * sup[k - 1];
+        rhs[k] -= w * rhs[k - 1];
+    }
+    let mut m = vec![0.0; n];
+    m[n - 1] = rhs[n - 1] / diag[n - 1];
+    for k in (0..n - 1).rev() {
+        m[k] = (rhs[k] - sup[k] * m[k + 1]) / diag[k];
+    }
+    m
+}
+
+/// The slopes of the shape-preserving piecewise cubic Hermite interpolant (Fritsch–Carlson, as in MATLAB's `pchip`):
+/// weighted harmonic means of the neighbouring secants, zero at local extrema.
+fn pchip_slopes(x: &[f64], y: &[f64]) -> Vec<f64> {
+    let n = x.len();
+    let (h, d) = secants(x, y);
+    if n == 2 {
+        return vec![d[0]; 2];
+    }
+    let mut m = vec![0.0; n];
+    for k in 1..n - 1 {
+        if d[k - 1] * d[k] > 0.0 {
+            let w1 = 2.0 * h[k] + h[k - 1];
+            let w2 = h[k] + 2.0 * h[k - 1];
+            m[k] = (w1 + w2) / (w1 / d[k - 1] + w2 / d[k]);
+        }
+    }
+    let end = |h0: f64, h1: f64, d0: f64, d1: f64| {
+        let s = ((2.0 * h0 + h1) * d0 - h0 * d1) / (h0 + h1);
+        if s * d0 <= 0.0 {
+            0.0
+        } else if d0 * d1 < 0.0 && s.abs() > 3.0 * d0.abs() {
+            3.0 * d0
+        } else {
+            s
+        }
+    };
+    m[0] = end(h[0], h[1], d[0], d[1]);
+    m[n - 1] = end(h[n - 2], h[n - 3], d[n - 2], d[n - 3]);
+    m
+}
+
+/// A one-dimensional interpolant of the samples `(x, y)` with its precomputed slopes, if any.
+struct Interpolant<'a> {
+    x: &'a [f64],
+    y: Vec<f64>,
+    slopes: Vec<f64>,
+    method: Method,
+    extrap: Extrapolation,
+}
+
+impl<'a> Interpolant<'a> {
+    /// Prepares the interpolant; `x` must already be checked.
+    fn new(x: &'a [f64], y: Vec<f64>, method: Method, extrap: Extrapolation) -> Interpolant<'a> {
+        let slopes = match method {
+            Method::Pchip => pchip_slopes(x, &y),
+            Method::Spline => spline_slopes(x, &y, Boundary::NotAKnot),
+            _ => Vec::new(),
+        };
+        Interpolant {
+            x,
+            y,
+            slopes,
+            method,
+            extrap,
+        }
+    }
+
+    /// Evaluates the interpolant at `xi`, applying the extrapolation rule outside the samples.
+    fn eval(&self, xi: f64) -> f64 {
+        let (x, y) = (self.x, &self.y);
+        let n = x.len();
+        if xi.is_nan() {
+            return f64::NAN;
+        }
+        let outside = xi < x[0] || xi > x[n - 1];
+        if outside {
+            match self.extrap {
+                Extrapolation::Nan => return f64::NAN,
+                Extrapolation::Value(v) => return v,
+                Extrapolation::Clamp => return if xi < x[0] { y[0] } else { y[n - 1] },
+                Extrapolation::Extrapolate => {}
+            }
+        }
+        let k = interval(x, xi);
+        match self.method {
+            _ if outside
+                && matches!(
+                    self.method,
+                    Method::Nearest | Method::Previous | Method::Next
+                ) =>
+            {
+                if xi < x[0] {
+                    y[0]
+                } else {
+                    y[n - 1]
+                }
+            }
+            Method::Nearest => {
+                if xi - x[k] < x[k + 1] - xi {
+                    y[k]
+                } else {
+                    y[k + 1]
+                }
+            }
+            Method::Previous => {
+                if xi == x[k + 1] {
+                    y[k + 1]
+                } else {
+                    y[k]
+                }
+            }
+            Method::Next => {
+                if xi == x[k] {
+                    y[k]
+                } else {
+                    y[k + 1]
+                }
+            }
+            Method::Linear => {
+                let t = (xi - x[k]) / (x[k + 1] - x[k]);
+                y[k] + t * (y[k + 1] - y[k])
+            }
+            Method::Pchip | Method::Spline => hermite(x, y, &self.slopes, xi),
+        }
+    }
+}
+
+/// ### interp1(x, y, xi, method, extrap)
+///
+/// Interpolation Function
+///
+/// The `interp1` function interpolates the samples `(x[i], y[i])` at the query points `xi` (MATLAB's `interp1`),
+/// with the method selected by `method` (see `Method`) and the values outside `[x[0], x[n - 1]]`
+/// selected by `extrap` (see `Extrapolation`). `x` must be strictly increasing, as grids from `range`
+/// and `range_from_to` are. A `NaN` query gives `NaN`.
+///
+/// For many evaluations of one spline, build a `CubicSpline` once instead.
+///
+/// ### Errors
+///
+/// Returns `MathError::DimensionMismatch` if `x` and `y` are not of the same length,
+/// and `MathError::Domain` if there are fewer than two points or `x` is not strictly increasing.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{fix64_vec, range_from_to};
+/// use mathlab::interpolate::{interp1, Extrapolation, Method};
+/// let x = range_from_to(0.0, 4.0, 1.0);
+/// let y = [0.0, 1.0, 4.0, 9.0, 16.0];
+/// let xi = [0.5, 2.5, 5.0];
+/// assert_eq!(interp1(&x, &y, &xi, Method::Linear, Extrapolation::Extrapolate), Ok(vec![0.5, 6.5, 23.0]));
+/// let s = interp1(&x, &y, &xi, Method::Spline, Extrapolation::Extrapolate).unwrap();
+/// assert_eq!(fix64_vec(&s), vec![0.25, 6.25, 25.0]);
+/// assert_eq!(interp1(&x, &y, &xi, Method::Previous, Extrapolation::Clamp), Ok(vec![0.0, 4.0, 16.0]));
+/// // pchip keeps monotonic data monotonic
+/// let p = interp1(&x, &[0.0, 0.0, 1.0, 1.0, 1.0], &[1.5, 3.5], Method::Pchip, Extrapolation::Nan).unwrap();
+/// assert_eq!((p[0], p[1]), (0.5, 1.0));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn interp1(
+    x: &[f64],
+    y: &[f64],
+    xi: &[f64],
+    method: Method,
+    extrap: Extrapolation,
+) -> Result<Vec<f64>, MathError> {
+    check_samples(x, y)?;
+    let f = Interpolant::new(x, y.to_vec(), method, extrap);
+    Ok(xi.iter().map(|&v| f.eval(v)).collect())
+}
+
+/// ### interp2(x, y, z, xi, yi, method, extrap)
+///
+/// Interpolation Function
+///
+/// The `interp2` function interpolates values `z` given on the rectangular grid `x` by `y` at the query points
+/// `(xi[k], yi[k])` (MATLAB's `interp2`). As in MATLAB, `z[(i, j)]` is the value at `(x[j], y[i])`,
+/// so `z` has one row per element of `y` and one column per element of `x`.
+///
+/// The interpolant is the tensor product of the one-dimensional `method`: `Linear` is bilinear interpolation,
+/// `Spline` and `Pchip` are bicubic, and `Nearest`, `Previous` and `Next` are piecewise constant.
+/// `extrap` applies along both axes.
+///
+/// ### Errors
+///
+/// Returns `MathError::DimensionMismatch` if `z` is not `y.len()` by `x.len()` or `xi` and `yi` are not
+/// of the same length, and `MathError::Domain` if `x` or `y` has fewer than two points or is not strictly increasing.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{fix, MathError, Matrix};
+/// use mathlab::interpolate::{interp2, Extrapolation, Method};
+/// let (x, y) = ([0.0, 1.0, 2.0, 3.0], [0.0, 1.0, 2.0]);
+/// // z = x + 10 y
+/// let z = Matrix::from_fn(3, 4, |i, j| x[j] + 10.0 * y[i]);
+/// let v = interp2(&x, &y, &z, &[0.5, 2.5], &[0.5, 1.25], Method::Linear, Extrapolation::Nan).unwrap();
+/// assert_eq!(v, vec![5.5, 15.0]);
+/// // z = x^2 y^2 is reproduced by the bicubic spline
+/// let z = Matrix::from_fn(3, 4, |i, j| (x[j] * y[i]).powi(2));
+/// let v = interp2(&x, &y, &z, &[1.5], &[0.5], Method::Spline, Extrapolation::Nan).unwrap();
+/// assert_eq!(fix(v[0], 12), 0.5625);
+/// assert!(interp2(&x, &y, &z, &[4.0], &[1.0], Method::Linear, Extrapolation::Nan).unwrap()[0].is_nan());
+/// // Too few sample points is an error, even with an empty z
+/// let empty = interp2(&[0.0, 1.0], &[], &Matrix::zeros(0, 2), &[0.5], &[0.5], Method::Linear, Extrapolation::Nan);
+/// assert_eq!(empty, Err(MathError::Domain("at least two sample points are required")));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn interp2(
+    x: &[f64],
+    y: &[f64],
+    z: &Matrix,
+    xi: &[f64],
+    yi: &[f64],
+    method: Method,
+    extrap: Extrapolation,
+) -> Result<Vec<f64>, MathError> {
+    if z.rows() != y.len() {
+        return Err(MathError::DimensionMismatch {
+            expected: y.len(),
+            found: z.rows(),
+        });
+    }
+    if z.cols() != x.len() {
+        return Err(MathError::DimensionMismatch {
+            expected: x.len(),
+            found: z.cols(),
+        });
+    }
+    check_points(x)?;
+    check_points(y)?;
+    if xi.len() != yi.len() {
+        return Err(MathError::DimensionMismatch {
+            expected: xi.len(),
+            found: yi.len(),
+        });
+    }
+    let rows: Vec<Interpolant> = (0..y.len())
+        .map(|i| Interpolant::new(x, z.row(i).to_vec(), method, extrap))
+        .collect();
+    Ok(xi
+        .iter()
+        .zip(yi)
+        .map(|(&u, &v)| {
+            let column = rows.iter().map(|r| r.eval(u)).collect();
+            Interpolant::new(y, column, method, extrap).eval(v)
+        })
+        .collect())
+}
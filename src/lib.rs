@@ -24,6 +24,51 @@ pub mod constants;
 // Functions
 pub mod functions;
 
+// Errors
+pub mod error;
+
+// Numeric Traits
+pub mod real;
+
+// Matrix
+pub mod matrix;
+
+// Linear Algebra
+pub mod linalg;
+
+// Random Numbers
+pub mod random;
+
+// Complex Numbers
+pub mod complex;
+
+// Polynomials
+pub mod polynomial;
+
+// Statistics
+pub mod stats;
+
+// Probability Distributions
+pub mod distributions;
+
+// Numerical Integration
+pub mod integrate;
+
+// Optimization
+pub mod optimize;
+
+// Ordinary Differential Equations
+pub mod ode;
+
+// Calculus
+pub mod calculus;
+
+// Interpolation
+pub mod interpolate;
+
+// Signal Processing
+pub mod signal;
+
 #[allow(dead_code)]
 pub mod math {
     // Constants
@@ -31,4 +76,25 @@ pub mod math {
 
     // Functions
     pub use crate::functions::*;
+
+    // Errors
+    pub use crate::error::MathError;
+
+    // Numeric Traits
+    pub use crate::real::Real;
+
+    // Matrix
+    pub use crate::matrix::Matrix;
+
+    // Random Numbers
+    pub use crate::random::Rng;
+
+    // Complex Numbers
+    pub use crate::complex::Complex;
+
+    // Polynomials
+    pub use crate::polynomial::Polynomial;
+
+    // Interpolation
+    pub use crate::interpolate::CubicSpline;
 }
@@ -0,0 +1,1390 @@
+use crate::error::MathError;
+use crate::matrix::Matrix;
+
+/// ### Lu
+///
+/// Matrix Decomposition
+///
+/// The `Lu` type holds the LU decomposition with partial pivoting of a square matrix `A`,
+/// such that `P * A = L * U`, where `P` is a permutation matrix, `L` is unit lower triangular
+/// and `U` is upper triangular. It is returned by the `lu` function.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{Matrix, fix64};
+/// use mathlab::linalg::lu;
+/// let a = Matrix::from_rows(&[[1.0, 2.0], [3.0, 4.0]]);
+/// let f = lu(&a).unwrap();
+/// assert_eq!(f.l().map(fix64), Matrix::from_rows(&[[1.0, 0.0], [0.33333334, 1.0]]));
+/// assert_eq!(f.u().map(fix64), Matrix::from_rows(&[[3.0, 4.0], [0.0, 0.6666667]]));
+/// assert_eq!(f.p(), Matrix::from_rows(&[[0.0, 1.0], [1.0, 0.0]]));
+/// assert_eq!(&f.p() * &a, &f.l() * &f.u());
+/// ```
+/// <small>End Type Doc</small>
+#[derive(Debug, Clone, PartialEq)]
+pub struct Lu {
+    lu: Matrix,
+    perm: Vec<usize>,
+    sign: f64,
+    singular: bool,
+}
+
+impl Lu {
+    /// ### l()
+    ///
+    /// Matrix Decomposition
+    ///
+    /// The `l` function returns the unit lower triangular factor `L`.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::math::Matrix;
+    /// use mathlab::linalg::lu;
+    /// let f = lu(&Matrix::from_rows(&[[2.0, 1.0], [4.0, 3.0]])).unwrap();
+    /// assert_eq!(f.l(), Matrix::from_rows(&[[1.0, 0.0], [0.5, 1.0]]));
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn l(&self) -> Matrix {
+        let n = self.lu.rows();
+        Matrix::from_fn(n, n, |i, j| match i.cmp(&j) {
+            std::cmp::Ordering::Greater => self.lu[(i, j)],
+            std::cmp::Ordering::Equal => 1.0,
+            std::cmp::Ordering::Less => 0.0,
+        })
+    }
+
+    /// ### u()
+    ///
+    /// Matrix Decomposition
+    ///
+    /// The `u` function returns the upper triangular factor `U`.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::math::Matrix;
+    /// use mathlab::linalg::lu;
+    /// let f = lu(&Matrix::from_rows(&[[2.0, 1.0], [4.0, 3.0]])).unwrap();
+    /// assert_eq!(f.u(), Matrix::from_rows(&[[4.0, 3.0], [0.0, -0.5]]));
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn u(&self) -> Matrix {
+        let n = self.lu.rows();
+        Matrix::from_fn(n, n, |i, j| if i <= j { self.lu[(i, j)] } else { 0.0 })
+    }
+
+    /// ### p()
+    ///
+    /// Matrix Decomposition
+    ///
+    /// The `p` function returns the permutation matrix `P` describing the row exchanges.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::math::Matrix;
+    /// use mathlab::linalg::lu;
+    /// let f = lu(&Matrix::from_rows(&[[2.0, 1.0], [4.0, 3.0]])).unwrap();
+    /// assert_eq!(f.p(), Matrix::from_rows(&[[0.0, 1.0], [1.0, 0.0]]));
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn p(&self) -> Matrix {
+        let n = self.perm.len();
+        Matrix::from_fn(n, n, |i, j| if self.perm[i] == j { 1.0 } else { 0.0 })
+    }
+
+    /// ### perm()
+    ///
+    /// Matrix Decomposition
+    ///
+    /// The `perm` function returns the pivot order as a permutation vector: row `i` of `P * A` is row `perm()[i]` of `A`.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::math::Matrix;
+    /// use mathlab::linalg::lu;
+    /// let f = lu(&Matrix::from_rows(&[[2.0, 1.0], [4.0, 3.0]])).unwrap();
+    /// assert_eq!(f.perm(), [1, 0]);
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn perm(&self) -> &[usize] {
+        &self.perm
+    }
+
+    /// ### is_singular()
+    ///
+    /// Boolean Check Function
+    ///
+    /// The `is_singular` function returns `true` when a pivot of `U` is zero (relative to the size of the matrix),
+    /// in which case `solve` reports `MathError::Singular`.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::math::Matrix;
+    /// use mathlab::linalg::lu;
+    /// assert!(lu(&Matrix::from_rows(&[[1.0, 2.0], [2.0, 4.0]])).unwrap().is_singular());
+    /// assert!(!lu(&Matrix::eye(2)).unwrap().is_singular());
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn is_singular(&self) -> bool {
+        self.singular
+    }
+
+    /// ### det()
+    ///
+    /// Linear Algebra Function
+    ///
+    /// The `det` function returns the determinant of the decomposed matrix, computed as the signed product of the pivots.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::math::Matrix;
+    /// use mathlab::linalg::lu;
+    /// let f = lu(&Matrix::from_rows(&[[2.0, 1.0], [4.0, 3.0]])).unwrap();
+    /// assert_eq!(f.det(), 2.0);
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn det(&self) -> f64 {
+        (0..self.lu.rows()).fold(self.sign, |acc, i| acc * self.lu[(i, i)])
+    }
+
+    /// ### solve(b)
+    ///
+    /// Linear Algebra Function
+    ///
+    /// The `solve` function solves `A * x = b` using the decomposition, by forward and back substitution.
+    ///
+    /// ### Errors
+    ///
+    /// Returns `MathError::DimensionMismatch` if `b` does not have one element per row of `A`,
+    /// and `MathError::Singular` if `A` is singular.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::math::Matrix;
+    /// use mathlab::linalg::lu;
+    /// let f = lu(&Matrix::from_rows(&[[2.0, 1.0], [4.0, 3.0]])).unwrap();
+    /// assert_eq!(f.solve(&[3.0, 7.0]), Ok(vec![1.0, 1.0]));
+    /// assert_eq!(f.solve(&[0.0, 2.0]), Ok(vec![-1.0, 2.0]));
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn solve(&self, b: &[f64]) -> Result<Vec<f64>, MathError> {
+        let n = self.lu.rows();
+        if b.len() != n {
+            return Err(MathError::DimensionMismatch {
+                expected: n,
+                found: b.len(),
+            });
+        }
+        if self.singular {
+            return Err(MathError::Singular);
+        }
+        // Forward substitution with the unit lower triangular factor
+        let mut x: Vec<f64> = self.perm.iter().map(|&p| b[p]).collect();
+        for i in 0..n {
+            for k in 0..i {
+                x[i] -= self.lu[(i, k)] * x[k];
+            }
+        }
+        // Back substitution with the upper triangular factor
+        for i in (0..n).rev() {
+            for k in i + 1..n {
+                x[i] -= self.lu[(i, k)] * x[k];
+            }
+            x[i] /= self.lu[(i, i)];
+        }
+        Ok(x)
+    }
+}
+
+/// Returns an error unless `a` is square.
+pub(crate) fn check_square(a: &Matrix) -> Result<(), MathError> {
+    if a.is_square() {
+        Ok(())
+    } else {
+        Err(MathError::NotSquare {
+            rows: a.rows(),
+            cols: a.cols(),
+        })
+    }
+}
+
+/// ### lu(a)
+///
+/// Matrix Decomposition
+///
+/// The `lu` function computes the LU decomposition of a square matrix using Gaussian elimination
+/// with partial pivoting (MATLAB's `[L, U, P] = lu(A)`). Singular matrices are decomposed too;
+/// use `Lu::is_singular` to detect them.
+///
+/// ### Errors
+///
+/// Returns `MathError::NotSquare` if `a` is not square.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{Matrix, MathError};
+/// use mathlab::linalg::lu;
+/// let a = Matrix::from_rows(&[[4.0, 3.0, 0.0], [8.0, 7.0, 1.0], [0.0, 2.0, 5.0]]);
+/// let f = lu(&a).unwrap();
+/// assert_eq!(&f.p() * &a, &f.l() * &f.u());
+/// assert_eq!(lu(&Matrix::zeros(2, 3)), Err(MathError::NotSquare { rows: 2, cols: 3 }));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn lu(a: &Matrix) -> Result<Lu, MathError> {
+    check_square(a)?;
+    let n = a.rows();
+    let mut lu = a.clone();
+    let mut perm: Vec<usize> = (0..n).collect();
+    let mut sign = 1.0;
+    let scale = a.as_slice().iter().fold(0.0_f64, |m, x| m.max(x.abs()));
+    let tol = n as f64 * f64::EPSILON * scale;
+    let mut singular = false;
+
+    for k in 0..n {
+        // Pick the largest pivot in column k
+        let p = (k..n)
+            .max_by(|&i, &j| lu[(i, k)].abs().total_cmp(&lu[(j, k)].abs()))
+            .unwrap_or(k);
+        if p != k {
+            for j in 0..n {
+                let tmp = lu[(k, j)];
+                lu[(k, j)] = lu[(p, j)];
+                lu[(p, j)] = tmp;
+            }
+            perm.swap(k, p);
+            sign = -sign;
+        }
+        let pivot = lu[(k, k)];
+        if pivot.abs() <= tol {
+            singular = true;
+            continue;
+        }
+        for i in k + 1..n {
+            let factor = lu[(i, k)] / pivot;
+            lu[(i, k)] = factor;
+            for j in k + 1..n {
+                lu[(i, j)] -= factor * lu[(k, j)];
+            }
+        }
+    }
+
+    Ok(Lu {
+        lu,
+        perm,
+        sign,
+        singular,
+    })
+}
+
+/// ### solve(a, b)
+///
+/// Linear Algebra Function
+///
+/// The `solve` function solves the square linear system `A * x = b` (MATLAB's `A \ b`)
+/// using the LU decomposition with partial pivoting.
+///
+/// ### Errors
+///
+/// Returns `MathError::NotSquare` if `a` is not square, `MathError::DimensionMismatch` if `b`
+/// does not have one element per row of `a`, and `MathError::Singular` if `a` is singular.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{Matrix, MathError};
+/// use mathlab::linalg::solve;
+/// let a = Matrix::from_rows(&[[2.0, 1.0, -1.0], [-3.0, -1.0, 2.0], [-2.0, 1.0, 2.0]]);
+/// let x = solve(&a, &[8.0, -11.0, -3.0]).unwrap();
+/// assert!((x[0] - 2.0).abs() < 1e-12 && (x[1] - 3.0).abs() < 1e-12 && (x[2] + 1.0).abs() < 1e-12);
+/// assert_eq!(solve(&a, &[1.0, 2.0]), Err(MathError::DimensionMismatch { expected: 3, found: 2 }));
+/// assert_eq!(solve(&Matrix::zeros(2, 2), &[1.0, 2.0]), Err(MathError::Singular));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn solve(a: &Matrix, b: &[f64]) -> Result<Vec<f64>, MathError> {
+    lu(a)?.solve(b)
+}
+
+/// ### det(a)
+///
+/// Linear Algebra Function
+///
+/// The `det` function returns the determinant of a square matrix, computed from its LU decomposition.
+///
+/// ### Errors
+///
+/// Returns `MathError::NotSquare` if `a` is not square.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{Matrix, MathError};
+/// use mathlab::linalg::det;
+/// assert_eq!(det(&Matrix::from_rows(&[[1.0, 2.0], [3.0, 4.0]])), Ok(-2.0));
+/// assert_eq!(det(&Matrix::eye(4)), Ok(1.0));
+/// assert_eq!(det(&Matrix::from_rows(&[[1.0, 2.0], [2.0, 4.0]])), Ok(0.0));
+/// assert_eq!(det(&Matrix::zeros(1, 2)), Err(MathError::NotSquare { rows: 1, cols: 2 }));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn det(a: &Matrix) -> Result<f64, MathError> {
+    Ok(lu(a)?.det())
+}
+
+/// ### inv(a)
+///
+/// Linear Algebra Function
+///
+/// The `inv` function returns the inverse of a square matrix, obtained by solving `A * X = I`
+/// column by column with the LU decomposition.
+///
+/// ### Errors
+///
+/// Returns `MathError::NotSquare` if `a` is not square and `MathError::Singular` if `a` is singular.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{Matrix, MathError, fix64};
+/// use mathlab::linalg::inv;
+/// let a = Matrix::from_rows(&[[4.0, 7.0], [2.0, 6.0]]);
+/// let a_inv = inv(&a).unwrap();
+/// assert_eq!(a_inv.map(fix64), Matrix::from_rows(&[[0.6, -0.7], [-0.2, 0.4]]));
+/// assert_eq!((&a * &a_inv).map(fix64), Matrix::eye(2));
+/// assert_eq!(inv(&Matrix::from_rows(&[[1.0, 2.0], [2.0, 4.0]])), Err(MathError::Singular));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn inv(a: &Matrix) -> Result<Matrix, MathError> {
+    let f = lu(a)?;
+    let n = a.rows();
+    let mut result = Matrix::zeros(n, n);
+    let mut e = vec![0.0; n];
+    for j in 0..n {
+        e[j] = 1.0;
+        let col = f.solve(&e)?;
+        for (i, x) in col.into_iter().enumerate() {
+            result[(i, j)] = x;
+        }
+        e[j] = 0.0;
+    }
+    Ok(result)
+}
+
+/// Returns an error unless `a` is symmetric (up to rounding errors).
+pub(crate) fn check_symmetric(a: &Matrix) -> Result<(), MathError> {
+    check_square(a)?;
+    let n = a.rows();
+    let tol = 100.0 * f64::EPSILON * max_abs(a);
+    for i in 0..n {
+        for j in i + 1..n {
+            if (a[(i, j)] - a[(j, i)]).abs() > tol {
+                return Err(MathError::NotSymmetric);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Returns the largest absolute value of the elements of `a`.
+pub(crate) fn max_abs(a: &Matrix) -> f64 {
+    a.as_slice().iter().fold(0.0_f64, |m, x| m.max(x.abs()))
+}
+
+/// ### Qr
+///
+/// Matrix Decomposition
+///
+/// The `Qr` type holds the QR decomposition `A = Q * R` of an `m x n` matrix, where `Q` is an `m x m`
+/// orthogonal matrix and `R` is an `m x n` upper triangular matrix. It is returned by the `qr` function.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{Matrix, fix, fix64};
+/// use mathlab::linalg::qr;
+/// let a = Matrix::from_rows(&[[3.0, 1.0], [4.0, 2.0]]);
+/// let f = qr(&a);
+/// assert_eq!(f.r().map(fix64), Matrix::from_rows(&[[-5.0, -2.2], [0.0, 0.4]]));
+/// assert_eq!((&f.q() * &f.r()).map(fix64), a);
+/// assert_eq!((&f.q().transpose() * &f.q()).map(|x| fix(x, 10)), Matrix::eye(2));
+/// ```
+/// <small>End Type Doc</small>
+#[derive(Debug, Clone, PartialEq)]
+pub struct Qr {
+    q: Matrix,
+    r: Matrix,
+}
+
+impl Qr {
+    /// ### q()
+    ///
+    /// Matrix Decomposition
+    ///
+    /// The `q` function returns the orthogonal factor `Q`.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::math::Matrix;
+    /// use mathlab::linalg::qr;
+    /// assert_eq!(qr(&Matrix::from_rows(&[[3.0], [4.0]])).q().size(), (2, 2));
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn q(&self) -> Matrix {
+        self.q.clone()
+    }
+
+    /// ### r()
+    ///
+    /// Matrix Decomposition
+    ///
+    /// The `r` function returns the upper triangular factor `R`.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::math::Matrix;
+    /// use mathlab::linalg::qr;
+    /// assert_eq!(qr(&Matrix::from_rows(&[[3.0], [4.0]])).r(), Matrix::from_rows(&[[-5.0], [0.0]]));
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn r(&self) -> Matrix {
+        self.r.clone()
+    }
+
+    /// ### solve(b)
+    ///
+    /// Linear Algebra Function
+    ///
+    /// The `solve` function returns the least-squares solution `x` minimizing `norm(A * x - b)`
+    /// for a matrix with at least as many rows as columns. For a square matrix this is the exact solution of `A * x = b`.
+    ///
+    /// ### Errors
+    ///
+    /// Returns `MathError::DimensionMismatch` if `b` does not have one element per row of `A`
+    /// or if `A` has fewer rows than columns, and `MathError::Singular` if `A` does not have full column rank.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::math::{Matrix, fix64_vec};
+    /// use mathlab::linalg::qr;
+    /// // Fit y = c0 + c1 * t through (0, 1), (1, 3), (2, 5)
+    /// let a = Matrix::from_rows(&[[1.0, 0.0], [1.0, 1.0], [1.0, 2.0]]);
+    /// let c = qr(&a).solve(&[1.0, 3.0, 5.0]).unwrap();
+    /// assert_eq!(fix64_vec(&c), [1.0, 2.0]);
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn solve(&self, b: &[f64]) -> Result<Vec<f64>, MathError> {
+        let (m, n) = self.r.size();
+        if b.len() != m {
+            return Err(MathError::DimensionMismatch {
+                expected: m,
+                found: b.len(),
+            });
+        }
+        if m < n {
+            return Err(MathError::DimensionMismatch {
+                expected: n,
+                found: m,
+            });
+        }
+        // y = Q' * b, then back substitution with the leading n x n block of R
+        let mut x: Vec<f64> = (0..n)
+            .map(|j| (0..m).map(|i| self.q[(i, j)] * b[i]).sum())
+            .collect();
+        let tol = m as f64 * f64::EPSILON * max_abs(&self.r);
+        for i in (0..n).rev() {
+            if self.r[(i, i)].abs() <= tol {
+                return Err(MathError::Singular);
+            }
+            for k in i + 1..n {
+                x[i] -= self.r[(i, k)] * x[k];
+            }
+            x[i] /= self.r[(i, i)];
+        }
+        Ok(x)
+    }
+}
+
+/// ### qr(a)
+///
+/// Matrix Decomposition
+///
+/// The `qr` function computes the QR decomposition of an `m x n` matrix with Householder reflections
+/// (MATLAB's `[Q, R] = qr(A)`).
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{Matrix, fix64};
+/// use mathlab::linalg::qr;
+/// let a = Matrix::from_rows(&[[12.0, -51.0, 4.0], [6.0, 167.0, -68.0], [-4.0, 24.0, -41.0]]);
+/// let f = qr(&a);
+/// assert_eq!(f.r().map(fix64), Matrix::from_rows(&[[-14.0, -21.0, 14.0], [0.0, -175.0, 70.0], [0.0, 0.0, -35.0]]));
+/// assert_eq!((&f.q() * &f.r()).map(fix64), a);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn qr(a: &Matrix) -> Qr {
+    let (m, n) = a.size();
+    let mut r = a.clone();
+    let mut q = Matrix::eye(m);
+
+    for k in 0..n.min(m.saturating_sub(1)) {
+        let norm = (k..m).map(|i| r[(i, k)] * r[(i, k)]).sum::<f64>().sqrt();
+        if norm == 0.0 {
+            continue;
+        }
+        let alpha = if r[(k, k)] > 0.0 { -norm } else { norm };
+        let mut v: Vec<f64> = (k..m).map(|i| r[(i, k)]).collect();
+        v[0] -= alpha;
+        let v_norm = v.iter().map(|x| x * x).sum::<f64>().sqrt();
+        if v_norm == 0.0 {
+            continue;
+        }
+        v.iter_mut().for_each(|x| *x /= v_norm);
+
+        // R = H * R, with H = I - 2 * v * v'
+        for j in k..n {
+            let s: f64 = (k..m).map(|i| v[i - k] * r[(i, j)]).sum();
+            for i in k..m {
+                r[(i, j)] -= 2.0 * v[i - k] * s;
+            }
+        }
+        // Q = Q * H
+        for i in 0..m {
+            let s: f64 = (k..m).map(|j| q[(i, j)] * v[j - k]).sum();
+            for j in k..m {
+                q[(i, j)] -= 2.0 * s * v[j - k];
+            }
+        }
+        r[(k, k)] = alpha;
+        for i in k + 1..m {
+            r[(i, k)] = 0.0;
+        }
+    }
+
+    Qr { q, r }
+}
+
+/// ### lstsq(a, b)
+///
+/// Linear Algebra Function
+///
+/// The `lstsq` function solves the linear system `A * x = b` in the least-squares sense (MATLAB's `A \ b` for
+/// rectangular matrices). Overdetermined systems get the solution minimizing `norm(A * x - b)`, and
+/// underdetermined systems get the solution of minimum norm.
+///
+/// ### Errors
+///
+/// Returns `MathError::DimensionMismatch` if `b` does not have one element per row of `a`,
+/// and `MathError::Singular` if `a` does not have full rank.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{Matrix, fix64_vec};
+/// use mathlab::linalg::lstsq;
+/// let a = Matrix::from_rows(&[[1.0, 1.0], [1.0, 2.0], [1.0, 3.0], [1.0, 4.0]]);
+/// assert_eq!(fix64_vec(&lstsq(&a, &[6.0, 5.0, 7.0, 10.0]).unwrap()), [3.5, 1.4]);
+/// // x + y = 2 has the minimum norm solution (1, 1)
+/// let b = Matrix::from_rows(&[[1.0, 1.0]]);
+/// assert_eq!(fix64_vec(&lstsq(&b, &[2.0]).unwrap()), [1.0, 1.0]);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn lstsq(a: &Matrix, b: &[f64]) -> Result<Vec<f64>, MathError> {
+    let (m, n) = a.size();
+    if b.len() != m {
+        return Err(MathError::DimensionMismatch {
+            expected: m,
+            found: b.len(),
+        });
+    }
+    if m >= n {
+        return qr(a).solve(b);
+    }
+    // A' = Q * R, so A = R' * Q' and x = Q * (R' \ b) is the minimum norm solution
+    let f = qr(&a.transpose());
+    let tol = n as f64 * f64::EPSILON * max_abs(&f.r);
+    let mut z = b.to_vec();
+    for i in 0..m {
+        if f.r[(i, i)].abs() <= tol {
+            return Err(MathError::Singular);
+        }
+        for k in 0..i {
+            z[i] -= f.r[(k, i)] * z[k];
+        }
+        z[i] /= f.r[(i, i)];
+    }
+    Ok((0..n)
+        .map(|i| (0..m).map(|k| f.q[(i, k)] * z[k]).sum())
+        .collect())
+}
+
+/// ### chol(a)
+///
+/// Matrix Decomposition
+///
+/// The `chol` function computes the Cholesky factorization of a symmetric positive definite matrix,
+/// returning the upper triangular matrix `R` such that `A = R' * R` (MATLAB's `R = chol(A)`).
+///
+/// ### Errors
+///
+/// Returns `MathError::NotSquare` or `MathError::NotSymmetric` if `a` is not a symmetric matrix,
+/// and `MathError::NotPositiveDefinite` if it is not positive definite.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{Matrix, MathError};
+/// use mathlab::linalg::chol;
+/// let a = Matrix::from_rows(&[[4.0, 12.0, -16.0], [12.0, 37.0, -43.0], [-16.0, -43.0, 98.0]]);
+/// let r = chol(&a).unwrap();
+/// assert_eq!(r, Matrix::from_rows(&[[2.0, 6.0, -8.0], [0.0, 1.0, 5.0], [0.0, 0.0, 3.0]]));
+/// assert_eq!(&r.transpose() * &r, a);
+/// assert_eq!(chol(&Matrix::from_rows(&[[1.0, 2.0], [2.0, 1.0]])), Err(MathError::NotPositiveDefinite));
+/// assert_eq!(chol(&Matrix::from_rows(&[[1.0, 2.0], [0.0, 1.0]])), Err(MathError::NotSymmetric));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn chol(a: &Matrix) -> Result<Matrix, MathError> {
+    check_symmetric(a)?;
+    let n = a.rows();
+    let mut r = Matrix::zeros(n, n);
+    for j in 0..n {
+        let d = a[(j, j)] - (0..j).map(|k| r[(k, j)] * r[(k, j)]).sum::<f64>();
+        if d <= 0.0 || d.is_nan() {
+            return Err(MathError::NotPositiveDefinite);
+        }
+        r[(j, j)] = d.sqrt();
+        for i in j + 1..n {
+            let s = a[(j, i)] - (0..j).map(|k| r[(k, j)] * r[(k, i)]).sum::<f64>();
+            r[(j, i)] = s / r[(j, j)];
+        }
+    }
+    Ok(r)
+}
+
+/// ### SymEigen
+///
+/// Matrix Decomposition
+///
+/// The `SymEigen` type holds the eigenvalues of a symmetric matrix in ascending order, together with the
+/// matching orthonormal eigenvectors stored as the columns of `vectors`, so that `A * V = V * diag(values)`.
+/// It is returned by the `eig_sym` function.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{Matrix, fix64};
+/// use mathlab::linalg::eig_sym;
+/// let a = Matrix::from_rows(&[[2.0, 1.0], [1.0, 2.0]]);
+/// let e = eig_sym(&a).unwrap();
+/// assert_eq!((&a * &e.vectors).map(fix64), (&e.vectors * &Matrix::diag(&e.values)).map(fix64));
+/// ```
+/// <small>End Type Doc</small>
+#[derive(Debug, Clone, PartialEq)]
+pub struct SymEigen {
+    pub values: Vec<f64>,
+    pub vectors: Matrix,
+}
+
+/// ### eig_sym(a)
+///
+/// Eigenvalue Function
+///
+/// The `eig_sym` function computes all eigenvalues and eigenvectors of a real symmetric matrix
+/// with the cyclic Jacobi method (MATLAB's `[V, D] = eig(A)` for symmetric `A`).
+///
+/// ### Errors
+///
+/// Returns `MathError::NotSquare` or `MathError::NotSymmetric` if `a` is not a symmetric matrix,
+/// and `MathError::NoConvergence` if the off-diagonal part does not vanish.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{Matrix, MathError, fix64_vec};
+/// use mathlab::linalg::eig_sym;
+/// // The eigenvalues are 2 - sqrt(2), 2 and 2 + sqrt(2)
+/// let a = Matrix::from_rows(&[[2.0, -1.0, 0.0], [-1.0, 2.0, -1.0], [0.0, -1.0, 2.0]]);
+/// let e = eig_sym(&a).unwrap();
+/// assert_eq!(fix64_vec(&e.values), [0.58578646, 2.0, 3.4142137]);
+/// assert_eq!(eig_sym(&Matrix::from_rows(&[[1.0, 2.0], [3.0, 4.0]])), Err(MathError::NotSymmetric));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn eig_sym(a: &Matrix) -> Result<SymEigen, MathError> {
+    const MAX_SWEEPS: usize = 100;
+
+    check_symmetric(a)?;
+    let n = a.rows();
+    let mut a = a.clone();
+    let mut v = Matrix::eye(n);
+    let scale: f64 = a.as_slice().iter().map(|x| x * x).sum();
+    let mut converged = false;
+
+    for _ in 0..MAX_SWEEPS {
+        let off: f64 = (0..n)
+            .flat_map(|i| (i + 1..n).map(move |j| (i, j)))
+            .map(|(i, j)| a[(i, j)] * a[(i, j)])
+            .sum();
+        if off <= f64::EPSILON * f64::EPSILON * scale {
+            converged = true;
+            break;
+        }
+        for p in 0..n {
+            for q in p + 1..n {
+                if a[(p, q)] == 0.0 {
+                    continue;
+                }
+                let theta = (a[(q, q)] - a[(p, p)]) / (2.0 * a[(p, q)]);
+                let sign = if theta >= 0.0 { 1.0 } else { -1.0 };
+                let t = sign / (theta.abs() + (theta * theta + 1.0).sqrt());
+                let c = 1.0 / (t * t + 1.0).sqrt();
+                let s = t * c;
+                for k in 0..n {
+                    let (akp, akq) = (a[(k, p)], a[(k, q)]);
+                    a[(k, p)] = c * akp - s * akq;
+                    a[(k, q)] = s * akp + c * akq;
+                }
+                for k in 0..n {
+                    let (apk, aqk) = (a[(p, k)], a[(q, k)]);
+                    a[(p, k)] = c * apk - s * aqk;
+                    a[(q, k)] = s * apk + c * aqk;
+                }
+                for k in 0..n {
+                    let (vkp, vkq) = (v[(k, p)], v[(k, q)]);
+                    v[(k, p)] = c * vkp - s * vkq;
+                    v[(k, q)] = s * vkp + c * vkq;
+                }
+            }
+        }
+    }
+    if !converged {
+        return Err(MathError::NoConvergence {
+            iterations: MAX_SWEEPS,
+        });
+    }
+
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|&i, &j| a[(i, i)].total_cmp(&a[(j, j)]));
+    Ok(SymEigen {
+        values: order.iter().map(|&i| a[(i, i)]).collect(),
+        vectors: Matrix::from_fn(n, n, |i, j| v[(i, order[j])]),
+    })
+}
+
+/// ### eigvals(a)
+///
+/// Eigenvalue Function
+///
+/// The `eigvals` function computes all eigenvalues of a real square matrix (MATLAB's `eig(A)`).
+/// The matrix is balanced, reduced to upper Hessenberg form by Householder reflections and then iterated with
+/// the implicit double-shift QR algorithm of Francis.
+/// Each eigenvalue is returned as a `(re, im)` pair; complex eigenvalues come in conjugate pairs.
+/// The eigenvalues are sorted by real part, then by imaginary part.
+///
+/// ### Errors
+///
+/// Returns `MathError::NotSquare` if `a` is not square and `MathError::NoConvergence` if the QR iteration fails.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{Matrix, fix64};
+/// use mathlab::linalg::eigvals;
+/// let a = Matrix::from_rows(&[[2.0, 0.0, 0.0], [0.0, 3.0, 4.0], [0.0, 4.0, 9.0]]);
+/// let e: Vec<(f64, f64)> = eigvals(&a).unwrap().iter().map(|&(re, im)| (fix64(re), fix64(im))).collect();
+/// assert_eq!(e, [(1.0, 0.0), (2.0, 0.0), (11.0, 0.0)]);
+/// // A rotation by 90 degrees has the eigenvalues -i and i
+/// let r = Matrix::from_rows(&[[0.0, -1.0], [1.0, 0.0]]);
+/// assert_eq!(eigvals(&r).unwrap(), [(0.0, -1.0), (0.0, 1.0)]);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn eigvals(a: &Matrix) -> Result<Vec<(f64, f64)>, MathError> {
+    check_square(a)?;
+    let mut h = a.clone();
+    balance(&mut h);
+    hessenberg(&mut h);
+    let mut values = francis_qr(&mut h)?;
+    values.sort_by(|x, y| x.0.total_cmp(&y.0).then(x.1.total_cmp(&y.1)));
+    Ok(values)
+}
+
+/// Balances `a` in place by a diagonal similarity `D^-1 A D` with powers of two on the diagonal, so that each
+/// row and the matching column have comparable 2-norms (Parlett and Reinsch, 1969; LAPACK's `dgebal`).
+/// Scaling by powers of two is exact, so the eigenvalues are unchanged while the rounding errors of the
+/// QR iteration, which are relative to the norm of the matrix, shrink.
+fn balance(a: &mut Matrix) {
+    let n = a.rows();
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for i in 0..n {
+            let col = (0..n)
+                .filter(|&k| k != i)
+                .map(|k| a[(k, i)] * a[(k, i)])
+                .sum::<f64>()
+                .sqrt();
+            let row = (0..n)
+                .filter(|&k| k != i)
+                .map(|k| a[(i, k)] * a[(i, k)])
+                .sum::<f64>()
+                .sqrt();
+            if col == 0.0 || row == 0.0 {
+                continue;
+            }
+            // Scaling column i by f and row i by 1 / f balances the norms when f = sqrt(row / col)
+            let f = 2.0_f64.powi((0.5 * (row / col).log2()).round() as i32);
+            if f != 1.0 && col * f + row / f < 0.95 * (col + row) {
+                changed = true;
+                for k in 0..n {
+                    a[(i, k)] /= f;
+                    a[(k, i)] *= f;
+                }
+            }
+        }
+    }
+}
+
+/// The Householder reflection `I - beta * v * v'` that maps `x` to a multiple of the first unit vector,
+/// as `(v, beta)`; `beta` is zero when `x` is already zero.
+fn householder(x: &[f64]) -> (Vec<f64>, f64) {
+    let norm = x.iter().map(|v| v * v).sum::<f64>().sqrt();
+    let mut v = x.to_vec();
+    if norm == 0.0 {
+        return (v, 0.0);
+    }
+    // Adding the norm with the sign of x[0] avoids cancellation
+    v[0] += norm.copysign(x[0]);
+    let beta = 2.0 / v.iter().map(|c| c * c).sum::<f64>();
+    (v, beta)
+}
+
+/// Applies the reflection `(v, beta)` from the left to rows `r0..r0 + v.len()` of `a`, in columns `cols`.
+fn reflect_rows(a: &mut Matrix, v: &[f64], beta: f64, r0: usize, cols: std::ops::Range<usize>) {
+    for j in cols {
+        let d = beta
+            * v.iter()
+                .enumerate()
+                .map(|(k, vk)| vk * a[(r0 + k, j)])
+                .sum::<f64>();
+        for (k, vk) in v.iter().enumerate() {
+            a[(r0 + k, j)] -= d * vk;
+        }
+    }
+}
+
+/// Applies the reflection `(v, beta)` from the right to columns `c0..c0 + v.len()` of `a`, in rows `rows`.
+fn reflect_cols(a: &mut Matrix, v: &[f64], beta: f64, c0: usize, rows: std::ops::Range<usize>) {
+    for i in rows {
+        let d = beta
+            * v.iter()
+                .enumerate()
+                .map(|(k, vk)| vk * a[(i, c0 + k)])
+                .sum::<f64>();
+        for (k, vk) in v.iter().enumerate() {
+            a[(i, c0 + k)] -= d * vk;
+        }
+    }
+}
+
+/// Reduces `a` in place to upper Hessenberg form by Householder similarity transformations
+/// (Golub and Van Loan, Matrix Computations, Algorithm 7.4.2).
+fn hessenberg(a: &mut Matrix) {
+    let n = a.rows();
+    for k in 0..n.saturating_sub(2) {
+        let x: Vec<f64> = (k + 1..n).map(|i| a[(i, k)]).collect();
+        let (v, beta) = householder(&x);
+        if beta == 0.0 {
+            continue;
+        }
+        reflect_rows(a, &v, beta, k + 1, k..n);
+        reflect_cols(a, &v, beta, k + 1, 0..n);
+        for i in k + 2..n {
+            a[(i, k)] = 0.0;
+        }
+    }
+}
+
+/// The eigenvalues of the 2 x 2 matrix `[[a, b], [c, d]]` as `(re, im)` pairs.
+fn eig2(a: f64, b: f64, c: f64, d: f64) -> [(f64, f64); 2] {
+    // With mu = lambda - d, the characteristic equation is mu^2 - 2 p mu - b c = 0
+    let p = 0.5 * (a - d);
+    let disc = p * p + b * c;
+    if disc >= 0.0 {
+        let mu = p + disc.sqrt().copysign(p);
+        let other = if mu == 0.0 { 0.0 } else { -b * c / mu };
+        [(d + mu, 0.0), (d + other, 0.0)]
+    } else {
+        let im = (-disc).sqrt();
+        [(d + p, -im), (d + p, im)]
+    }
+}
+
+/// Computes the eigenvalues of an upper Hessenberg matrix with the implicit double-shift QR algorithm of Francis
+/// (Golub and Van Loan, Matrix Computations, Algorithms 7.5.1 and 7.5.2). The active block `lo..hi` shrinks as
+/// negligible subdiagonal entries split off 1 x 1 and 2 x 2 blocks, whose eigenvalues are read off directly.
+/// Only the active block is updated, since the eigenvalues of a block triangular matrix are those of its diagonal blocks.
+fn francis_qr(h: &mut Matrix) -> Result<Vec<(f64, f64)>, MathError> {
+    let n = h.rows();
+    let max_iter = 30 * n.max(10);
+    let scale = h.as_slice().iter().map(|v| v.abs()).fold(0.0, f64::max);
+    let mut values = Vec::with_capacity(n);
+    let mut hi = n;
+    let mut iter = 0;
+    while hi > 0 {
+        // Find the start of the unreduced block ending at hi - 1
+        let mut lo = hi - 1;
+        while lo > 0 {
+            let mut s = h[(lo - 1, lo - 1)].abs() + h[(lo, lo)].abs();
+            if s == 0.0 {
+                s = scale;
+            }
+            if h[(lo, lo - 1)].abs() <= f64::EPSILON * s {
+                h[(lo, lo - 1)] = 0.0;
+                break;
+            }
+            lo -= 1;
+        }
+        match hi - lo {
+            1 => {
+                values.push((h[(lo, lo)], 0.0));
+                hi -= 1;
+                iter = 0;
+                continue;
+            }
+            2 => {
+                let (a, b, c, d) = (
+                    h[(lo, lo)],
+                    h[(lo, lo + 1)],
+                    h[(lo + 1, lo)],
+                    h[(lo + 1, lo + 1)],
+                );
+                values.extend(eig2(a, b, c, d));
+                hi -= 2;
+                iter = 0;
+                continue;
+            }
+            _ => {}
+        }
+        if iter == max_iter {
+            return Err(MathError::NoConvergence { iterations: iter });
+        }
+        iter += 1;
+        // The shifts are the eigenvalues of the trailing 2 x 2 block, given by its trace and determinant;
+        // now and then an exceptional shift (as in LAPACK's dlahqr) breaks cycles
+        let (trace, det) = if iter % 10 == 0 {
+            let w = h[(hi - 1, hi - 2)].abs() + h[(hi - 2, hi - 3)].abs();
+            let d = 0.75 * w + h[(hi - 1, hi - 1)];
+            (2.0 * d, d * d + 0.4375 * w * w)
+        } else {
+            let (a, b, c, d) = (
+                h[(hi - 2, hi - 2)],
+                h[(hi - 2, hi - 1)],
+                h[(hi - 1, hi - 2)],
+                h[(hi - 1, hi - 1)],
+            );
+            (a + d, a * d - b * c)
+        };
+        francis_step(h, lo, hi, trace, det);
+    }
+    Ok(values)
+}
+
+/// One implicit double-shift QR step on the unreduced Hessenberg block `lo..hi` with the shifts whose sum is
+/// `trace` and product is `det`: the first column of `(H - s1 I)(H - s2 I)` is reflected to a multiple of the
+/// first unit vector, and the resulting bulge is chased down the subdiagonal with 3 x 3 reflections.
+fn francis_step(h: &mut Matrix, lo: usize, hi: usize, trace: f64, det: f64) {
+    let (h11, h12, h21) = (h[(lo, lo)], h[(lo, lo + 1)], h[(lo + 1, lo)]);
+    let (h22, h32) = (h[(lo + 1, lo + 1)], h[(lo + 2, lo + 1)]);
+    let mut x = h11 * h11 + h12 * h21 - trace * h11 + det;
+    let mut y = h21 * (h11 + h22 - trace);
+    let mut z = h21 * h32;
+    for k in lo..hi - 2 {
+        let (v, beta) = householder(&[x, y, z]);
+        if beta != 0.0 {
+            let first_col = if k > lo { k - 1 } else { lo };
+            reflect_rows(h, &v, beta, k, first_col..hi);
+            reflect_cols(h, &v, beta, k, lo..(k + 4).min(hi));
+        }
+        x = h[(k + 1, k)];
+        y = h[(k + 2, k)];
+        if k + 3 < hi {
+            z = h[(k + 3, k)];
+        }
+    }
+    let (v, beta) = householder(&[x, y]);
+    if beta != 0.0 {
+        reflect_rows(h, &v, beta, hi - 2, hi - 3..hi);
+        reflect_cols(h, &v, beta, hi - 2, lo..hi);
+    }
+    // Clear the rounding residue below the subdiagonal left by the bulge chase
+    for i in lo + 2..hi {
+        h[(i, i - 2)] = 0.0;
+        if i >= lo + 3 {
+            h[(i, i - 3)] = 0.0;
+        }
+    }
+}
+
+/// ### Svd
+///
+/// Matrix Decomposition
+///
+/// The `Svd` type holds the economy-size singular value decomposition `A = U * diag(s) * V'` of an `m x n` matrix.
+/// With `k = min(m, n)`, `u` is `m x k`, `v` is `n x k`, both with orthonormal columns, and the `k` singular values
+/// in `s` are sorted in decreasing order. It is returned by the `svd` function.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{Matrix, fix64};
+/// use mathlab::linalg::svd;
+/// let a = Matrix::from_rows(&[[3.0, 0.0], [4.0, 5.0]]);
+/// let f = svd(&a).unwrap();
+/// let usv = &(&f.u * &Matrix::diag(&f.s)) * &f.v.transpose();
+/// assert_eq!(usv.map(fix64), a);
+/// ```
+/// <small>End Type Doc</small>
+#[derive(Debug, Clone, PartialEq)]
+pub struct Svd {
+    pub u: Matrix,
+    pub s: Vec<f64>,
+    pub v: Matrix,
+}
+
+/// ### svd(a)
+///
+/// Matrix Decomposition
+///
+/// The `svd` function computes the economy-size singular value decomposition of a matrix
+/// (MATLAB's `[U, S, V] = svd(A, "econ")`) with the one-sided Jacobi method.
+///
+/// ### Errors
+///
+/// Returns `MathError::NoConvergence` if the Jacobi sweeps do not converge.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{Matrix, fix64_vec};
+/// use mathlab::linalg::svd;
+/// let a = Matrix::from_rows(&[[3.0, 2.0, 2.0], [2.0, 3.0, -2.0]]);
+/// let f = svd(&a).unwrap();
+/// assert_eq!(fix64_vec(&f.s), [5.0, 3.0]);
+/// assert_eq!(f.u.size(), (2, 2));
+/// assert_eq!(f.v.size(), (3, 2));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn svd(a: &Matrix) -> Result<Svd, MathError> {
+    if a.rows() < a.cols() {
+        let f = svd(&a.transpose())?;
+        return Ok(Svd {
+            u: f.v,
+            s: f.s,
+            v: f.u,
+        });
+    }
+
+    const MAX_SWEEPS: usize = 100;
+    let (m, n) = a.size();
+    let mut u = a.clone();
+    let mut v = Matrix::eye(n);
+    let mut converged = false;
+    // Column pairs whose inner product is negligible next to the whole matrix are already orthogonal
+    let tiny = f64::EPSILON * f64::EPSILON * a.as_slice().iter().map(|x| x * x).sum::<f64>();
+
+    for _ in 0..MAX_SWEEPS {
+        let mut rotated = false;
+        for p in 0..n {
+            for q in p + 1..n {
+                let (mut alpha, mut beta, mut gamma) = (0.0, 0.0, 0.0);
+                for i in 0..m {
+                    alpha += u[(i, p)] * u[(i, p)];
+                    beta += u[(i, q)] * u[(i, q)];
+                    gamma += u[(i, p)] * u[(i, q)];
+                }
+                if gamma.abs() <= tiny || gamma.abs() <= f64::EPSILON * (alpha * beta).sqrt() {
+                    continue;
+                }
+                rotated = true;
+                let zeta = (beta - alpha) / (2.0 * gamma);
+                let sign = if zeta >= 0.0 { 1.0 } else { -1.0 };
+                let t = sign / (zeta.abs() + (zeta * zeta + 1.0).sqrt());
+                let c = 1.0 / (t * t + 1.0).sqrt();
+                let s = c * t;
+                for i in 0..m {
+                    let (up, uq) = (u[(i, p)], u[(i, q)]);
+                    u[(i, p)] = c * up - s * uq;
+                    u[(i, q)] = s * up + c * uq;
+                }
+                for i in 0..n {
+                    let (vp, vq) = (v[(i, p)], v[(i, q)]);
+                    v[(i, p)] = c * vp - s * vq;
+                    v[(i, q)] = s * vp + c * vq;
+                }
+            }
+        }
+        if !rotated {
+            converged = true;
+            break;
+        }
+    }
+    if !converged {
+        return Err(MathError::NoConvergence {
+            iterations: MAX_SWEEPS,
+        });
+    }
+
+    // The singular values are the norms of the rotated columns
+    let s: Vec<f64> = (0..n)
+        .map(|j| (0..m).map(|i| u[(i, j)] * u[(i, j)]).sum::<f64>().sqrt())
+        .collect();
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|&i, &j| s[j].total_cmp(&s[i]));
+    let s: Vec<f64> = order.iter().map(|&j| s[j]).collect();
+    let v = Matrix::from_fn(n, n, |i, j| v[(i, order[j])]);
+    let mut u = Matrix::from_fn(m, n, |i, j| {
+        if s[j] > 0.0 {
+            u[(i, order[j])] / s[j]
+        } else {
+            0.0
+        }
+    });
+    complete_orthonormal(&mut u, &s);
+    Ok(Svd { u, s, v })
+}
+
+/// Replaces the columns of `u` that belong to zero singular values by unit vectors orthogonal to the other columns.
+fn complete_orthonormal(u: &mut Matrix, s: &[f64]) {
+    let m = u.rows();
+    for j in 0..u.cols() {
+        if s[j] > 0.0 {
+            continue;
+        }
+        for e in 0..m {
+            // Gram-Schmidt on the e-th standard basis vector
+            let mut w = vec![0.0; m];
+            w[e] = 1.0;
+            for k in (0..u.cols()).filter(|&k| k != j && (s[k] > 0.0 || k < j)) {
+                let d: f64 = (0..m).map(|i| u[(i, k)] * w[i]).sum();
+                for (i, wi) in w.iter_mut().enumerate() {
+                    *wi -= d * u[(i, k)];
+                }
+            }
+            let norm = w.iter().map(|x| x * x).sum::<f64>().sqrt();
+            if norm > 0.5 {
+                for (i, wi) in w.iter().enumerate() {
+                    u[(i, j)] = wi / norm;
+                }
+                break;
+            }
+        }
+    }
+}
+
+/// Returns the default tolerance `max(m, n) * eps * s_max` below which singular values are treated as zero.
+fn svd_tol(a: &Matrix, s: &[f64]) -> f64 {
+    let s_max = s.first().copied().unwrap_or(0.0);
+    a.rows().max(a.cols()) as f64 * f64::EPSILON * s_max
+}
+
+/// ### rank(a)
+///
+/// Linear Algebra Function
+///
+/// The `rank` function returns the number of linearly independent rows or columns of a matrix,
+/// counted as the singular values greater than `max(m, n) * eps * norm(A, 2)`, like MATLAB's `rank(A)`.
+///
+/// ### Errors
+///
+/// Returns `MathError::NoConvergence` if the singular value decomposition fails.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::Matrix;
+/// use mathlab::linalg::rank;
+/// assert_eq!(rank(&Matrix::eye(3)), Ok(3));
+/// assert_eq!(rank(&Matrix::from_rows(&[[1.0, 2.0, 3.0], [2.0, 4.0, 6.0]])), Ok(1));
+/// assert_eq!(rank(&Matrix::zeros(2, 2)), Ok(0));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn rank(a: &Matrix) -> Result<usize, MathError> {
+    let f = svd(a)?;
+    let tol = svd_tol(a, &f.s);
+    Ok(f.s.iter().filter(|&&s| s > tol).count())
+}
+
+/// ### pinv(a)
+///
+/// Linear Algebra Function
+///
+/// The `pinv` function returns the Moore-Penrose pseudoinverse `V * diag(1 / s) * U'` of a matrix,
+/// ignoring the singular values below the `rank` tolerance, like MATLAB's `pinv(A)`.
+///
+/// ### Errors
+///
+/// Returns `MathError::NoConvergence` if the singular value decomposition fails.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{Matrix, fix};
+/// use mathlab::linalg::pinv;
+/// let a = Matrix::from_rows(&[[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+/// let p = pinv(&a).unwrap();
+/// assert_eq!((&p * &a).map(|x| fix(x, 10)), Matrix::eye(2));
+/// let s = Matrix::from_rows(&[[1.0, 1.0], [1.0, 1.0]]);
+/// assert_eq!(pinv(&s).unwrap().map(|x| fix(x, 10)), Matrix::fill(2, 2, 0.25));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn pinv(a: &Matrix) -> Result<Matrix, MathError> {
+    let f = svd(a)?;
+    let tol = svd_tol(a, &f.s);
+    let (m, n) = a.size();
+    Ok(Matrix::from_fn(n, m, |i, j| {
+        f.s.iter()
+            .enumerate()
+            .filter(|&(_, &s)| s > tol)
+            .map(|(k, &s)| f.v[(i, k)] * f.u[(j, k)] / s)
+            .sum()
+    }))
+}
+
+/// ### Norm
+///
+/// Matrix Norm Type
+///
+/// The `Norm` enum selects the matrix norm computed by the `norm` function:
+/// - `One`: the maximum absolute column sum (MATLAB's `norm(A, 1)`)
+/// - `Two`: the largest singular value (MATLAB's `norm(A, 2)`)
+/// - `Inf`: the maximum absolute row sum (MATLAB's `norm(A, Inf)`)
+/// - `Fro`: the square root of the sum of squares of all elements (MATLAB's `norm(A, "fro")`)
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::Matrix;
+/// use mathlab::linalg::{norm, Norm};
+/// let a = Matrix::from_rows(&[[1.0, -2.0], [-3.0, 4.0]]);
+/// assert_eq!(norm(&a, Norm::One), Ok(6.0));
+/// assert_eq!(norm(&a, Norm::Inf), Ok(7.0));
+/// ```
+/// <small>End Type Doc</small>
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Norm {
+    One,
+    Two,
+    Inf,
+    Fro,
+}
+
+/// ### norm(a, p)
+///
+/// Linear Algebra Function
+///
+/// The `norm` function returns the matrix norm of `a` selected by `p` (see `Norm`).
+/// For vector norms of slices, use `vecnorm` or `hypot`.
+///
+/// ### Errors
+///
+/// Returns `MathError::NoConvergence` if the singular value decomposition needed by `Norm::Two` fails.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{Matrix, fix64};
+/// use mathlab::linalg::{norm, Norm};
+/// let a = Matrix::from_rows(&[[1.0, 2.0], [3.0, 4.0]]);
+/// assert_eq!(norm(&a, Norm::One), Ok(6.0));
+/// assert_eq!(fix64(norm(&a, Norm::Two).unwrap()), 5.464986);
+/// assert_eq!(norm(&a, Norm::Inf), Ok(7.0));
+/// assert_eq!(norm(&a, Norm::Fro), Ok(30.0_f64.sqrt()));
+/// assert_eq!(norm(&Matrix::zeros(0, 0), Norm::Two), Ok(0.0));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn norm(a: &Matrix, p: Norm) -> Result<f64, MathError> {
+    let (m, n) = a.size();
+    Ok(match p {
+        Norm::One => (0..n)
+            .map(|j| (0..m).map(|i| a[(i, j)].abs()).sum::<f64>())
+            .fold(0.0, f64::max),
+        Norm::Two => svd(a)?.s.first().copied().unwrap_or(0.0),
+        Norm::Inf => (0..m)
+            .map(|i| a.row(i).iter().map(|x| x.abs()).sum::<f64>())
+            .fold(0.0, f64::max),
+        Norm::Fro => a.as_slice().iter().map(|x| x * x).sum::<f64>().sqrt(),
+    })
+}
+
+/// ### cond(a)
+///
+/// Linear Algebra Function
+///
+/// The `cond` function returns the 2-norm condition number of a matrix, the ratio of its largest to its smallest
+/// singular value (MATLAB's `cond(A)`). Singular matrices have an infinite condition number.
+///
+/// ### Errors
+///
+/// Returns `MathError::NoConvergence` if the singular value decomposition fails.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{Matrix, fix64, INF_F64};
+/// use mathlab::linalg::cond;
+/// assert_eq!(cond(&Matrix::eye(3)), Ok(1.0));
+/// assert_eq!(fix64(cond(&Matrix::from_rows(&[[1.0, 2.0], [3.0, 4.0]])).unwrap()), 14.933034);
+/// assert_eq!(cond(&Matrix::from_rows(&[[1.0, 2.0], [2.0, 4.0]])), Ok(INF_F64));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn cond(a: &Matrix) -> Result<f64, MathError> {
+    let s = svd(a)?.s;
+    match (s.first(), s.last()) {
+        (Some(&s_max), Some(&s_min)) if s_min > svd_tol(a, &s) => Ok(s_max / s_min),
+        (Some(_), Some(_)) => Ok(f64::INFINITY),
+        _ => Ok(0.0),
+    }
+}
+
+/// ### null(a)
+///
+/// Linear Algebra Function
+///
+/// The `null` function returns an orthonormal basis for the null space of `a`, stored as the columns of an
+/// `n x k` matrix, where `k = n - rank(A)` (MATLAB's `null(A)`).
+///
+/// ### Errors
+///
+/// Returns `MathError::NoConvergence` if the singular value decomposition fails.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{Matrix, fix};
+/// use mathlab::linalg::null;
+/// let a = Matrix::from_rows(&[[1.0, 1.0, 1.0], [1.0, 1.0, 1.0]]);
+/// let z = null(&a).unwrap();
+/// assert_eq!(z.size(), (3, 2));
+/// assert_eq!((&a * &z).map(|x| fix(x, 10)), Matrix::zeros(2, 2));
+/// assert_eq!(null(&Matrix::eye(2)).unwrap().size(), (2, 0));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn null(a: &Matrix) -> Result<Matrix, MathError> {
+    let (m, n) = a.size();
+    // Zero rows do not change the null space, and make V square
+    let padded = if m < n {
+        Matrix::from_fn(n, n, |i, j| if i < m { a[(i, j)] } else { 0.0 })
+    } else {
+        a.clone()
+    };
+    let f = svd(&padded)?;
+    let tol = svd_tol(a, &f.s);
+    let r = f.s.iter().filter(|&&s| s > tol).count();
+    Ok(f.v.slice(.., r..))
+}
+
+/// ### orth(a)
+///
+/// Linear Algebra Function
+///
+/// The `orth` function returns an orthonormal basis for the range (column space) of `a`, stored as the columns of an
+/// `m x r` matrix, where `r = rank(A)` (MATLAB's `orth(A)`).
+///
+/// ### Errors
+///
+/// Returns `MathError::NoConvergence` if the singular value decomposition fails.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{Matrix, fix64};
+/// use mathlab::linalg::orth;
+/// let a = Matrix::from_rows(&[[1.0, 2.0], [2.0, 4.0], [0.0, 0.0]]);
+/// let q = orth(&a).unwrap();
+/// assert_eq!(q.size(), (3, 1));
+/// assert_eq!((&q.transpose() * &q).map(fix64), Matrix::eye(1));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn orth(a: &Matrix) -> Result<Matrix, MathError> {
+    let f = svd(a)?;
+    let tol = svd_tol(a, &f.s);
+    let r = f.s.iter().filter(|&&s| s > tol).count();
+    Ok(f.u.slice(.., ..r))
+}
@@ -0,0 +1,716 @@
+use crate::functions::{add_vec_vec, dot, mult_vec_num, mult_vec_vec, subt_vec_vec};
+use std::fmt;
+use std::ops::{Add, Bound, Index, IndexMut, Mul, Neg, RangeBounds, Sub};
+
+/// ### Matrix
+///
+/// Dense Matrix Type
+///
+/// The `Matrix` type stores a two-dimensional array of `f64` values in `row-major` order,
+/// i.e. the elements of the first row come first, followed by the elements of the second row, and so on.
+///
+/// Elements are addressed with zero-based `(row, col)` pairs, and the arithmetic operators follow MATLAB:
+/// `+` and `-` work element by element, `*` is the matrix product and `mul_elem` is the element-wise (`.*`) product.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::Matrix;
+/// let a = Matrix::from_rows(&[[1.0, 2.0], [3.0, 4.0]]);
+/// let b = Matrix::eye(2);
+/// assert_eq!(a.size(), (2, 2));
+/// assert_eq!(a[(1, 0)], 3.0);
+/// assert_eq!(&a * &b, a);
+/// assert_eq!(&a + &b, Matrix::from_rows(&[[2.0, 2.0], [3.0, 5.0]]));
+/// assert_eq!(&a - &b, Matrix::from_rows(&[[0.0, 2.0], [3.0, 3.0]]));
+/// assert_eq!(&a * 2.0, Matrix::from_rows(&[[2.0, 4.0], [6.0, 8.0]]));
+/// assert_eq!(-&a, Matrix::from_rows(&[[-1.0, -2.0], [-3.0, -4.0]]));
+/// assert_eq!(a.mul_elem(&a), Matrix::from_rows(&[[1.0, 4.0], [9.0, 16.0]]));
+/// assert_eq!(a.transpose(), Matrix::from_rows(&[[1.0, 3.0], [2.0, 4.0]]));
+/// ```
+/// <small>End Type Doc</small>
+#[derive(Debug, Clone, PartialEq)]
+pub struct Matrix {
+    rows: usize,
+    cols: usize,
+    data: Vec<f64>,
+}
+
+impl Matrix {
+    /// ### new(rows, cols, data)
+    ///
+    /// Matrix Constructor
+    ///
+    /// The `new` function builds a `rows x cols` matrix from a vector of `rows * cols` values given in row-major order.
+    ///
+    /// ### Panics
+    ///
+    /// This function will panic if `data.len()` is not equal to `rows * cols`.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::math::Matrix;
+    /// let a = Matrix::new(2, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    /// assert_eq!(a.size(), (2, 3));
+    /// assert_eq!(a[(0, 2)], 3.0);
+    /// assert_eq!(a[(1, 0)], 4.0);
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn new(rows: usize, cols: usize, data: Vec<f64>) -> Matrix {
+        assert_eq!(
+            data.len(),
+            rows * cols,
+            "Data length must be equal to rows * cols"
+        );
+        Matrix { rows, cols, data }
+    }
+
+    /// ### zeros(rows, cols)
+    ///
+    /// Matrix Constructor
+    ///
+    /// The `zeros` function returns a `rows x cols` matrix filled with `0.0`.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::math::Matrix;
+    /// assert_eq!(Matrix::zeros(2, 2), Matrix::from_rows(&[[0.0, 0.0], [0.0, 0.0]]));
+    /// assert_eq!(Matrix::zeros(0, 3).size(), (0, 3));
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn zeros(rows: usize, cols: usize) -> Matrix {
+        Matrix::fill(rows, cols, 0.0)
+    }
+
+    /// ### ones(rows, cols)
+    ///
+    /// Matrix Constructor
+    ///
+    /// The `ones` function returns a `rows x cols` matrix filled with `1.0`.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::math::Matrix;
+    /// assert_eq!(Matrix::ones(1, 3), Matrix::from_rows(&[[1.0, 1.0, 1.0]]));
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn ones(rows: usize, cols: usize) -> Matrix {
+        Matrix::fill(rows, cols, 1.0)
+    }
+
+    /// ### fill(rows, cols, x)
+    ///
+    /// Matrix Constructor
+    ///
+    /// The `fill` function returns a `rows x cols` matrix in which every element is equal to `x`.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::math::Matrix;
+    /// assert_eq!(Matrix::fill(2, 1, 0.5), Matrix::from_rows(&[[0.5], [0.5]]));
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn fill(rows: usize, cols: usize, x: f64) -> Matrix {
+        Matrix {
+            rows,
+            cols,
+            data: vec![x; rows * cols],
+        }
+    }
+
+    /// ### eye(n)
+    ///
+    /// Matrix Constructor
+    ///
+    /// The `eye` function returns the `n x n` identity matrix, with ones on the main diagonal and zeros elsewhere.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::math::Matrix;
+    /// assert_eq!(Matrix::eye(3), Matrix::from_rows(&[[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]));
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn eye(n: usize) -> Matrix {
+        Matrix::from_fn(n, n, |i, j| if i == j { 1.0 } else { 0.0 })
+    }
+
+    /// ### diag(x)
+    ///
+    /// Matrix Constructor
+    ///
+    /// The `diag` function returns a square matrix with the elements of `x` on the main diagonal and zeros elsewhere.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::math::Matrix;
+    /// assert_eq!(Matrix::diag(&[1.0, 2.0]), Matrix::from_rows(&[[1.0, 0.0], [0.0, 2.0]]));
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn diag(x: &[f64]) -> Matrix {
+        Matrix::from_fn(x.len(), x.len(), |i, j| if i == j { x[i] } else { 0.0 })
+    }
+
+    /// ### from_fn(rows, cols, f)
+    ///
+    /// Matrix Constructor
+    ///
+    /// The `from_fn` function builds a `rows x cols` matrix whose element at `(i, j)` is `f(i, j)`.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::math::Matrix;
+    /// let hilbert = Matrix::from_fn(2, 2, |i, j| 1.0 / (i + j + 1) as f64);
+    /// assert_eq!(hilbert, Matrix::from_rows(&[[1.0, 0.5], [0.5, 1.0 / 3.0]]));
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn from_fn<F: FnMut(usize, usize) -> f64>(rows: usize, cols: usize, mut f: F) -> Matrix {
+        let mut data = Vec::with_capacity(rows * cols);
+        for i in 0..rows {
+            for j in 0..cols {
+                data.push(f(i, j));
+            }
+        }
+        Matrix { rows, cols, data }
+    }
+
+    /// ### from_rows(rows)
+    ///
+    /// Matrix Constructor
+    ///
+    /// The `from_rows` function builds a matrix from a list of rows. Each row can be an array, a slice or a `Vec<f64>`.
+    ///
+    /// ### Panics
+    ///
+    /// This function will panic if the rows do not all have the same length.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::math::Matrix;
+    /// let a = Matrix::from_rows(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    /// let b = Matrix::from_rows(&[vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]);
+    /// assert_eq!(a, b);
+    /// assert_eq!(a.size(), (2, 3));
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn from_rows<R: AsRef<[f64]>>(rows: &[R]) -> Matrix {
+        let cols = rows.first().map_or(0, |row| row.as_ref().len());
+        let mut data = Vec::with_capacity(rows.len() * cols);
+        for row in rows {
+            assert_eq!(
+                row.as_ref().len(),
+                cols,
+                "All rows must be of the same length"
+            );
+            data.extend_from_slice(row.as_ref());
+        }
+        Matrix {
+            rows: rows.len(),
+            cols,
+            data,
+        }
+    }
+
+    /// ### row_vector(x)
+    ///
+    /// Matrix Constructor
+    ///
+    /// The `row_vector` function turns a slice into a `1 x n` matrix.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::math::Matrix;
+    /// assert_eq!(Matrix::row_vector(&[1.0, 2.0]).size(), (1, 2));
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn row_vector(x: &[f64]) -> Matrix {
+        Matrix::new(1, x.len(), x.to_vec())
+    }
+
+    /// ### col_vector(x)
+    ///
+    /// Matrix Constructor
+    ///
+    /// The `col_vector` function turns a slice into an `n x 1` matrix.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::math::Matrix;
+    /// assert_eq!(Matrix::col_vector(&[1.0, 2.0]).size(), (2, 1));
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn col_vector(x: &[f64]) -> Matrix {
+        Matrix::new(x.len(), 1, x.to_vec())
+    }
+
+    /// ### rows()
+    ///
+    /// Matrix Property
+    ///
+    /// The `rows` function returns the number of rows of the matrix.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::math::Matrix;
+    /// assert_eq!(Matrix::zeros(2, 3).rows(), 2);
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// ### cols()
+    ///
+    /// Matrix Property
+    ///
+    /// The `cols` function returns the number of columns of the matrix.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::math::Matrix;
+    /// assert_eq!(Matrix::zeros(2, 3).cols(), 3);
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// ### size()
+    ///
+    /// Matrix Property
+    ///
+    /// The `size` function returns the dimensions of the matrix as a `(rows, cols)` pair, like MATLAB's `size(A)`.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::math::Matrix;
+    /// assert_eq!(Matrix::zeros(2, 3).size(), (2, 3));
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn size(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    /// ### is_square()
+    ///
+    /// Matrix Property
+    ///
+    /// The `is_square` function returns `true` when the matrix has as many rows as columns.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::math::Matrix;
+    /// assert!(Matrix::eye(3).is_square());
+    /// assert!(!Matrix::zeros(2, 3).is_square());
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn is_square(&self) -> bool {
+        self.rows == self.cols
+    }
+
+    /// ### as_slice()
+    ///
+    /// Matrix Property
+    ///
+    /// The `as_slice` function returns the elements of the matrix in row-major order.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::math::Matrix;
+    /// assert_eq!(Matrix::from_rows(&[[1.0, 2.0], [3.0, 4.0]]).as_slice(), [1.0, 2.0, 3.0, 4.0]);
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn as_slice(&self) -> &[f64] {
+        &self.data
+    }
+
+    /// ### into_vec()
+    ///
+    /// Matrix Conversion
+    ///
+    /// The `into_vec` function consumes the matrix and returns its elements in row-major order.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::math::Matrix;
+    /// assert_eq!(Matrix::eye(2).into_vec(), vec![1.0, 0.0, 0.0, 1.0]);
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn into_vec(self) -> Vec<f64> {
+        self.data
+    }
+
+    /// ### get(i, j)
+    ///
+    /// Matrix Indexing
+    ///
+    /// The `get` function returns the element at row `i` and column `j`, or `None` if the position is out of bounds.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::math::Matrix;
+    /// let a = Matrix::from_rows(&[[1.0, 2.0], [3.0, 4.0]]);
+    /// assert_eq!(a.get(0, 1), Some(2.0));
+    /// assert_eq!(a.get(2, 0), None);
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn get(&self, i: usize, j: usize) -> Option<f64> {
+        if i < self.rows && j < self.cols {
+            Some(self.data[i * self.cols + j])
+        } else {
+            None
+        }
+    }
+
+    /// ### row(i)
+    ///
+    /// Matrix Indexing
+    ///
+    /// The `row` function returns the elements of row `i` as a slice.
+    ///
+    /// ### Panics
+    ///
+    /// This function will panic if `i` is out of bounds.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::math::Matrix;
+    /// let a = Matrix::from_rows(&[[1.0, 2.0], [3.0, 4.0]]);
+    /// assert_eq!(a.row(1), [3.0, 4.0]);
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn row(&self, i: usize) -> &[f64] {
+        assert!(i < self.rows, "Row index out of bounds");
+        &self.data[i * self.cols..(i + 1) * self.cols]
+    }
+
+    /// ### col(j)
+    ///
+    /// Matrix Indexing
+    ///
+    /// The `col` function returns a copy of the elements of column `j`.
+    ///
+    /// ### Panics
+    ///
+    /// This function will panic if `j` is out of bounds.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::math::Matrix;
+    /// let a = Matrix::from_rows(&[[1.0, 2.0], [3.0, 4.0]]);
+    /// assert_eq!(a.col(1), [2.0, 4.0]);
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn col(&self, j: usize) -> Vec<f64> {
+        assert!(j < self.cols, "Column index out of bounds");
+        (0..self.rows)
+            .map(|i| self.data[i * self.cols + j])
+            .collect()
+    }
+
+    /// ### slice(rows, cols)
+    ///
+    /// Matrix Indexing
+    ///
+    /// The `slice` function copies the sub-matrix selected by a range of rows and a range of columns,
+    /// similar to MATLAB's `A(r1:r2, c1:c2)` but with zero-based, end-exclusive Rust ranges.
+    ///
+    /// ### Panics
+    ///
+    /// This function will panic if a range is out of bounds.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::math::Matrix;
+    /// let a = Matrix::from_rows(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+    /// assert_eq!(a.slice(0..2, 1..3), Matrix::from_rows(&[[2.0, 3.0], [5.0, 6.0]]));
+    /// assert_eq!(a.slice(2.., ..), Matrix::from_rows(&[[7.0, 8.0, 9.0]]));
+    /// assert_eq!(a.slice(.., 0..=0), Matrix::from_rows(&[[1.0], [4.0], [7.0]]));
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn slice<R: RangeBounds<usize>, C: RangeBounds<usize>>(&self, rows: R, cols: C) -> Matrix {
+        let (r0, r1) = bounds(&rows, self.rows);
+        let (c0, c1) = bounds(&cols, self.cols);
+        Matrix::from_fn(r1 - r0, c1 - c0, |i, j| self[(r0 + i, c0 + j)])
+    }
+
+    /// ### reshape(rows, cols)
+    ///
+    /// Matrix Transformation
+    ///
+    /// The `reshape` function returns a `rows x cols` matrix holding the same elements.
+    /// Elements are taken and placed in row-major order (MATLAB's `reshape` works column by column).
+    ///
+    /// ### Panics
+    ///
+    /// This function will panic if `rows * cols` differs from the number of elements of the matrix.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::math::Matrix;
+    /// let a = Matrix::from_rows(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    /// assert_eq!(a.reshape(3, 2), Matrix::from_rows(&[[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]));
+    /// assert_eq!(a.reshape(1, 6), Matrix::row_vector(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]));
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn reshape(&self, rows: usize, cols: usize) -> Matrix {
+        Matrix::new(rows, cols, self.data.clone())
+    }
+
+    /// ### transpose()
+    ///
+    /// Matrix Transformation
+    ///
+    /// The `transpose` function returns the matrix with its rows and columns swapped (MATLAB's `A'`).
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::math::Matrix;
+    /// let a = Matrix::from_rows(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    /// assert_eq!(a.transpose(), Matrix::from_rows(&[[1.0, 4.0], [2.0, 5.0], [3.0, 6.0]]));
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn transpose(&self) -> Matrix {
+        Matrix::from_fn(self.cols, self.rows, |i, j| self[(j, i)])
+    }
+
+    /// ### map(f)
+    ///
+    /// Matrix Transformation
+    ///
+    /// The `map` function applies `f` to every element and returns the resulting matrix,
+    /// which makes any scalar function of `mathlab::math` usable on a matrix.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::math::{Matrix, sqrt};
+    /// let a = Matrix::from_rows(&[[1.0, 4.0], [9.0, 16.0]]);
+    /// assert_eq!(a.map(sqrt), Matrix::from_rows(&[[1.0, 2.0], [3.0, 4.0]]));
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn map<F: FnMut(f64) -> f64>(&self, f: F) -> Matrix {
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self.data.iter().copied().map(f).collect(),
+        }
+    }
+
+    /// ### mul_elem(other)
+    ///
+    /// Operation Function
+    ///
+    /// The `mul_elem` function multiplies corresponding elements of two matrices of the same size (MATLAB's `A .* B`),
+    /// using the `mult` function.
+    ///
+    /// ### Panics
+    ///
+    /// This function will panic if the matrices are not of the same size.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::math::Matrix;
+    /// let a = Matrix::from_rows(&[[1.0, 2.0], [3.0, 4.0]]);
+    /// assert_eq!(a.mul_elem(&a), Matrix::from_rows(&[[1.0, 4.0], [9.0, 16.0]]));
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn mul_elem(&self, other: &Matrix) -> Matrix {
+        assert_same_size(self, other);
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: mult_vec_vec(&self.data, &other.data),
+        }
+    }
+
+    /// ### matmul(other)
+    ///
+    /// Operation Function
+    ///
+    /// The `matmul` function computes the matrix product `A * B`. Each element of the result is the `dot` product
+    /// of a row of `A` with a column of `B`. The `*` operator calls this function.
+    ///
+    /// ### Panics
+    ///
+    /// This function will panic if the number of columns of `A` differs from the number of rows of `B`.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::math::Matrix;
+    /// let a = Matrix::from_rows(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    /// let b = Matrix::from_rows(&[[7.0, 8.0], [9.0, 10.0], [11.0, 12.0]]);
+    /// assert_eq!(a.matmul(&b), Matrix::from_rows(&[[58.0, 64.0], [139.0, 154.0]]));
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn matmul(&self, other: &Matrix) -> Matrix {
+        assert_eq!(self.cols, other.rows, "Inner matrix dimensions must agree");
+        let other_t = other.transpose();
+        Matrix::from_fn(self.rows, other.cols, |i, j| {
+            dot(self.row(i), other_t.row(j))
+        })
+    }
+}
+
+/// Resolves a range against a dimension of length `len`, returning the start and (exclusive) end.
+fn bounds<R: RangeBounds<usize>>(range: &R, len: usize) -> (usize, usize) {
+    let start = match range.start_bound() {
+        Bound::Included(&s) => s,
+        Bound::Excluded(&s) => s + 1,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&e) => e + 1,
+        Bound::Excluded(&e) => e,
+        Bound::Unbounded => len,
+    };
+    assert!(start <= end && end <= len, "Range out of bounds");
+    (start, end)
+}
+
+/// Checks that two matrices have the same size before an element-wise operation.
+fn assert_same_size(a: &Matrix, b: &Matrix) {
+    assert_eq!(a.size(), b.size(), "Matrices must be of the same size");
+}
+
+impl Index<(usize, usize)> for Matrix {
+    type Output = f64;
+
+    fn index(&self, (i, j): (usize, usize)) -> &f64 {
+        assert!(i < self.rows && j < self.cols, "Index out of bounds");
+        &self.data[i * self.cols + j]
+    }
+}
+
+impl IndexMut<(usize, usize)> for Matrix {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut f64 {
+        assert!(i < self.rows && j < self.cols, "Index out of bounds");
+        &mut self.data[i * self.cols + j]
+    }
+}
+
+impl Add<&Matrix> for &Matrix {
+    type Output = Matrix;
+
+    fn add(self, rhs: &Matrix) -> Matrix {
+        assert_same_size(self, rhs);
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: add_vec_vec(&self.data, &rhs.data),
+        }
+    }
+}
+
+impl Sub<&Matrix> for &Matrix {
+    type Output = Matrix;
+
+    fn sub(self, rhs: &Matrix) -> Matrix {
+        assert_same_size(self, rhs);
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: subt_vec_vec(&self.data, &rhs.data),
+        }
+    }
+}
+
+impl Mul<&Matrix> for &Matrix {
+    type Output = Matrix;
+
+    fn mul(self, rhs: &Matrix) -> Matrix {
+        self.matmul(rhs)
+    }
+}
+
+impl Mul<f64> for &Matrix {
+    type Output = Matrix;
+
+    fn mul(self, rhs: f64) -> Matrix {
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: mult_vec_num(&self.data, rhs),
+        }
+    }
+}
+
+impl Mul<&Matrix> for f64 {
+    type Output = Matrix;
+
+    fn mul(self, rhs: &Matrix) -> Matrix {
+        rhs * self
+    }
+}
+
+impl Neg for &Matrix {
+    type Output = Matrix;
+
+    fn neg(self) -> Matrix {
+        self * -1.0
+    }
+}
+
+// Owned operands forward to the reference implementations above.
+macro_rules! forward_owned_binop {
+    ($tr:ident, $method:ident) => {
+        impl $tr<Matrix> for Matrix {
+            type Output = Matrix;
+
+            fn $method(self, rhs: Matrix) -> Matrix {
+                (&self).$method(&rhs)
+            }
+        }
+
+        impl $tr<&Matrix> for Matrix {
+            type Output = Matrix;
+
+            fn $method(self, rhs: &Matrix) -> Matrix {
+                (&self).$method(rhs)
+            }
+        }
+
+        impl $tr<Matrix> for &Matrix {
+            type Output = Matrix;
+
+            fn $method(self, rhs: Matrix) -> Matrix {
+                self.$method(&rhs)
+            }
+        }
+    };
+}
+
+forward_owned_binop!(Add, add);
+forward_owned_binop!(Sub, sub);
+forward_owned_binop!(Mul, mul);
+
+impl Mul<f64> for Matrix {
+    type Output = Matrix;
+
+    fn mul(self, rhs: f64) -> Matrix {
+        &self * rhs
+    }
+}
+
+impl Mul<Matrix> for f64 {
+    type Output = Matrix;
+
+    fn mul(self, rhs: Matrix) -> Matrix {
+        &rhs * self
+    }
+}
+
+impl Neg for Matrix {
+    type Output = Matrix;
+
+    fn neg(self) -> Matrix {
+        -&self
+    }
+}
+
+impl fmt::Display for Matrix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for i in 0..self.rows {
+            let row: Vec<String> = self.row(i).iter().map(|x| x.to_string()).collect();
+            writeln!(f, "{}", row.join("\t"))?;
+        }
+        Ok(())
+    }
+}
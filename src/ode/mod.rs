@@ -0,0 +1,751 @@
+use crate::error::MathError;
+use crate::linalg::lu;
+use crate::matrix::Matrix;
+use crate::optimize::fzero;
+
+/// Nodes of the Dormand–Prince 5(4) pair.
+const DP_C: [f64; 5] = [1.0 / 5.0, 3.0 / 10.0, 4.0 / 5.0, 8.0 / 9.0, 1.0];
+
+/// Weights of the fifth-order Dormand–Prince solution (the seventh stage has weight zero).
+const DP_B: [f64; 6] = [
+    35.0 / 384.0,
+    0.0,
+    500.0 / 1113.0,
+    125.0 / 192.0,
+    -2187.0 / 6784.0,
+    11.0 / 84.0,
+];
+
+/// Differences between the fifth- and fourth-order Dormand–Prince weights.
+const DP_E: [f64; 7] = [
+    71.0 / 57600.0,
+    0.0,
+    -71.0 / 16695.0,
+    71.0 / 1920.0,
+    -17253.0 / 339200.0,
+    22.0 / 525.0,
+    -1.0 / 40.0,
+];
+
+/// Coefficients of the fourth-order continuous extension of the Dormand–Prince pair (Hairer's `DOPRI5`).
+const DP_D: [f64; 7] = [
+    -12715105075.0 / 11282082432.0,
+    0.0,
+    87487479700.0 / 32700410799.0,
+    -10690763975.0 / 1880347072.0,
+    701980252875.0 / 199316789632.0,
+    -1453857185.0 / 822651844.0,
+    69997945.0 / 29380423.0,
+];
+
+/// ### OdeSolution
+///
+/// Solver Result
+///
+/// The `OdeSolution` type is returned by the ODE solvers of this module (`rk4`, `ode45`, `ode23s` and their `*_events` variants).
+/// `y[i]` is the state at time `t[i]`, where `t` is the requested time grid, cut short at a terminal event.
+/// `te` and `ye` hold the times and states of the detected events, and `steps`, `rejected` and `evaluations`
+/// count the accepted steps, the rejected steps and the calls of the right-hand side (MATLAB's `stats`).
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{fix, exp, range_from_to};
+/// use mathlab::ode::ode45;
+/// let s = ode45(|_t, y| vec![-y[0]], &range_from_to(0.0, 2.0, 0.5), &[1.0], 1e-8, 1e-10).unwrap();
+/// assert_eq!(s.t, vec![0.0, 0.5, 1.0, 1.5, 2.0]);
+/// assert_eq!((s.y.len(), fix(s.y[4][0], 8)), (5, fix(exp(-2.0), 8)));
+/// assert!(s.te.is_empty() && s.steps > 0 && s.evaluations > 6 * s.steps);
+/// ```
+/// <small>End Type Doc</small>
+#[derive(Debug, Clone, PartialEq)]
+pub struct OdeSolution {
+    pub t: Vec<f64>,
+    pub y: Vec<Vec<f64>>,
+    pub te: Vec<f64>,
+    pub ye: Vec<Vec<f64>>,
+    pub steps: usize,
+    pub rejected: usize,
+    pub evaluations: usize,
+}
+
+/// The adaptive methods shared by the `ode45` and `ode23s` drivers.
+#[derive(Clone, Copy)]
+enum Method {
+    DormandPrince,
+    Rosenbrock,
+}
+
+impl Method {
+    /// The order of the embedded error estimate, which sets the step size exponent.
+    fn order(self) -> i32 {
+        match self {
+            Method::DormandPrince => 4,
+            Method::Rosenbrock => 2,
+        }
+    }
+}
+
+/// The interpolant of one accepted step, used for output at the requested times and to locate events.
+enum Dense {
+    DormandPrince {
+        t: f64,
+        h: f64,
+        r: [Vec<f64>; 5],
+    },
+    Rosenbrock {
+        t: f64,
+        h: f64,
+        y: Vec<f64>,
+        k1: Vec<f64>,
+        k2: Vec<f64>,
+    },
+}
+
+impl Dense {
+    /// Evaluates the interpolant at time `s` within the step.
+    fn eval(&self, s: f64) -> Vec<f64> {
+        match self {
+            Dense::DormandPrince { t, h, r } => {
+                let th = (s - t) / h;
+                let th1 = 1.0 - th;
+                (0..r[0].len())
+                    .map(|i| {
+                        r[0][i] + th * (r[1][i] + th1 * (r[2][i] + th * (r[3][i] + th1 * r[4][i])))
+                    })
+                    .collect()
+            }
+            Dense::Rosenbrock { t, h, y, k1, k2 } => {
+                let d = rosenbrock_d();
+                let th = (s - t) / h;
+                let c1 = th * (1.0 - th) / (1.0 - 2.0 * d);
+                let c2 = th * (th - 2.0 * d) / (1.0 - 2.0 * d);
+                (0..y.len())
+                    .map(|i| y[i] + h * (c1 * k1[i] + c2 * k2[i]))
+                    .collect()
+            }
+        }
+    }
+}
+
+/// An event function and whether it stops the integration.
+type Event<'a> = (&'a dyn Fn(f64, &[f64]) -> f64, bool);
+
+/// An attempted step: the new state, the right-hand side there, the scaled error norm and the interpolant.
+struct Trial {
+    y: Vec<f64>,
+    fy: Vec<f64>,
+    err: f64,
+    dense: Dense,
+    evaluations: usize,
+}
+
+/// The diagonal coefficient `1 / (2 + sqrt(2))` of the Shampine–Reichelt Rosenbrock method.
+fn rosenbrock_d() -> f64 {
+    1.0 / (2.0 + 2.0_f64.sqrt())
+}
+
+/// Checks the time grid and returns the direction of integration.
+fn check_grid(t: &[f64]) -> Result<f64, MathError> {
+    if t.len() < 2 {
+        return Err(MathError::Domain("time grid must have at least two points"));
+    }
+    let dir = (t[1] - t[0]).signum();
+    if t.iter().any(|x| !x.is_finite()) || t.windows(2).any(|w| (w[1] - w[0]) * dir <= 0.0) {
+        return Err(MathError::Domain(
+            "time grid must be finite and strictly monotonic",
+        ));
+    }
+    Ok(dir)
+}
+
+/// Checks the initial state and the length of the first right-hand side evaluation.
+fn check_state(y0: &[f64], f0: &[f64]) -> Result<(), MathError> {
+    if y0.is_empty() {
+        Err(MathError::EmptyInput)
+    } else if f0.len() != y0.len() {
+        Err(MathError::DimensionMismatch {
+            expected: y0.len(),
+            found: f0.len(),
+        })
+    } else {
+        Ok(())
+    }
+}
+
+/// Returns `h * sum(c * k)` for the given coefficients and stages.
+fn lincomb(h: f64, terms: &[(f64, &[f64])]) -> Vec<f64> {
+    let mut out = vec![0.0; terms[0].1.len()];
+    for &(c, k) in terms {
+        if c != 0.0 {
+            for (o, ki) in out.iter_mut().zip(k) {
+                *o += h * c * ki;
+            }
+        }
+    }
+    out
+}
+
+/// Returns `y + d`.
+fn add(y: &[f64], d: &[f64]) -> Vec<f64> {
+    y.iter().zip(d).map(|(a, b)| a + b).collect()
+}
+
+/// The root mean square of `e` scaled by the mixed tolerance `atol + rtol * max(|y0|, |y1|)`.
+fn scaled_norm(e: &[f64], y0: &[f64], y1: &[f64], rtol: f64, atol: f64) -> f64 {
+    let sum: f64 = (0..e.len())
+        .map(|i| {
+            let sc = atol + rtol * y0[i].abs().max(y1[i].abs());
+            (e[i] / sc).powi(2)
+        })
+        .sum();
+    (sum / e.len() as f64).sqrt()
+}
+
+/// Chooses the initial step size from the scale of the solution and its derivatives (Hairer's `hinit`).
+#[allow(clippy::too_many_arguments)]
+fn initial_step<F: Fn(f64, &[f64]) -> Vec<f64>>(
+    f: &F,
+    t0: f64,
+    y0: &[f64],
+    f0: &[f64],
+    span: f64,
+    order: i32,
+    rtol: f64,
+    atol: f64,
+) -> f64 {
+    let dir = span.signum();
+    let d0 = scaled_norm(y0, y0, y0, rtol, atol);
+    let d1 = scaled_norm(f0, y0, y0, rtol, atol);
+    let h0 = if d0 < 1e-5 || d1 < 1e-5 {
+        1e-6
+    } else {
+        0.01 * d0 / d1
+    }
+    .min(span.abs());
+    let y1 = add(y0, &lincomb(dir * h0, &[(1.0, f0)]));
+    let f1 = f(t0 + dir * h0, &y1);
+    let df: Vec<f64> = f1.iter().zip(f0).map(|(a, b)| a - b).collect();
+    let d2 = scaled_norm(&df, y0, y0, rtol, atol) / h0;
+    let h1 = if d1.max(d2) <= 1e-15 {
+        (h0 * 1e-3).max(1e-6)
+    } else {
+        (0.01 / d1.max(d2)).powf(1.0 / (order + 1) as f64)
+    };
+    (100.0 * h0).min(h1).min(span.abs())
+}
+
+/// Takes one Dormand–Prince 5(4) step of size `h` from `(t, y)`, where `k1 = f(t, y)`.
+fn dormand_prince_step<F: Fn(f64, &[f64]) -> Vec<f64>>(
+    f: &F,
+    t: f64,
+    y: &[f64],
+    k1: &[f64],
+    h: f64,
+    rtol: f64,
+    atol: f64,
+) -> Trial {
+    let k2 = f(t + DP_C[0] * h, &add(y, &lincomb(h, &[(1.0 / 5.0, k1)])));
+    let k3 = f(
+        t + DP_C[1] * h,
+        &add(y, &lincomb(h, &[(3.0 / 40.0, k1), (9.0 / 40.0, &k2)])),
+    );
+    let k4 = f(
+        t + DP_C[2] * h,
+        &add(
+            y,
+            &lincomb(
+                h,
+                &[(44.0 / 45.0, k1), (-56.0 / 15.0, &k2), (32.0 / 9.0, &k3)],
+            ),
+        ),
+    );
+    let k5 = f(
+        t + DP_C[3] * h,
+        &add(
+            y,
+            &lincomb(
+                h,
+                &[
+                    (19372.0 / 6561.0, k1),
+                    (-25360.0 / 2187.0, &k2),
+                    (64448.0 / 6561.0, &k3),
+                    (-212.0 / 729.0, &k4),
+                ],
+            ),
+        ),
+    );
+    let k6 = f(
+        t + DP_C[4] * h,
+        &add(
+            y,
+            &lincomb(
+                h,
+                &[
+                    (9017.0 / 3168.0, k1),
+                    (-355.0 / 33.0, &k2),
+                    (46732.0 / 5247.0, &k3),
+                    (49.0 / 176.0, &k4),
+                    (-5103.0 / 18656.0, &k5),
+                ],
+            ),
+        ),
+    );
+    let stages = [k1, &k2, &k3, &k4, &k5, &k6];
+    let terms: Vec<(f64, &[f64])> = DP_B.iter().copied().zip(stages).collect();
+    let y_new = add(y, &lincomb(h, &terms));
+    let k7 = f(t + h, &y_new);
+    let stages = [k1, &k2, &k3, &k4, &k5, &k6, &k7];
+    let terms: Vec<(f64, &[f64])> = DP_E.iter().copied().zip(stages).collect();
+    let err = scaled_norm(&lincomb(h, &terms), y, &y_new, rtol, atol);
+    // Continuous extension
+    let diff: Vec<f64> = y_new.iter().zip(y).map(|(a, b)| a - b).collect();
+    let bspl: Vec<f64> = (0..y.len()).map(|i| h * k1[i] - diff[i]).collect();
+    let r4: Vec<f64> = (0..y.len())
+        .map(|i| diff[i] - h * k7[i] - bspl[i])
+        .collect();
+    let terms: Vec<(f64, &[f64])> = DP_D.iter().copied().zip(stages).collect();
+    let r5 = lincomb(h, &terms);
+    Trial {
+        dense: Dense::DormandPrince {
+            t,
+            h,
+            r: [y.to_vec(), diff, bspl, r4, r5],
+        },
+        y: y_new,
+        fy: k7,
+        err,
+        evaluations: 6,
+    }
+}
+
+/// Takes one step of the linearly implicit Rosenbrock 2(3) method of Shampine and Reichelt (MATLAB's `ode23s`)
+/// of size `h` from `(t, y)`, where `f0 = f(t, y)`. The Jacobian and the time derivative are approximated
+/// by forward differences. A singular iteration matrix rejects the step.
+fn rosenbrock_step<F: Fn(f64, &[f64]) -> Vec<f64>>(
+    f: &F,
+    t: f64,
+    y: &[f64],
+    f0: &[f64],
+    h: f64,
+    rtol: f64,
+    atol: f64,
+) -> Result<Trial, MathError> {
+    let n = y.len();
+    let d = rosenbrock_d();
+    let e32 = 6.0 + 2.0_f64.sqrt();
+    let sqrt_eps = f64::EPSILON.sqrt();
+    let mut jac = Matrix::zeros(n, n);
+    let mut yd = y.to_vec();
+    for j in 0..n {
+        let delta = sqrt_eps * y[j].abs().max(1.0);
+        yd[j] = y[j] + delta;
+        let fd = f(t, &yd);
+        for i in 0..n {
+            jac[(i, j)] = (fd[i] - f0[i]) / delta;
+        }
+        yd[j] = y[j];
+    }
+    let dt = sqrt_eps * t.abs().max(1.0) * h.signum();
+    let ft = f(t + dt, y);
+    let hdt: Vec<f64> = (0..n).map(|i| h * d * (ft[i] - f0[i]) / dt).collect();
+    let mut evaluations = n + 1;
+    let w = lu(&Matrix::from_fn(n, n, |i, j| {
+        f64::from(u8::from(i == j)) - h * d * jac[(i, j)]
+    }))?;
+    if w.is_singular() {
+        return Ok(Trial {
+            y: y.to_vec(),
+            fy: f0.to_vec(),
+            err: f64::INFINITY,
+            dense: Dense::Rosenbrock {
+                t,
+                h,
+                y: y.to_vec(),
+                k1: f0.to_vec(),
+                k2: f0.to_vec(),
+            },
+            evaluations,
+        });
+    }
+    let k1 = w.solve(&add(f0, &hdt))?;
+    let f1 = f(t + 0.5 * h, &add(y, &lincomb(0.5 * h, &[(1.0, &k1)])));
+    let rhs: Vec<f64> = (0..n).map(|i| f1[i] - k1[i]).collect();
+    let k2 = add(&w.solve(&rhs)?, &k1);
+    let y_new = add(y, &lincomb(h, &[(1.0, &k2)]));
+    let f2 = f(t + h, &y_new);
+    evaluations += 2;
+    let rhs: Vec<f64> = (0..n)
+        .map(|i| f2[i] - e32 * (k2[i] - f1[i]) - 2.0 * (k1[i] - f0[i]) + hdt[i])
+        .collect();
+    let k3 = w.solve(&rhs)?;
+    let e = lincomb(h / 6.0, &[(1.0, &k1), (-2.0, &k2), (1.0, &k3)]);
+    let err = scaled_norm(&e, y, &y_new, rtol, atol);
+    Ok(Trial {
+        dense: Dense::Rosenbrock {
+            t,
+            h,
+            y: y.to_vec(),
+            k1,
+            k2,
+        },
+        y: y_new,
+        fy: f2,
+        err,
+        evaluations,
+    })
+}
+
+/// The adaptive driver behind `ode45` and `ode23s`: steps from `t[0]` to the end of the grid with error control,
+/// fills in the requested times from the interpolants and locates sign changes of the optional event function.
+fn solve_adaptive<F: Fn(f64, &[f64]) -> Vec<f64>>(
+    method: Method,
+    f: F,
+    t: &[f64],
+    y0: &[f64],
+    rtol: f64,
+    atol: f64,
+    event: Option<Event>,
+) -> Result<OdeSolution, MathError> {
+    let dir = check_grid(t)?;
+    if !(rtol > 0.0 && atol > 0.0) {
+        return Err(MathError::Domain("tolerance must be positive"));
+    }
+    let t_end = t[t.len() - 1];
+    let mut tc = t[0];
+    let mut yc = y0.to_vec();
+    let mut fc = f(tc, &yc);
+    check_state(y0, &fc)?;
+    let mut out = OdeSolution {
+        t: vec![tc],
+        y: vec![yc.clone()],
+        te: Vec::new(),
+        ye: Vec::new(),
+        steps: 0,
+        rejected: 0,
+        evaluations: 2,
+    };
+    let order = method.order();
+    let mut h = initial_step(&f, tc, &yc, &fc, t_end - tc, order, rtol, atol);
+    let mut g_old = event.map(|(g, _)| g(tc, &yc));
+    let mut next = 1;
+    let mut rejected_last = false;
+    while (t_end - tc) * dir > 0.0 {
+        if h.is_nan() || h <= 16.0 * f64::EPSILON * tc.abs() {
+            return Err(MathError::NoConvergence {
+                iterations: out.steps,
+            });
+        }
+        // Stretch the step to the end of the grid rather than leave a sliver
+        let last = 1.1 * h >= (t_end - tc).abs();
+        let step = if last { t_end - tc } else { dir * h };
+        let trial = match method {
+            Method::DormandPrince => dormand_prince_step(&f, tc, &yc, &fc, step, rtol, atol),
+            Method::Rosenbrock => rosenbrock_step(&f, tc, &yc, &fc, step, rtol, atol)?,
+        };
+        out.evaluations += trial.evaluations;
+        let exponent = -1.0 / (order + 1) as f64;
+        if trial.err.is_nan() || trial.err > 1.0 {
+            out.rejected += 1;
+            rejected_last = true;
+            let fac = if trial.err.is_finite() {
+                (0.9 * trial.err.powf(exponent)).max(0.2)
+            } else {
+                0.2
+            };
+            h = step.abs() * fac.min(1.0);
+            continue;
+        }
+        out.steps += 1;
+        let t_new = if last { t_end } else { tc + step };
+        if let (Some((g, terminal)), Some(g0)) = (event, g_old) {
+            let g_new = g(t_new, &trial.y);
+            if g0 != 0.0 && (g_new == 0.0 || (g_new > 0.0) != (g0 > 0.0)) {
+                let root = fzero(
+                    |s| g(s, &trial.dense.eval(s)),
+                    tc,
+                    t_new,
+                    4.0 * f64::EPSILON,
+                    100,
+                )?;
+                let ye = if root.x == t_new {
+                    trial.y.clone()
+                } else {
+                    trial.dense.eval(root.x)
+                };
+                out.te.push(root.x);
+                out.ye.push(ye.clone());
+                if terminal {
+                    while next < t.len() && (t[next] - root.x) * dir < 0.0 {
+                        out.t.push(t[next]);
+                        out.y.push(trial.dense.eval(t[next]));
+                        next += 1;
+                    }
+                    out.t.push(root.x);
+                    out.y.push(ye);
+                    return Ok(out);
+                }
+            }
+            g_old = Some(g_new);
+        }
+        while next < t.len() && (t[next] - t_new) * dir <= 0.0 {
+            out.t.push(t[next]);
+            out.y.push(if t[next] == t_new {
+                trial.y.clone()
+            } else {
+                trial.dense.eval(t[next])
+            });
+            next += 1;
+        }
+        let mut fac = (0.9 * trial.err.powf(exponent)).clamp(0.2, 5.0);
+        if rejected_last {
+            fac = fac.min(1.0);
+        }
+        rejected_last = false;
+        h = step.abs() * fac;
+        tc = t_new;
+        yc = trial.y;
+        fc = trial.fy;
+    }
+    Ok(out)
+}
+
+/// ### rk4(f, t, y0)
+///
+/// ODE Solver
+///
+/// The `rk4` function solves the initial value problem `y' = f(t, y)`, `y(t[0]) = y0` with the classical
+/// fourth-order Runge–Kutta method, taking one step per interval of the time grid `t`
+/// (for example `range_from_to(0.0, 10.0, 0.01)`). The grid may be decreasing to integrate backwards.
+/// There is no error control: the accuracy is set by the grid spacing, with a global error of order `h^4`.
+///
+/// `f` takes the time and the state and returns the derivative of the state.
+///
+/// ### Errors
+///
+/// Returns `MathError::Domain` if `t` has fewer than two points or is not finite and strictly monotonic,
+/// `MathError::EmptyInput` if `y0` is empty, and `MathError::DimensionMismatch` if `f` returns a vector
+/// of a different length than `y0`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{exp, fix, range_from_to};
+/// use mathlab::ode::rk4;
+/// let s = rk4(|_t, y| vec![-y[0]], &range_from_to(0.0, 1.0, 0.1), &[1.0]).unwrap();
+/// assert_eq!((s.t.len(), s.steps, s.evaluations), (11, 10, 40));
+/// assert_eq!(fix(s.y[10][0], 5), fix(exp(-1.0), 5));
+/// // y'' = -y with y(0) = 0, y'(0) = 1 is y = sin(t)
+/// let s = rk4(|_t, y| vec![y[1], -y[0]], &range_from_to(0.0, 1.0, 0.01), &[0.0, 1.0]).unwrap();
+/// assert_eq!(fix(s.y[100][0], 8), fix(1.0_f64.sin(), 8));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn rk4<F: Fn(f64, &[f64]) -> Vec<f64>>(
+    f: F,
+    t: &[f64],
+    y0: &[f64],
+) -> Result<OdeSolution, MathError> {
+    check_grid(t)?;
+    let mut y = y0.to_vec();
+    let mut k1 = f(t[0], &y);
+    check_state(y0, &k1)?;
+    let mut out = OdeSolution {
+        t: t.to_vec(),
+        y: vec![y.clone()],
+        te: Vec::new(),
+        ye: Vec::new(),
+        steps: 0,
+        rejected: 0,
+        evaluations: 0,
+    };
+    for w in t.windows(2) {
+        let (tc, h) = (w[0], w[1] - w[0]);
+        if out.steps > 0 {
+            k1 = f(tc, &y);
+        }
+        let k2 = f(tc + 0.5 * h, &add(&y, &lincomb(0.5 * h, &[(1.0, &k1)])));
+        let k3 = f(tc + 0.5 * h, &add(&y, &lincomb(0.5 * h, &[(1.0, &k2)])));
+        let k4 = f(tc + h, &add(&y, &lincomb(h, &[(1.0, &k3)])));
+        y = add(
+            &y,
+            &lincomb(h / 6.0, &[(1.0, &k1), (2.0, &k2), (2.0, &k3), (1.0, &k4)]),
+        );
+        out.y.push(y.clone());
+        out.steps += 1;
+        out.evaluations += 4;
+    }
+    Ok(out)
+}
+
+/// ### ode45(f, t, y0, rtol, atol)
+///
+/// ODE Solver
+///
+/// The `ode45` function solves the initial value problem `y' = f(t, y)`, `y(t[0]) = y0` with the adaptive
+/// Dormand–Prince 5(4) Runge–Kutta pair (MATLAB's `ode45`). The step size is chosen so that the local error
+/// of each component stays below about `atol + rtol * |y|`, independently of the time grid `t`;
+/// the solution at the requested times is then filled in from the method's fourth-order dense output,
+/// so a fine grid such as `range_from_to(0.0, 10.0, 0.01)` costs little extra. The grid may be decreasing.
+///
+/// `ode45` is the solver to try first. For stiff problems, where it needs very many tiny steps, use `ode23s`.
+///
+/// ### Errors
+///
+/// Returns `MathError::Domain` if `t` has fewer than two points or is not finite and strictly monotonic,
+/// or `rtol` or `atol` is not positive, `MathError::EmptyInput` if `y0` is empty,
+/// `MathError::DimensionMismatch` if `f` returns a vector of a different length than `y0`,
+/// and `MathError::NoConvergence` if the step size underflows, as at a singularity of the solution.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{fix, range_from_to, MathError};
+/// use mathlab::ode::ode45;
+/// // y'' = -y with y(0) = 1, y'(0) = 0 is y = cos(t)
+/// let t = range_from_to(0.0, 10.0, 0.1);
+/// let s = ode45(|_t, y| vec![y[1], -y[0]], &t, &[1.0, 0.0], 1e-9, 1e-12).unwrap();
+/// assert_eq!(s.y.len(), 101);
+/// assert!(s.t.iter().zip(&s.y).all(|(t, y)| (y[0] - t.cos()).abs() < 1e-7));
+/// // y' = y^2 with y(0) = 1 blows up at t = 1
+/// let s = ode45(|_t, y| vec![y[0] * y[0]], &[0.0, 2.0], &[1.0], 1e-6, 1e-9);
+/// assert!(matches!(s, Err(MathError::NoConvergence { .. })));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn ode45<F: Fn(f64, &[f64]) -> Vec<f64>>(
+    f: F,
+    t: &[f64],
+    y0: &[f64],
+    rtol: f64,
+    atol: f64,
+) -> Result<OdeSolution, MathError> {
+    solve_adaptive(Method::DormandPrince, f, t, y0, rtol, atol, None)
+}
+
+/// ### ode45_events(f, t, y0, rtol, atol, event, terminal)
+///
+/// ODE Solver
+///
+/// The `ode45_events` function is `ode45` with event detection (MATLAB's `Events` option).
+/// After every step the event function `event(t, y)` is checked for a sign change; its zero is then located
+/// on the dense output and recorded in `te` and `ye`. If `terminal` is `true` the integration stops
+/// at the first event, and the solution ends with the state at `te[0]`; otherwise all events are recorded.
+/// An event whose zero falls exactly on `t[0]` is not reported.
+///
+/// ### Errors
+///
+/// Returns the same errors as `ode45`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{fix, range_from_to};
+/// use mathlab::ode::ode45_events;
+/// // A ball dropped from 10 m hits the ground after sqrt(20 / 9.81) s
+/// let f = |_t: f64, y: &[f64]| vec![y[1], -9.81];
+/// let s = ode45_events(f, &range_from_to(0.0, 5.0, 0.5), &[10.0, 0.0], 1e-8, 1e-10, &|_t, y| y[0], true).unwrap();
+/// assert_eq!(fix(s.te[0], 8), fix((20.0 / 9.81_f64).sqrt(), 8));
+/// assert_eq!(s.t, vec![0.0, 0.5, 1.0, s.te[0]]);
+/// assert_eq!(fix(s.ye[0][0], 8), 0.0);
+/// // y = cos(t) crosses zero at pi/2, 3 pi/2 and 5 pi/2 on [0, 10]
+/// let f = |_t: f64, y: &[f64]| vec![y[1], -y[0]];
+/// let s = ode45_events(f, &[0.0, 10.0], &[1.0, 0.0], 1e-9, 1e-12, &|_t, y| y[0], false).unwrap();
+/// assert_eq!(s.te.len(), 3);
+/// assert_eq!(fix(s.te[2], 6), fix(2.5 * std::f64::consts::PI, 6));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn ode45_events<F: Fn(f64, &[f64]) -> Vec<f64>>(
+    f: F,
+    t: &[f64],
+    y0: &[f64],
+    rtol: f64,
+    atol: f64,
+    event: &dyn Fn(f64, &[f64]) -> f64,
+    terminal: bool,
+) -> Result<OdeSolution, MathError> {
+    solve_adaptive(
+        Method::DormandPrince,
+        f,
+        t,
+        y0,
+        rtol,
+        atol,
+        Some((event, terminal)),
+    )
+}
+
+/// ### ode23s(f, t, y0, rtol, atol)
+///
+/// ODE Solver
+///
+/// The `ode23s` function solves the stiff initial value problem `y' = f(t, y)`, `y(t[0]) = y0` with the
+/// linearly implicit Rosenbrock 2(3) method of Shampine and Reichelt (MATLAB's `ode23s`). Each step solves
+/// linear systems with `I - h d J`, where the Jacobian `J` of `f` is approximated by finite differences,
+/// so the step size is limited by accuracy alone and not by the fastest decaying component.
+/// Error control, output at the requested times (through the method's interpolant) and the time grid
+/// work as in `ode45`.
+///
+/// Every step costs `n + 3` evaluations of `f` and an LU decomposition for `n` equations, so for non-stiff
+/// problems `ode45` is cheaper; the order is low, so tight tolerances need many steps.
+///
+/// ### Errors
+///
+/// Returns the same errors as `ode45`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::ode::{ode23s, ode45};
+/// // y' = -1000 (y - cos(t)) quickly relaxes to a slowly varying solution
+/// let f = |t: f64, y: &[f64]| vec![-1000.0 * (y[0] - t.cos())];
+/// let stiff = ode23s(f, &[0.0, 2.0], &[0.0], 1e-3, 1e-6).unwrap();
+/// let exact = (1e6 * 2.0_f64.cos() + 1e3 * 2.0_f64.sin()) / (1e6 + 1.0);
+/// assert!((stiff.y[1][0] - exact).abs() < 1e-3);
+/// let nonstiff = ode45(f, &[0.0, 2.0], &[0.0], 1e-3, 1e-6).unwrap();
+/// assert!(stiff.steps * 5 < nonstiff.steps);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn ode23s<F: Fn(f64, &[f64]) -> Vec<f64>>(
+    f: F,
+    t: &[f64],
+    y0: &[f64],
+    rtol: f64,
+    atol: f64,
+) -> Result<OdeSolution, MathError> {
+    solve_adaptive(Method::Rosenbrock, f, t, y0, rtol, atol, None)
+}
+
+/// ### ode23s_events(f, t, y0, rtol, atol, event, terminal)
+///
+/// ODE Solver
+///
+/// The `ode23s_events` function is `ode23s` with event detection, handled as in `ode45_events`.
+///
+/// ### Errors
+///
+/// Returns the same errors as `ode45`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::fix;
+/// use mathlab::ode::ode23s_events;
+/// // Stop when the relaxing solution of y' = -50 (y - 1), y(0) = 0, reaches 0.5
+/// let f = |_t: f64, y: &[f64]| vec![-50.0 * (y[0] - 1.0)];
+/// let s = ode23s_events(f, &[0.0, 1.0], &[0.0], 1e-8, 1e-10, &|_t, y| y[0] - 0.5, true).unwrap();
+/// assert_eq!(fix(s.te[0], 4), fix(2.0_f64.ln() / 50.0, 4));
+/// assert_eq!(s.t.last(), s.te.last());
+/// ```
+/// <small>End Fun Doc</small>
+pub fn ode23s_events<F: Fn(f64, &[f64]) -> Vec<f64>>(
+    f: F,
+    t: &[f64],
+    y0: &[f64],
+    rtol: f64,
+    atol: f64,
+    event: &dyn Fn(f64, &[f64]) -> f64,
+    terminal: bool,
+) -> Result<OdeSolution, MathError> {
+    solve_adaptive(
+        Method::Rosenbrock,
+        f,
+        t,
+        y0,
+        rtol,
+        atol,
+        Some((event, terminal)),
+    )
+}
@@ -0,0 +1,824 @@
+use crate::error::MathError;
+use crate::linalg;
+use crate::matrix::Matrix;
+
+/// ### Solution
+///
+/// Solver Result
+///
+/// The `Solution` type is returned by the root finders (`bisect`, `fzero`, `newton`, `secant`)
+/// and the scalar minimizers (`golden`, `fminbnd`) of this module. It holds the final point `x`, the value `fx = f(x)`
+/// (the residual for a root finder, the minimum for a minimizer), the number of iterations used,
+/// and whether the tolerance was met before the iteration limit or a breakdown such as a zero derivative.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::fix64;
+/// use mathlab::optimize::fzero;
+/// let s = fzero(|x| x * x - 2.0, 0.0, 2.0, 1e-12, 100).unwrap();
+/// assert_eq!(fix64(s.x), 1.4142135);
+/// assert!(s.converged && s.fx.abs() < 1e-12 && s.iterations < 20);
+/// let s = fzero(|x| x * x - 2.0, 0.0, 2.0, 1e-12, 2).unwrap();
+/// assert!(!s.converged && s.iterations == 2);
+/// ```
+/// <small>End Type Doc</small>
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Solution {
+    pub x: f64,
+    pub fx: f64,
+    pub iterations: usize,
+    pub converged: bool,
+}
+
+/// Checks that `tol` is a positive tolerance.
+fn check_tol(tol: f64) -> Result<(), MathError> {
+    if tol > 0.0 {
+        Ok(())
+    } else {
+        Err(MathError::Domain("tolerance must be positive"))
+    }
+}
+
+/// Checks that `a` and `b` are finite and returns them in increasing order.
+fn check_bounds(a: f64, b: f64) -> Result<(f64, f64), MathError> {
+    if !a.is_finite() || !b.is_finite() {
+        Err(MathError::Domain("bounds must be finite"))
+    } else {
+        Ok((a.min(b), a.max(b)))
+    }
+}
+
+/// The accuracy in `x` that the solvers aim for, absolute near zero and relative elsewhere.
+fn x_tol(tol: f64, x: f64) -> f64 {
+    tol * x.abs().max(1.0)
+}
+
+/// ### bisect(f, a, b, tol, max_iter)
+///
+/// Root Finding Function
+///
+/// The `bisect` function finds a root of `f` in the interval `[a, b]` by bisection, halving the bracket until it is
+/// narrower than about `2 * tol * max(1, |x|)`. `f(a)` and `f(b)` must have opposite signs (or one of them be zero).
+/// It is slow but always converges for a continuous `f`.
+///
+/// ### Errors
+///
+/// Returns `MathError::Domain` if `tol` is not positive, a bound is not finite,
+/// or `f(a)` and `f(b)` have the same sign.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{cos, fix64};
+/// use mathlab::optimize::bisect;
+/// let s = bisect(|x| cos(x) - x, 0.0, 1.0, 1e-10, 100).unwrap();
+/// assert_eq!(fix64(s.x), 0.73908514);
+/// assert!(s.converged && s.iterations <= 35);
+/// assert!(bisect(|x| x * x + 1.0, -1.0, 1.0, 1e-10, 100).is_err());
+/// ```
+/// <small>End Fun Doc</small>
+pub fn bisect<F: Fn(f64) -> f64>(
+    f: F,
+    a: f64,
+    b: f64,
+    tol: f64,
+    max_iter: usize,
+) -> Result<Solution, MathError> {
+    check_tol(tol)?;
+    let (mut a, mut b) = check_bounds(a, b)?;
+    let mut fa = f(a);
+    let fb = f(b);
+    for (x, fx) in [(a, fa), (b, fb)] {
+        if fx == 0.0 {
+            return Ok(Solution {
+                x,
+                fx,
+                iterations: 0,
+                converged: true,
+            });
+        }
+    }
+    if fa.is_nan() || fb.is_nan() || (fa > 0.0) == (fb > 0.0) {
+        return Err(MathError::Domain("f(a) and f(b) must have opposite signs"));
+    }
+    let mut s = Solution {
+        x: a,
+        fx: fa,
+        iterations: 0,
+        converged: false,
+    };
+    while s.iterations < max_iter {
+        s.iterations += 1;
+        let half = 0.5 * (b - a);
+        s.x = a + half;
+        s.fx = f(s.x);
+        if s.fx == 0.0 || half <= x_tol(tol, s.x) {
+            s.converged = true;
+            break;
+        }
+        if s.fx.is_nan() {
+            break;
+        }
+        if (s.fx > 0.0) == (fa > 0.0) {
+            a = s.x;
+            fa = s.fx;
+        } else {
+            b = s.x;
+        }
+    }
+    Ok(s)
+}
+
+/// ### fzero(f, a, b, tol, max_iter)
+///
+/// Root Finding Function
+///
+/// The `fzero` function finds a root of `f` in the bracket `[a, b]` with Brent's method (MATLAB's `fzero(f, [a b])`),
+/// which combines inverse quadratic interpolation and the secant method with bisection as a safeguard.
+/// It converges superlinearly for smooth `f` and never leaves the bracket, so it is the method of choice
+/// when a sign change is known. `f(a)` and `f(b)` must have opposite signs (or one of them be zero).
+///
+/// ### Errors
+///
+/// Returns `MathError::Domain` if `tol` is not positive, a bound is not finite,
+/// or `f(a)` and `f(b)` have the same sign.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{cos, exp, fix64, PI};
+/// use mathlab::optimize::fzero;
+/// let s = fzero(cos, 1.0, 2.0, 1e-12, 100).unwrap();
+/// assert_eq!(fix64(s.x), fix64(PI / 2.0));
+/// let s = fzero(|x| exp(x) - 10.0, 0.0, 10.0, 1e-12, 100).unwrap();
+/// assert_eq!(fix64(s.x), fix64(10.0_f64.ln()));
+/// assert!(s.converged && s.iterations < 15);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn fzero<F: Fn(f64) -> f64>(
+    f: F,
+    a: f64,
+    b: f64,
+    tol: f64,
+    max_iter: usize,
+) -> Result<Solution, MathError> {
+    check_tol(tol)?;
+    let (mut a, mut b) = check_bounds(a, b)?;
+    let (mut fa, mut fb) = (f(a), f(b));
+    if fa == 0.0 {
+        return Ok(Solution {
+            x: a,
+            fx: fa,
+            iterations: 0,
+            converged: true,
+        });
+    }
+    if fa.is_nan() || fb.is_nan() || (fb != 0.0 && (fa > 0.0) == (fb > 0.0)) {
+        return Err(MathError::Domain("f(a) and f(b) must have opposite signs"));
+    }
+    let (mut c, mut fc) = (a, fa);
+    let mut d = b - a;
+    let mut e = d;
+    let mut iterations = 0;
+    loop {
+        // Keep the root bracketed by b and c, with b the best estimate
+        if (fb > 0.0) == (fc > 0.0) {
+            c = a;
+            fc = fa;
+            d = b - a;
+            e = d;
+        }
+        if fc.abs() < fb.abs() {
+            a = b;
+            b = c;
+            c = a;
+            fa = fb;
+            fb = fc;
+            fc = fa;
+        }
+        let tol1 = 2.0 * f64::EPSILON * b.abs() + 0.5 * x_tol(tol, b);
+        let xm = 0.5 * (c - b);
+        if xm.abs() <= tol1 || fb == 0.0 || iterations >= max_iter || fb.is_nan() {
+            return Ok(Solution {
+                x: b,
+                fx: fb,
+                iterations,
+                converged: xm.abs() <= tol1 || fb == 0.0,
+            });
+        }
+        iterations += 1;
+        if e.abs() >= tol1 && fa.abs() > fb.abs() {
+            // Secant (a == c) or inverse quadratic interpolation
+            let s = fb / fa;
+            let (mut p, mut q) = if a == c {
+                (2.0 * xm * s, 1.0 - s)
+            } else {
+                let q = fa / fc;
+                let r = fb / fc;
+                (
+                    s * (2.0 * xm * q * (q - r) - (b - a) * (r - 1.0)),
+                    (q - 1.0) * (r - 1.0) * (s - 1.0),
+                )
+            };
+            if p > 0.0 {
+                q = -q;
+            } else {
+                p = -p;
+            }
+            if 2.0 * p < (3.0 * xm * q - (tol1 * q).abs()).min((e * q).abs()) {
+                e = d;
+                d = p / q;
+            } else {
+                d = xm;
+                e = d;
+            }
+        } else {
+            d = xm;
+            e = d;
+        }
+        a = b;
+        fa = fb;
+        b += if d.abs() > tol1 { d } else { tol1.copysign(xm) };
+        fb = f(b);
+    }
+}
+
+/// ### newton(f, df, x0, tol, max_iter)
+///
+/// Root Finding Function
+///
+/// The `newton` function finds a root of `f` with Newton's method from the starting point `x0`,
+/// stopping when a step is smaller than `tol * max(1, |x|)`. The derivative `df` is optional:
+/// with `None` it is approximated by central differences.
+///
+/// Newton's method converges quadratically near a simple root but may diverge from a poor start;
+/// the result then reports `converged: false`, as it does for a zero derivative.
+///
+/// ### Errors
+///
+/// Returns `MathError::Domain` if `tol` is not positive or `x0` is not finite.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{cos, fix64, sin};
+/// use mathlab::optimize::newton;
+/// let s = newton(|x| x * x - 2.0, Some(&|x| 2.0 * x), 1.0, 1e-12, 50).unwrap();
+/// assert_eq!(fix64(s.x), 1.4142135);
+/// assert!(s.converged && s.iterations <= 6);
+/// let s = newton(|x| cos(x) - x, None, 1.0, 1e-12, 50).unwrap();
+/// assert_eq!(fix64(s.x), 0.73908514);
+/// let s = newton(|x| x * x + 1.0, Some(&|x| 2.0 * x), 0.0, 1e-12, 50).unwrap();
+/// assert!(!s.converged);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn newton<F: Fn(f64) -> f64>(
+    f: F,
+    df: Option<&dyn Fn(f64) -> f64>,
+    x0: f64,
+    tol: f64,
+    max_iter: usize,
+) -> Result<Solution, MathError> {
+    check_tol(tol)?;
+    if !x0.is_finite() {
+        return Err(MathError::Domain("starting point must be finite"));
+    }
+    let derivative = |x: f64| match df {
+        Some(df) => df(x),
+        None => {
+            let h = f64::EPSILON.cbrt() * x.abs().max(1.0);
+            (f(x + h) - f(x - h)) / (2.0 * h)
+        }
+    };
+    let mut s = Solution {
+        x: x0,
+        fx: f(x0),
+        iterations: 0,
+        converged: false,
+    };
+    while s.iterations < max_iter {
+        if s.fx == 0.0 {
+            s.converged = true;
+            break;
+        }
+        let step = s.fx / derivative(s.x);
+        if !step.is_finite() {
+            break;
+        }
+        s.iterations += 1;
+        s.x -= step;
+        s.fx = f(s.x);
+        if step.abs() <= x_tol(tol, s.x) {
+            s.converged = true;
+            break;
+        }
+    }
+    Ok(s)
+}
+
+/// ### secant(f, x0, x1, tol, max_iter)
+///
+/// Root Finding Function
+///
+/// The `secant` function finds a root of `f` with the secant method from the starting points `x0` and `x1`,
+/// stopping when a step is smaller than `tol * max(1, |x|)`. It needs no derivative and converges superlinearly
+/// near a simple root, but like `newton` it is not guaranteed to converge.
+///
+/// ### Errors
+///
+/// Returns `MathError::Domain` if `tol` is not positive or a starting point is not finite.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{exp, fix64};
+/// use mathlab::optimize::secant;
+/// let s = secant(|x| x * x * x - 2.0 * x - 5.0, 2.0, 3.0, 1e-12, 50).unwrap();
+/// assert_eq!(fix64(s.x), 2.0945516);
+/// assert!(s.converged && s.fx.abs() < 1e-12);
+/// let s = secant(|x| exp(x) - 2.0, 0.0, 1.0, 1e-12, 50).unwrap();
+/// assert_eq!(fix64(s.x), fix64(2.0_f64.ln()));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn secant<F: Fn(f64) -> f64>(
+    f: F,
+    x0: f64,
+    x1: f64,
+    tol: f64,
+    max_iter: usize,
+) -> Result<Solution, MathError> {
+    check_tol(tol)?;
+    if !x0.is_finite() || !x1.is_finite() {
+        return Err(MathError::Domain("starting points must be finite"));
+    }
+    let (mut x0, mut f0) = (x0, f(x0));
+    let mut s = Solution {
+        x: x1,
+        fx: f(x1),
+        iterations: 0,
+        converged: false,
+    };
+    while s.iterations < max_iter {
+        if s.fx == 0.0 {
+            s.converged = true;
+            break;
+        }
+        let step = s.fx * (s.x - x0) / (s.fx - f0);
+        if !step.is_finite() {
+            break;
+        }
+        s.iterations += 1;
+        x0 = s.x;
+        f0 = s.fx;
+        s.x -= step;
+        s.fx = f(s.x);
+        if step.abs() <= x_tol(tol, s.x) {
+            s.converged = true;
+            break;
+        }
+    }
+    Ok(s)
+}
+
+/// ### golden(f, a, b, tol, max_iter)
+///
+/// Minimization Function
+///
+/// The `golden` function finds a minimum of `f` in the interval `[a, b]` by golden-section search,
+/// shrinking the bracket by the golden ratio at every step until it is narrower than about `2 * tol * max(1, |x|)`.
+/// For a unimodal `f` it always converges, to the global minimum in `[a, b]`; otherwise it finds a local one.
+///
+/// Near a smooth minimum `f` is flat to second order, so `x` cannot be located more accurately than
+/// about the square root of the machine epsilon, `1.5e-8` in relative terms.
+///
+/// ### Errors
+///
+/// Returns `MathError::Domain` if `tol` is not positive or a bound is not finite.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::functions::precise;
+/// use mathlab::math::{fix, PI};
+/// use mathlab::optimize::golden;
+/// let s = golden(|x| (x - 2.0) * (x - 2.0) + 1.0, 0.0, 5.0, 1e-8, 100).unwrap();
+/// assert_eq!((fix(s.x, 6), s.fx), (2.0, 1.0));
+/// assert!(s.converged);
+/// let s = golden(precise::sin, 0.0, 2.0 * PI, 1e-8, 100).unwrap();
+/// assert_eq!((fix(s.x, 6), fix(s.fx, 6)), (fix(1.5 * PI, 6), -1.0));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn golden<F: Fn(f64) -> f64>(
+    f: F,
+    a: f64,
+    b: f64,
+    tol: f64,
+    max_iter: usize,
+) -> Result<Solution, MathError> {
+    check_tol(tol)?;
+    let (mut a, mut b) = check_bounds(a, b)?;
+    let ratio = 0.5 * (5.0_f64.sqrt() - 1.0);
+    let mut c = b - ratio * (b - a);
+    let mut d = a + ratio * (b - a);
+    let (mut fc, mut fd) = (f(c), f(d));
+    let mut iterations = 0;
+    loop {
+        let (x, fx) = if fc < fd { (c, fc) } else { (d, fd) };
+        let converged = 0.5 * (b - a) <= x_tol(tol, x);
+        if converged || iterations >= max_iter {
+            return Ok(Solution {
+                x,
+                fx,
+                iterations,
+                converged,
+            });
+        }
+        iterations += 1;
+        if fc < fd {
+            b = d;
+            d = c;
+            fd = fc;
+            c = b - ratio * (b - a);
+            fc = f(c);
+        } else {
+            a = c;
+            c = d;
+            fc = fd;
+            d = a + ratio * (b - a);
+            fd = f(d);
+        }
+    }
+}
+
+/// ### fminbnd(f, a, b, tol, max_iter)
+///
+/// Minimization Function
+///
+/// The `fminbnd` function finds a minimum of `f` in the interval `[a, b]` with Brent's method (MATLAB's `fminbnd`),
+/// which uses parabolic interpolation where `f` is smooth and golden-section steps as a safeguard.
+/// It needs far fewer evaluations of `f` than `golden` for smooth functions, and stops when `x` is known
+/// to within about `tol * max(1, |x|)`. As with `golden`, the minimum is local unless `f` is unimodal on `[a, b]`.
+///
+/// ### Errors
+///
+/// Returns `MathError::Domain` if `tol` is not positive or a bound is not finite.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::functions::precise;
+/// use mathlab::math::{exp, fix, fix64, PI};
+/// use mathlab::optimize::fminbnd;
+/// let s = fminbnd(precise::cos, 3.0, 4.0, 1e-8, 100).unwrap();
+/// assert_eq!((fix(s.x, 6), s.fx), (fix(PI, 6), -1.0));
+/// assert!(s.converged && s.iterations < 15);
+/// let s = fminbnd(|x| exp(x) - 2.0 * x, 0.0, 2.0, 1e-8, 100).unwrap();
+/// assert_eq!(fix(s.x, 6), fix(2.0_f64.ln(), 6));
+/// assert_eq!(fix64(s.fx), fix64(2.0 - 2.0 * 2.0_f64.ln()));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn fminbnd<F: Fn(f64) -> f64>(
+    f: F,
+    a: f64,
+    b: f64,
+    tol: f64,
+    max_iter: usize,
+) -> Result<Solution, MathError> {
+    check_tol(tol)?;
+    let (mut a, mut b) = check_bounds(a, b)?;
+    let golden_step = 0.5 * (3.0 - 5.0_f64.sqrt());
+    let sqrt_eps = f64::EPSILON.sqrt();
+    let mut x = a + golden_step * (b - a);
+    let (mut v, mut w) = (x, x);
+    let mut fx = f(x);
+    let (mut fv, mut fw) = (fx, fx);
+    let (mut d, mut e) = (0.0_f64, 0.0_f64);
+    let mut iterations = 0;
+    loop {
+        let xm = 0.5 * (a + b);
+        let tol1 = sqrt_eps * x.abs() + x_tol(tol, x) / 3.0;
+        let tol2 = 2.0 * tol1;
+        let converged = (x - xm).abs() <= tol2 - 0.5 * (b - a);
+        if converged || iterations >= max_iter {
+            return Ok(Solution {
+                x,
+                fx,
+                iterations,
+                converged,
+            });
+        }
+        iterations += 1;
+        let mut parabolic = false;
+        if e.abs() > tol1 {
+            // Fit a parabola through x, v and w
+            let r = (x - w) * (fx - fv);
+            let q = (x - v) * (fx - fw);
+            let mut p = (x - v) * q - (x - w) * r;
+            let mut q = 2.0 * (q - r);
+            if q > 0.0 {
+                p = -p;
+            }
+            q = q.abs();
+            let r = e;
+            if p.abs() < (0.5 * q * r).abs() && p > q * (a - x) && p < q * (b - x) {
+                e = d;
+                d = p / q;
+                let u = x + d;
+                if u - a < tol2 || b - u < tol2 {
+                    d = tol1.copysign(xm - x);
+                }
+                parabolic = true;
+            }
+        }
+        if !parabolic {
+            e = if x >= xm { a - x } else { b - x };
+            d = golden_step * e;
+        }
+        let u = x + if d.abs() >= tol1 { d } else { tol1.copysign(d) };
+        let fu = f(u);
+        if fu <= fx {
+            if u >= x {
+                a = x;
+            } else {
+                b = x;
+            }
+            v = w;
+            fv = fw;
+            w = x;
+            fw = fx;
+            x = u;
+            fx = fu;
+        } else {
+            if u < x {
+                a = u;
+            } else {
+                b = u;
+            }
+            if fu <= fw || w == x {
+                v = w;
+                fv = fw;
+                w = u;
+                fw = fu;
+            } else if fu <= fv || v == x || v == w {
+                v = u;
+                fv = fu;
+            }
+        }
+    }
+}
+
+/// ### CurveFit
+///
+/// Solver Result
+///
+/// The `CurveFit` type is returned by `lsqcurvefit`. It holds the fitted parameters `params`, the residuals
+/// `model(params, xdata[i]) - ydata[i]`, their sum of squares `resnorm`, the estimated covariance matrix of the parameters,
+/// the number of iterations used, and whether the tolerance was met before the iteration limit.
+///
+/// The covariance is `s^2 * inv(J^T J)`, where `J` is the Jacobian of the residuals with respect to the parameters
+/// at the solution and `s^2 = resnorm / (m - n)` estimates the variance of the noise from `m` data points and `n` parameters.
+/// The square roots of its diagonal are the standard errors of the parameters. It is filled with NaN if there are no more
+/// data points than parameters or `J^T J` is singular, i.e. the data do not determine all the parameters.
+/// Parameters held at a bound are treated like the others, so their entries are only indicative.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::Rng;
+/// use mathlab::optimize::lsqcurvefit;
+/// // A straight line through noisy data: the errors of the fit match the noise level
+/// let mut rng = Rng::seed(1);
+/// let x: Vec<f64> = (0..200).map(|k| k as f64 / 20.0).collect();
+/// let y: Vec<f64> = x.iter().map(|x| 1.0 + 0.5 * x + rng.normal(0.0, 0.1)).collect();
+/// let fit = lsqcurvefit(|p, x| p[0] + p[1] * x, &[0.0, 0.0], &x, &y, &[], &[], 1e-10, 100).unwrap();
+/// assert!(fit.converged && fit.residuals.len() == 200);
+/// assert!((fit.resnorm / 198.0).sqrt() > 0.09 && (fit.resnorm / 198.0).sqrt() < 0.11);
+/// let se = [fit.covariance[(0, 0)].sqrt(), fit.covariance[(1, 1)].sqrt()];
+/// assert!(se[0] > 0.01 && se[0] < 0.02 && se[1] > 0.002 && se[1] < 0.004);
+/// assert!((fit.params[0] - 1.0).abs() < 3.0 * se[0] && (fit.params[1] - 0.5).abs() < 3.0 * se[1]);
+/// ```
+/// <small>End Type Doc</small>
+#[derive(Debug, Clone, PartialEq)]
+pub struct CurveFit {
+    pub params: Vec<f64>,
+    pub residuals: Vec<f64>,
+    pub resnorm: f64,
+    pub covariance: Matrix,
+    pub iterations: usize,
+    pub converged: bool,
+}
+
+/// Expands optional bounds: an empty slice gives `n` copies of `fill`.
+fn fit_bounds(bound: &[f64], n: usize, fill: f64) -> Result<Vec<f64>, MathError> {
+    match bound.len() {
+        0 => Ok(vec![fill; n]),
+        len if len == n => Ok(bound.to_vec()),
+        len => Err(MathError::DimensionMismatch {
+            expected: n,
+            found: len,
+        }),
+    }
+}
+
+fn fit_residuals<F: Fn(&[f64], f64) -> f64>(
+    model: &F,
+    p: &[f64],
+    xdata: &[f64],
+    ydata: &[f64],
+) -> Vec<f64> {
+    xdata
+        .iter()
+        .zip(ydata)
+        .map(|(&x, &y)| model(p, x) - y)
+        .collect()
+}
+
+/// The Jacobian of the residuals by forward differences, stepping away from an upper bound.
+fn fit_jacobian<F: Fn(&[f64], f64) -> f64>(
+    model: &F,
+    p: &[f64],
+    r: &[f64],
+    xdata: &[f64],
+    ydata: &[f64],
+    upper: &[f64],
+) -> Matrix {
+    let mut jac = Matrix::zeros(r.len(), p.len());
+    let mut q = p.to_vec();
+    for j in 0..p.len() {
+        let mut h = f64::EPSILON.sqrt() * p[j].abs().max(1.0);
+        if p[j] + h > upper[j] {
+            h = -h;
+        }
+        q[j] = p[j] + h;
+        for (i, rq) in fit_residuals(model, &q, xdata, ydata).iter().enumerate() {
+            jac[(i, j)] = (rq - r[i]) / h;
+        }
+        q[j] = p[j];
+    }
+    jac
+}
+
+/// ### lsqcurvefit(model, p0, xdata, ydata, lb, ub, tol, max_iter)
+///
+/// Curve Fitting Function
+///
+/// The `lsqcurvefit` function fits the parameters `p` of `model(p, x)` to the data points `(xdata[i], ydata[i])` by
+/// nonlinear least squares (MATLAB's `lsqcurvefit`), minimizing the sum of the squared residuals
+/// `model(p, xdata[i]) - ydata[i]` from the initial guess `p0`. It uses the Levenberg–Marquardt method with
+/// Marquardt's scaling and a forward-difference Jacobian, and keeps the parameters within the bounds `lb <= p <= ub`:
+/// `p0` and each step are projected onto them, and parameters at a bound that the fit pushes outwards are held there. Pass empty slices for no bounds, and infinities to leave single
+/// parameters unbounded.
+///
+/// The iteration stops with `converged` set when a step changes the parameters by at most about `tol` relative to
+/// their size, or the sum of squares by at most `tol` relative to its value. As for any local method, a poor `p0` can
+/// lead to a local minimum; for periodic models in particular, start near the right frequency.
+///
+/// ### Errors
+///
+/// Returns `MathError::EmptyInput` if `p0` or `xdata` is empty, `MathError::DimensionMismatch` if `ydata` does not
+/// have the length of `xdata` or non-empty bounds do not have the length of `p0`, and `MathError::Domain` if `tol`
+/// is not positive, `p0` is not finite, a lower bound exceeds the upper one, or the model is not finite at the start.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::fix;
+/// use mathlab::optimize::lsqcurvefit;
+/// // Exponential decay y = a * exp(b * x)
+/// let x: Vec<f64> = (0..20).map(|k| k as f64 * 0.25).collect();
+/// let y: Vec<f64> = x.iter().map(|x| 3.0 * (-0.7 * x).exp()).collect();
+/// let model = |p: &[f64], x: f64| p[0] * (p[1] * x).exp();
+/// let fit = lsqcurvefit(model, &[1.0, 0.0], &x, &y, &[], &[], 1e-12, 200).unwrap();
+/// assert!(fit.converged && fit.resnorm < 1e-20);
+/// assert_eq!((fix(fit.params[0], 8), fix(fit.params[1], 8)), (3.0, -0.7));
+/// // The same data with the amplitude bounded above by 2
+/// let fit = lsqcurvefit(model, &[1.0, 0.0], &x, &y, &[0.0, -10.0], &[2.0, 10.0], 1e-12, 200).unwrap();
+/// assert_eq!(fit.params[0], 2.0);
+/// // A sinusoid y = A * sin(w * x + phi), started near the right frequency
+/// let y: Vec<f64> = x.iter().map(|x| 1.5 * (2.0 * x + 0.3).sin()).collect();
+/// let fit = lsqcurvefit(|p, x| p[0] * (p[1] * x + p[2]).sin(), &[1.0, 1.8, 0.0], &x, &y, &[], &[], 1e-12, 200).unwrap();
+/// assert_eq!(fit.params.iter().map(|p| fix(*p, 8)).collect::<Vec<_>>(), [1.5, 2.0, 0.3]);
+/// ```
+/// <small>End Fun Doc</small>
+#[allow(clippy::too_many_arguments)]
+pub fn lsqcurvefit<F: Fn(&[f64], f64) -> f64>(
+    model: F,
+    p0: &[f64],
+    xdata: &[f64],
+    ydata: &[f64],
+    lb: &[f64],
+    ub: &[f64],
+    tol: f64,
+    max_iter: usize,
+) -> Result<CurveFit, MathError> {
+    check_tol(tol)?;
+    let (n, m) = (p0.len(), xdata.len());
+    if n == 0 || m == 0 {
+        return Err(MathError::EmptyInput);
+    }
+    if ydata.len() != m {
+        return Err(MathError::DimensionMismatch {
+            expected: m,
+            found: ydata.len(),
+        });
+    }
+    let lower = fit_bounds(lb, n, f64::NEG_INFINITY)?;
+    let upper = fit_bounds(ub, n, f64::INFINITY)?;
+    if lower
+        .iter()
+        .zip(&upper)
+        .any(|(l, u)| l.is_nan() || u.is_nan() || l > u)
+    {
+        return Err(MathError::Domain(
+            "lower bounds must not exceed upper bounds",
+        ));
+    }
+    if p0.iter().any(|v| !v.is_finite()) {
+        return Err(MathError::Domain("starting point must be finite"));
+    }
+    let project = |p: &mut [f64]| {
+        for ((v, l), u) in p.iter_mut().zip(&lower).zip(&upper) {
+            *v = v.clamp(*l, *u);
+        }
+    };
+    let norm = |v: &[f64]| v.iter().map(|a| a * a).sum::<f64>().sqrt();
+    let mut p = p0.to_vec();
+    project(&mut p);
+    let mut r = fit_residuals(&model, &p, xdata, ydata);
+    let mut cost = r.iter().map(|v| v * v).sum::<f64>();
+    if !cost.is_finite() {
+        return Err(MathError::Domain("model value is not finite"));
+    }
+    let mut jac = fit_jacobian(&model, &p, &r, xdata, ydata, &upper);
+    let mut lambda = 1e-3;
+    let mut iterations = 0;
+    let mut converged = false;
+    while iterations < max_iter {
+        iterations += 1;
+        let jtj = &jac.transpose() * &jac;
+        let g: Vec<f64> = (0..n)
+            .map(|j| (0..m).map(|i| jac[(i, j)] * r[i]).sum())
+            .collect();
+        // Parameters at a bound that the gradient pushes outwards are held there
+        let held: Vec<bool> = (0..n)
+            .map(|j| (p[j] >= upper[j] && g[j] < 0.0) || (p[j] <= lower[j] && g[j] > 0.0))
+            .collect();
+        if (0..n).all(|j| held[j] || g[j] == 0.0) {
+            converged = true;
+            break;
+        }
+        // Marquardt's damping scales with the curvature along each parameter
+        let floor = 1e-12 * (0..n).map(|j| jtj[(j, j)]).fold(0.0, f64::max);
+        let damped = Matrix::from_fn(n, n, |i, j| {
+            if held[i] || held[j] {
+                if i == j {
+                    1.0
+                } else {
+                    0.0
+                }
+            } else if i == j {
+                jtj[(i, i)] + lambda * jtj[(i, i)].max(floor)
+            } else {
+                jtj[(i, j)]
+            }
+        });
+        let neg_g: Vec<f64> = (0..n).map(|j| if held[j] { 0.0 } else { -g[j] }).collect();
+        let Ok(step) = linalg::solve(&damped, &neg_g) else {
+            lambda *= 10.0;
+            continue;
+        };
+        let mut trial: Vec<f64> = p.iter().zip(&step).map(|(a, d)| a + d).collect();
+        project(&mut trial);
+        let moved: Vec<f64> = trial.iter().zip(&p).map(|(a, b)| a - b).collect();
+        let small_step = norm(&moved) <= tol * (norm(&p) + tol);
+        let r_trial = fit_residuals(&model, &trial, xdata, ydata);
+        let cost_trial = r_trial.iter().map(|v| v * v).sum::<f64>();
+        if cost_trial < cost {
+            let small_decrease = cost - cost_trial <= tol * cost;
+            p = trial;
+            r = r_trial;
+            cost = cost_trial;
+            jac = fit_jacobian(&model, &p, &r, xdata, ydata, &upper);
+            lambda = (lambda / 10.0).max(1e-12);
+            if small_step || small_decrease {
+                converged = true;
+                break;
+            }
+        } else if small_step {
+            // No step the solver can resolve improves the fit
+            converged = true;
+            break;
+        } else {
+            lambda *= 10.0;
+        }
+    }
+    let covariance = if m > n {
+        let s2 = cost / (m - n) as f64;
+        match linalg::inv(&(&jac.transpose() * &jac)) {
+            Ok(c) => c.map(|v| v * s2),
+            Err(_) => Matrix::from_fn(n, n, |_, _| f64::NAN),
+        }
+    } else {
+        Matrix::from_fn(n, n, |_, _| f64::NAN)
+    };
+    Ok(CurveFit {
+        params: p,
+        residuals: r,
+        resnorm: cost,
+        covariance,
+        iterations,
+        converged,
+    })
+}
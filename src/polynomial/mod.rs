@@ -0,0 +1,700 @@
+use crate::complex::Complex;
+use crate::error::MathError;
+use crate::linalg::{eigvals, lstsq};
+use crate::matrix::Matrix;
+use std::fmt;
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// ### Polynomial
+///
+/// Polynomial Type
+///
+/// The `Polynomial` type stores a real polynomial as its coefficients in descending powers, as MATLAB does,
+/// so `[1.0, -3.0, 2.0]` is `x^2 - 3x + 2`. Leading zero coefficients are dropped, and the zero polynomial is `[0.0]`.
+///
+/// `+`, `-` and `*` work on polynomials (and `*` also on a polynomial and an `f64`), and `div_rem` performs division with remainder.
+/// The slice functions in the `polynomial` module (`polyval`, `polyfit`, `roots`, `conv`, `deconv`, `polyder`, `polyint`)
+/// provide the same operations on plain coefficient vectors.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::Polynomial;
+/// let p = Polynomial::new(&[1.0, -3.0, 2.0]);
+/// let q = Polynomial::new(&[1.0, 1.0]);
+/// assert_eq!(p.eval(3.0), 2.0);
+/// assert_eq!(p.degree(), 2);
+/// assert_eq!(&p + &q, Polynomial::new(&[1.0, -2.0, 3.0]));
+/// assert_eq!(&p - &q, Polynomial::new(&[1.0, -4.0, 1.0]));
+/// assert_eq!(&p * &q, Polynomial::new(&[1.0, -2.0, -1.0, 2.0]));
+/// assert_eq!(&p * 2.0, Polynomial::new(&[2.0, -6.0, 4.0]));
+/// assert_eq!(p.derivative(), Polynomial::new(&[2.0, -3.0]));
+/// assert_eq!(p.to_string(), "x^2 - 3x + 2");
+/// assert_eq!(Polynomial::new(&[0.0, 0.0, 5.0]).coefficients(), [5.0]);
+/// ```
+/// <small>End Type Doc</small>
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polynomial {
+    coef: Vec<f64>,
+}
+
+impl Polynomial {
+    /// ### new(coef)
+    ///
+    /// Polynomial Constructor
+    ///
+    /// The `new` function builds a polynomial from its coefficients in descending powers.
+    /// Leading zeros are dropped, and an empty slice gives the zero polynomial.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::math::Polynomial;
+    /// assert_eq!(Polynomial::new(&[0.0, 2.0, 1.0]).coefficients(), [2.0, 1.0]);
+    /// assert_eq!(Polynomial::new(&[]).coefficients(), [0.0]);
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn new(coef: &[f64]) -> Polynomial {
+        Polynomial {
+            coef: trim(coef).to_vec(),
+        }
+    }
+
+    /// ### from_roots(r)
+    ///
+    /// Polynomial Constructor
+    ///
+    /// The `from_roots` function returns the monic polynomial whose roots are the given real numbers (MATLAB's `poly`).
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::math::Polynomial;
+    /// assert_eq!(Polynomial::from_roots(&[1.0, 2.0]), Polynomial::new(&[1.0, -3.0, 2.0]));
+    /// assert_eq!(Polynomial::from_roots(&[]), Polynomial::new(&[1.0]));
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn from_roots(r: &[f64]) -> Polynomial {
+        r.iter().fold(Polynomial::new(&[1.0]), |p, &x| {
+            &p * &Polynomial::new(&[1.0, -x])
+        })
+    }
+
+    /// ### fit(x, y, n)
+    ///
+    /// Polynomial Constructor
+    ///
+    /// The `fit` function returns the polynomial of degree `n` that fits the points `(x, y)` best in the least-squares sense.
+    /// It is the `Polynomial` form of `polyfit`.
+    ///
+    /// ### Errors
+    ///
+    /// Returns the same errors as `polyfit`.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::math::{Polynomial, range_from_to, fix64};
+    /// let x = range_from_to(0.0, 5.0, 1.0);
+    /// let y: Vec<f64> = x.iter().map(|x| 2.0 * x - 1.0).collect();
+    /// let p = Polynomial::fit(&x, &y, 1).unwrap();
+    /// assert_eq!(fix64(p.eval(10.0)), 19.0);
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn fit(x: &[f64], y: &[f64], n: usize) -> Result<Polynomial, MathError> {
+        Ok(Polynomial::new(&polyfit(x, y, n)?))
+    }
+
+    /// ### coefficients()
+    ///
+    /// Polynomial Property
+    ///
+    /// The `coefficients` function returns the coefficients in descending powers.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::math::Polynomial;
+    /// assert_eq!(Polynomial::new(&[3.0, 0.0, 1.0]).coefficients(), [3.0, 0.0, 1.0]);
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn coefficients(&self) -> &[f64] {
+        &self.coef
+    }
+
+    /// ### degree()
+    ///
+    /// Polynomial Property
+    ///
+    /// The `degree` function returns the degree of the polynomial; constants, including the zero polynomial, have degree `0`.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::math::Polynomial;
+    /// assert_eq!(Polynomial::new(&[3.0, 0.0, 1.0]).degree(), 2);
+    /// assert_eq!(Polynomial::new(&[7.0]).degree(), 0);
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn degree(&self) -> usize {
+        self.coef.len() - 1
+    }
+
+    /// ### is_zero()
+    ///
+    /// Polynomial Property
+    ///
+    /// The `is_zero` function returns `true` for the zero polynomial.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::math::Polynomial;
+    /// assert!(Polynomial::new(&[0.0, 0.0]).is_zero());
+    /// assert!(!Polynomial::new(&[1.0]).is_zero());
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn is_zero(&self) -> bool {
+        self.coef == [0.0]
+    }
+
+    /// ### eval(x)
+    ///
+    /// Polynomial Function
+    ///
+    /// The `eval` function evaluates the polynomial at `x` with Horner's method.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::math::Polynomial;
+    /// let p = Polynomial::new(&[2.0, 0.0, -1.0]);
+    /// assert_eq!(p.eval(3.0), 17.0);
+    /// assert_eq!(p.eval_vec(&[0.0, 1.0, 2.0]), [-1.0, 1.0, 7.0]);
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn eval(&self, x: f64) -> f64 {
+        polyval(&self.coef, x)
+    }
+
+    /// ### eval_vec(x)
+    ///
+    /// Polynomial Function
+    ///
+    /// The `eval_vec` function evaluates the polynomial at every element of `x`.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::math::Polynomial;
+    /// assert_eq!(Polynomial::new(&[1.0, 1.0]).eval_vec(&[1.0, 2.0]), [2.0, 3.0]);
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn eval_vec(&self, x: &[f64]) -> Vec<f64> {
+        polyval_vec(&self.coef, x)
+    }
+
+    /// ### eval_complex(z)
+    ///
+    /// Polynomial Function
+    ///
+    /// The `eval_complex` function evaluates the polynomial at a complex number with Horner's method.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::math::{Complex, Polynomial};
+    /// let p = Polynomial::new(&[1.0, 0.0, 1.0]);
+    /// assert_eq!(p.eval_complex(Complex::I), Complex::ZERO);
+    /// assert_eq!(p.eval_complex(Complex::new(1.0, 1.0)), Complex::new(1.0, 2.0));
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn eval_complex(&self, z: Complex) -> Complex {
+        self.coef.iter().fold(Complex::ZERO, |acc, &c| acc * z + c)
+    }
+
+    /// ### derivative()
+    ///
+    /// Polynomial Function
+    ///
+    /// The `derivative` function returns the derivative of the polynomial.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::math::Polynomial;
+    /// assert_eq!(Polynomial::new(&[1.0, 2.0, 3.0]).derivative(), Polynomial::new(&[2.0, 2.0]));
+    /// assert!(Polynomial::new(&[5.0]).derivative().is_zero());
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn derivative(&self) -> Polynomial {
+        Polynomial::new(&polyder(&self.coef))
+    }
+
+    /// ### integral(k)
+    ///
+    /// Polynomial Function
+    ///
+    /// The `integral` function returns the antiderivative of the polynomial whose constant term is `k`.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::math::Polynomial;
+    /// assert_eq!(Polynomial::new(&[3.0, 2.0]).integral(1.0), Polynomial::new(&[1.5, 2.0, 1.0]));
+    /// assert_eq!(Polynomial::new(&[3.0, 2.0]).integral(1.0).derivative(), Polynomial::new(&[3.0, 2.0]));
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn integral(&self, k: f64) -> Polynomial {
+        Polynomial::new(&polyint(&self.coef, k))
+    }
+
+    /// ### div_rem(d)
+    ///
+    /// Polynomial Function
+    ///
+    /// The `div_rem` function divides the polynomial by `d` and returns the quotient and the remainder,
+    /// such that `self = quotient * d + remainder` with the remainder of lower degree than `d`.
+    ///
+    /// ### Errors
+    ///
+    /// Returns `MathError::Domain` if `d` is the zero polynomial.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::math::Polynomial;
+    /// let p = Polynomial::new(&[1.0, 0.0, -1.0, 5.0]);
+    /// let d = Polynomial::new(&[1.0, -1.0]);
+    /// let (q, r) = p.div_rem(&d).unwrap();
+    /// assert_eq!(q, Polynomial::new(&[1.0, 1.0, 0.0]));
+    /// assert_eq!(r, Polynomial::new(&[5.0]));
+    /// assert_eq!(&(&q * &d) + &r, p);
+    /// assert!(p.div_rem(&Polynomial::new(&[0.0])).is_err());
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn div_rem(&self, d: &Polynomial) -> Result<(Polynomial, Polynomial), MathError> {
+        let (q, r) = deconv(&self.coef, &d.coef)?;
+        Ok((Polynomial::new(&q), Polynomial::new(&r)))
+    }
+
+    /// ### roots()
+    ///
+    /// Polynomial Function
+    ///
+    /// The `roots` function returns the roots of the polynomial, as `roots` does for a coefficient vector.
+    ///
+    /// ### Errors
+    ///
+    /// Returns the same errors as `roots`.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::math::{Complex, Polynomial};
+    /// assert_eq!(Polynomial::new(&[1.0, -3.0, 2.0]).roots().unwrap(), [Complex::from(1.0), Complex::from(2.0)]);
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn roots(&self) -> Result<Vec<Complex>, MathError> {
+        roots(&self.coef)
+    }
+}
+
+/// Drops the leading zeros of a coefficient vector, keeping at least one coefficient.
+fn trim(p: &[f64]) -> &[f64] {
+    match p.iter().position(|&c| c != 0.0) {
+        Some(i) => &p[i..],
+        None => &[0.0],
+    }
+}
+
+/// Adds two coefficient vectors, aligning them at the constant term.
+fn add_coef(a: &[f64], b: &[f64], sign: f64) -> Vec<f64> {
+    let n = a.len().max(b.len());
+    let mut c = vec![0.0; n];
+    for (ci, &x) in c[n - a.len()..].iter_mut().zip(a) {
+        *ci += x;
+    }
+    for (ci, &x) in c[n - b.len()..].iter_mut().zip(b) {
+        *ci += sign * x;
+    }
+    c
+}
+
+impl fmt::Display for Polynomial {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+        let n = self.degree();
+        let mut first = true;
+        for (i, &c) in self.coef.iter().enumerate() {
+            if c == 0.0 {
+                continue;
+            }
+            let power = n - i;
+            let sign = if c < 0.0 { "-" } else { "+" };
+            if first {
+                if c < 0.0 {
+                    write!(f, "-")?;
+                }
+            } else {
+                write!(f, " {} ", sign)?;
+            }
+            first = false;
+            let a = c.abs();
+            if a != 1.0 || power == 0 {
+                write!(f, "{}", a)?;
+            }
+            match power {
+                0 => {}
+                1 => write!(f, "x")?,
+                _ => write!(f, "x^{}", power)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Add<&Polynomial> for &Polynomial {
+    type Output = Polynomial;
+
+    fn add(self, rhs: &Polynomial) -> Polynomial {
+        Polynomial::new(&add_coef(&self.coef, &rhs.coef, 1.0))
+    }
+}
+
+impl Sub<&Polynomial> for &Polynomial {
+    type Output = Polynomial;
+
+    fn sub(self, rhs: &Polynomial) -> Polynomial {
+        Polynomial::new(&add_coef(&self.coef, &rhs.coef, -1.0))
+    }
+}
+
+impl Mul<&Polynomial> for &Polynomial {
+    type Output = Polynomial;
+
+    fn mul(self, rhs: &Polynomial) -> Polynomial {
+        Polynomial::new(&conv(&self.coef, &rhs.coef))
+    }
+}
+
+impl Mul<f64> for &Polynomial {
+    type Output = Polynomial;
+
+    fn mul(self, rhs: f64) -> Polynomial {
+        Polynomial::new(&self.coef.iter().map(|c| c * rhs).collect::<Vec<f64>>())
+    }
+}
+
+impl Mul<&Polynomial> for f64 {
+    type Output = Polynomial;
+
+    fn mul(self, rhs: &Polynomial) -> Polynomial {
+        rhs * self
+    }
+}
+
+impl Neg for &Polynomial {
+    type Output = Polynomial;
+
+    fn neg(self) -> Polynomial {
+        self * -1.0
+    }
+}
+
+// Owned operands forward to the reference implementations above.
+macro_rules! forward_owned_binop {
+    ($tr:ident, $method:ident) => {
+        impl $tr<Polynomial> for Polynomial {
+            type Output = Polynomial;
+
+            fn $method(self, rhs: Polynomial) -> Polynomial {
+                (&self).$method(&rhs)
+            }
+        }
+
+        impl $tr<&Polynomial> for Polynomial {
+            type Output = Polynomial;
+
+            fn $method(self, rhs: &Polynomial) -> Polynomial {
+                (&self).$method(rhs)
+            }
+        }
+
+        impl $tr<Polynomial> for &Polynomial {
+            type Output = Polynomial;
+
+            fn $method(self, rhs: Polynomial) -> Polynomial {
+                self.$method(&rhs)
+            }
+        }
+    };
+}
+
+forward_owned_binop!(Add, add);
+forward_owned_binop!(Sub, sub);
+forward_owned_binop!(Mul, mul);
+
+impl Mul<f64> for Polynomial {
+    type Output = Polynomial;
+
+    fn mul(self, rhs: f64) -> Polynomial {
+        &self * rhs
+    }
+}
+
+impl Mul<Polynomial> for f64 {
+    type Output = Polynomial;
+
+    fn mul(self, rhs: Polynomial) -> Polynomial {
+        &rhs * self
+    }
+}
+
+impl Neg for Polynomial {
+    type Output = Polynomial;
+
+    fn neg(self) -> Polynomial {
+        -&self
+    }
+}
+
+/// ### polyval(p, x)
+///
+/// Polynomial Function
+///
+/// The `polyval` function evaluates the polynomial with coefficients `p` (in descending powers) at `x` with Horner's method (MATLAB's `polyval`).
+/// An empty `p` is the zero polynomial.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::polynomial::polyval;
+/// assert_eq!(polyval(&[3.0, 2.0, 1.0], 5.0), 86.0);
+/// assert_eq!(polyval(&[], 5.0), 0.0);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn polyval(p: &[f64], x: f64) -> f64 {
+    p.iter().fold(0.0, |acc, &c| acc * x + c)
+}
+
+/// ### polyval_vec(p, x)
+///
+/// Polynomial Function
+///
+/// The `polyval_vec` function evaluates the polynomial with coefficients `p` at every element of `x`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::range_from_to;
+/// use mathlab::polynomial::polyval_vec;
+/// let x = range_from_to(0.0, 3.0, 1.0);
+/// assert_eq!(polyval_vec(&[1.0, 0.0, -1.0], &x), [-1.0, 0.0, 3.0, 8.0]);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn polyval_vec(p: &[f64], x: &[f64]) -> Vec<f64> {
+    x.iter().map(|&x| polyval(p, x)).collect()
+}
+
+/// ### polyfit(x, y, n)
+///
+/// Polynomial Function
+///
+/// The `polyfit` function returns the coefficients (in descending powers) of the polynomial of degree `n`
+/// that fits the points `(x[i], y[i])` best in the least-squares sense (MATLAB's `polyfit`).
+/// The Vandermonde system is solved with a QR decomposition; with fewer than `n + 1` points
+/// the minimum-norm coefficients are returned.
+///
+/// ### Errors
+///
+/// Returns `MathError::DimensionMismatch` if `x` and `y` differ in length, `MathError::EmptyInput` if they are empty,
+/// and `MathError::Singular` if the points do not determine the fit (for example, repeated `x` values).
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{range_from_to, fix64_vec};
+/// use mathlab::polynomial::{polyfit, polyval_vec};
+/// let x = range_from_to(0.0, 3.0, 0.5);
+/// let y = polyval_vec(&[2.0, -1.0, 0.5], &x);
+/// assert_eq!(fix64_vec(&polyfit(&x, &y, 2).unwrap()), [2.0, -1.0, 0.5]);
+/// // Least-squares line through noisy points
+/// let p = polyfit(&[1.0, 2.0, 3.0, 4.0], &[6.0, 5.0, 7.0, 10.0], 1).unwrap();
+/// assert_eq!(fix64_vec(&p), [1.4, 3.5]);
+/// assert!(polyfit(&[1.0, 2.0], &[1.0], 1).is_err());
+/// ```
+/// <small>End Fun Doc</small>
+pub fn polyfit(x: &[f64], y: &[f64], n: usize) -> Result<Vec<f64>, MathError> {
+    if x.len() != y.len() {
+        return Err(MathError::DimensionMismatch {
+            expected: x.len(),
+            found: y.len(),
+        });
+    }
+    if x.is_empty() {
+        return Err(MathError::EmptyInput);
+    }
+    let v = Matrix::from_fn(x.len(), n + 1, |i, j| x[i].powi((n - j) as i32));
+    lstsq(&v, y)
+}
+
+/// ### roots(p)
+///
+/// Polynomial Function
+///
+/// The `roots` function returns the roots of the polynomial with coefficients `p` (in descending powers) as complex numbers (MATLAB's `roots`).
+/// Leading zeros are ignored, trailing zeros give roots at `0`, and the remaining roots are the eigenvalues of the companion matrix,
+/// sorted by real part, then by imaginary part. Constants have no roots.
+///
+/// ### Errors
+///
+/// Returns `MathError::NoConvergence` if the eigenvalue iteration fails.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{Complex, fix64};
+/// use mathlab::polynomial::roots;
+/// let r = roots(&[1.0, -6.0, 11.0, -6.0]).unwrap();
+/// assert_eq!(r.iter().map(|z| fix64(z.re)).collect::<Vec<f64>>(), [1.0, 2.0, 3.0]);
+/// // x^2 + 1 has the roots -i and i
+/// assert_eq!(roots(&[1.0, 0.0, 1.0]).unwrap(), [Complex::new(0.0, -1.0), Complex::new(0.0, 1.0)]);
+/// assert_eq!(roots(&[0.0, 2.0, -4.0, 0.0]).unwrap(), [Complex::from(0.0), Complex::from(2.0)]);
+/// assert!(roots(&[5.0]).unwrap().is_empty());
+/// ```
+/// <small>End Fun Doc</small>
+pub fn roots(p: &[f64]) -> Result<Vec<Complex>, MathError> {
+    let p = trim(p);
+    let zeros = p.iter().rev().take_while(|&&c| c == 0.0).count();
+    if p[0] == 0.0 {
+        return Ok(Vec::new());
+    }
+    let p = &p[..p.len() - zeros];
+    let n = p.len() - 1;
+    let mut r = vec![Complex::ZERO; zeros];
+    if n > 0 {
+        let c = Matrix::from_fn(n, n, |i, j| match i {
+            0 => -p[j + 1] / p[0],
+            _ if i == j + 1 => 1.0,
+            _ => 0.0,
+        });
+        r.extend(
+            eigvals(&c)?
+                .into_iter()
+                .map(|(re, im)| Complex::new(re, im)),
+        );
+    }
+    r.sort_by(|x, y| x.re.total_cmp(&y.re).then(x.im.total_cmp(&y.im)));
+    Ok(r)
+}
+
+/// ### conv(a, b)
+///
+/// Polynomial Function
+///
+/// The `conv` function multiplies the polynomials with coefficients `a` and `b`, i.e. returns the convolution of the two vectors (MATLAB's `conv`).
+/// The result has `a.len() + b.len() - 1` elements, or none if either input is empty.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::polynomial::conv;
+/// assert_eq!(conv(&[1.0, 2.0], &[1.0, 3.0]), [1.0, 5.0, 6.0]);
+/// assert_eq!(conv(&[1.0, 1.0, 1.0], &[1.0, -1.0]), [1.0, 0.0, 0.0, -1.0]);
+/// assert!(conv(&[], &[1.0]).is_empty());
+/// ```
+/// <small>End Fun Doc</small>
+pub fn conv(a: &[f64], b: &[f64]) -> Vec<f64> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let mut c = vec![0.0; a.len() + b.len() - 1];
+    for (i, &x) in a.iter().enumerate() {
+        for (j, &y) in b.iter().enumerate() {
+            c[i + j] += x * y;
+        }
+    }
+    c
+}
+
+/// ### deconv(b, a)
+///
+/// Polynomial Function
+///
+/// The `deconv` function divides the polynomial `b` by the polynomial `a` (MATLAB's `[q, r] = deconv(b, a)`)
+/// and returns the quotient `q` and the remainder `r`, such that `b = conv(a, q) + r`.
+/// The remainder has the same length as `b`, and the quotient is empty when `b` is shorter than `a`.
+///
+/// ### Errors
+///
+/// Returns `MathError::Domain` if the first coefficient of `a` is zero or `a` is empty.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::polynomial::deconv;
+/// let (q, r) = deconv(&[1.0, 5.0, 6.0], &[1.0, 2.0]).unwrap();
+/// assert_eq!(q, [1.0, 3.0]);
+/// assert_eq!(r, [0.0, 0.0, 0.0]);
+/// let (q, r) = deconv(&[1.0, 0.0, 0.0, 1.0], &[1.0, 1.0, 1.0]).unwrap();
+/// assert_eq!(q, [1.0, -1.0]);
+/// assert_eq!(r, [0.0, 0.0, 0.0, 2.0]);
+/// assert!(deconv(&[1.0, 2.0], &[0.0, 1.0]).is_err());
+/// ```
+/// <small>End Fun Doc</small>
+pub fn deconv(b: &[f64], a: &[f64]) -> Result<(Vec<f64>, Vec<f64>), MathError> {
+    if a.first().is_none_or(|&c| c == 0.0) {
+        return Err(MathError::Domain(
+            "the leading coefficient of the divisor must be nonzero",
+        ));
+    }
+    if b.len() < a.len() {
+        return Ok((Vec::new(), b.to_vec()));
+    }
+    let mut r = b.to_vec();
+    let mut q = vec![0.0; b.len() - a.len() + 1];
+    for i in 0..q.len() {
+        q[i] = r[i] / a[0];
+        for (j, &c) in a.iter().enumerate() {
+            r[i + j] -= q[i] * c;
+        }
+        // The leading term cancels exactly by construction
+        r[i] = 0.0;
+    }
+    Ok((q, r))
+}
+
+/// ### polyder(p)
+///
+/// Polynomial Function
+///
+/// The `polyder` function returns the coefficients of the derivative of the polynomial `p` (MATLAB's `polyder`).
+/// The derivative of a constant is `[0.0]`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::polynomial::polyder;
+/// assert_eq!(polyder(&[3.0, -2.0, 1.0]), [6.0, -2.0]);
+/// assert_eq!(polyder(&[4.0]), [0.0]);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn polyder(p: &[f64]) -> Vec<f64> {
+    let n = p.len();
+    if n <= 1 {
+        return vec![0.0];
+    }
+    p[..n - 1]
+        .iter()
+        .enumerate()
+        .map(|(i, &c)| c * (n - 1 - i) as f64)
+        .collect()
+}
+
+/// ### polyint(p, k)
+///
+/// Polynomial Function
+///
+/// The `polyint` function returns the coefficients of the antiderivative of the polynomial `p`
+/// with the constant of integration `k` (MATLAB's `polyint(p, k)`).
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::polynomial::{polyint, polyval};
+/// assert_eq!(polyint(&[3.0, 2.0, 1.0], 0.0), [1.0, 1.0, 1.0, 0.0]);
+/// assert_eq!(polyint(&[2.0], 5.0), [2.0, 5.0]);
+/// // The definite integral of x^2 over [0, 3]
+/// let q = polyint(&[1.0, 0.0, 0.0], 0.0);
+/// assert_eq!(polyval(&q, 3.0) - polyval(&q, 0.0), 9.0);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn polyint(p: &[f64], k: f64) -> Vec<f64> {
+    let n = p.len();
+    p.iter()
+        .enumerate()
+        .map(|(i, &c)| c / (n - i) as f64)
+        .chain(std::iter::once(k))
+        .collect()
+}
@@ -0,0 +1,284 @@
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// ### Rng
+///
+/// Random Number Generator
+///
+/// The `Rng` type is a seedable pseudo-random number generator based on xoshiro256**,
+/// with its state expanded from a single `u64` seed by SplitMix64.
+/// Generators created with the same seed produce the same sequence, which makes simulations reproducible.
+/// It is fast and statistically strong, but it is not suitable for cryptographic use.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::Rng;
+/// let mut a = Rng::seed(42);
+/// let mut b = Rng::seed(42);
+/// assert_eq!(a.next_u64(), b.next_u64());
+/// let x = a.next_f64();
+/// assert!((0.0..1.0).contains(&x));
+/// let d = a.int_range(1, 6);
+/// assert!((1..=6).contains(&d));
+/// ```
+/// <small>End Type Doc</small>
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rng {
+    s: [u64; 4],
+}
+
+/// Advances a SplitMix64 state and returns its next output.
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+impl Rng {
+    /// ### Rng::seed(seed)
+    ///
+    /// Random Number Generator
+    ///
+    /// Creates a generator whose whole sequence is determined by `seed`.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::math::Rng;
+    /// let mut rng = Rng::seed(7);
+    /// let first: Vec<u64> = (0..3).map(|_| rng.next_u64()).collect();
+    /// let mut again = Rng::seed(7);
+    /// assert_eq!(first, (0..3).map(|_| again.next_u64()).collect::<Vec<u64>>());
+    /// assert_ne!(Rng::seed(7), Rng::seed(8));
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn seed(seed: u64) -> Rng {
+        let mut state = seed;
+        Rng {
+            s: [
+                splitmix64(&mut state),
+                splitmix64(&mut state),
+                splitmix64(&mut state),
+                splitmix64(&mut state),
+            ],
+        }
+    }
+
+    /// ### Rng::from_time()
+    ///
+    /// Random Number Generator
+    ///
+    /// Creates a generator seeded from the system clock and a process-wide counter,
+    /// so that generators created in the same clock tick still differ.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::math::Rng;
+    /// assert_ne!(Rng::from_time(), Rng::from_time());
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn from_time() -> Rng {
+        static COUNTER: AtomicU64 = AtomicU64::new(0);
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_nanos() as u64)
+            .unwrap_or(0);
+        let count = COUNTER.fetch_add(1, Ordering::Relaxed);
+        Rng::seed(nanos ^ count.wrapping_mul(0x9e37_79b9_7f4a_7c15))
+    }
+
+    /// ### next_u64()
+    ///
+    /// Random Number Generator
+    ///
+    /// Returns the next uniformly distributed `u64`.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::math::Rng;
+    /// let mut rng = Rng::seed(1);
+    /// assert_ne!(rng.next_u64(), rng.next_u64());
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn next_u64(&mut self) -> u64 {
+        let result = self.s[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = self.s[1] << 17;
+        self.s[2] ^= self.s[0];
+        self.s[3] ^= self.s[1];
+        self.s[1] ^= self.s[2];
+        self.s[0] ^= self.s[3];
+        self.s[2] ^= t;
+        self.s[3] = self.s[3].rotate_left(45);
+        result
+    }
+
+    /// ### next_f64()
+    ///
+    /// Random Number Generator
+    ///
+    /// Returns a uniformly distributed `f64` in `[0, 1)` with 53 random bits (MATLAB's `rand`).
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::math::Rng;
+    /// let mut rng = Rng::seed(2);
+    /// let mean = (0..10000).map(|_| rng.next_f64()).sum::<f64>() / 10000.0;
+    /// assert!((mean - 0.5).abs() < 0.02);
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 * (1.0 / (1_u64 << 53) as f64)
+    }
+
+    /// ### uniform(low, high)
+    ///
+    /// Random Number Generator
+    ///
+    /// Returns a uniformly distributed `f64` in `[low, high)`.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::math::Rng;
+    /// let mut rng = Rng::seed(3);
+    /// let x = rng.uniform(-2.0, 2.0);
+    /// assert!((-2.0..2.0).contains(&x));
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn uniform(&mut self, low: f64, high: f64) -> f64 {
+        low + (high - low) * self.next_f64()
+    }
+
+    /// ### int_range(low, high)
+    ///
+    /// Random Number Generator
+    ///
+    /// Returns a uniformly distributed integer in the inclusive range `[low, high]` (MATLAB's `randi([low, high])`),
+    /// without modulo bias.
+    ///
+    /// ### Panics
+    ///
+    /// Panics if `low > high`.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::math::Rng;
+    /// let mut rng = Rng::seed(4);
+    /// let rolls: Vec<i64> = (0..1000).map(|_| rng.int_range(1, 6)).collect();
+    /// assert!(rolls.iter().all(|r| (1..=6).contains(r)));
+    /// assert!((1..=6).all(|face| rolls.contains(&face)));
+    /// assert_eq!(rng.int_range(-3, -3), -3);
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn int_range(&mut self, low: i64, high: i64) -> i64 {
+        assert!(low <= high, "low must not be greater than high");
+        let span = (high as i128 - low as i128) as u64;
+        if span == u64::MAX {
+            return self.next_u64() as i64;
+        }
+        let n = span + 1;
+        // Reject the lowest 2^64 mod n values so that every residue is equally likely
+        let threshold = n.wrapping_neg() % n;
+        loop {
+            let r = self.next_u64();
+            if r >= threshold {
+                return (low as i128 + (r % n) as i128) as i64;
+            }
+        }
+    }
+
+    /// ### normal(mean, std)
+    ///
+    /// Random Number Generator
+    ///
+    /// Returns a normally distributed `f64` with the given mean and standard deviation (MATLAB's `mean + std * randn`),
+    /// using the Marsaglia polar method.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::math::Rng;
+    /// let mut rng = Rng::seed(5);
+    /// let xs: Vec<f64> = (0..10000).map(|_| rng.normal(10.0, 2.0)).collect();
+    /// let mean = xs.iter().sum::<f64>() / xs.len() as f64;
+    /// assert!((mean - 10.0).abs() < 0.1);
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn normal(&mut self, mean: f64, std: f64) -> f64 {
+        loop {
+            let u = 2.0 * self.next_f64() - 1.0;
+            let v = 2.0 * self.next_f64() - 1.0;
+            let s = u * u + v * v;
+            if s > 0.0 && s < 1.0 {
+                return mean + std * u * (-2.0 * s.ln() / s).sqrt();
+            }
+        }
+    }
+
+    /// ### shuffle(v)
+    ///
+    /// Random Number Generator
+    ///
+    /// Shuffles a slice in place with the Fisher-Yates algorithm, so every ordering is equally likely.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// use mathlab::math::Rng;
+    /// let mut v = [1, 2, 3, 4, 5];
+    /// Rng::seed(6).shuffle(&mut v);
+    /// v.sort();
+    /// assert_eq!(v, [1, 2, 3, 4, 5]);
+    /// ```
+    /// <small>End Fun Doc</small>
+    pub fn shuffle<T>(&mut self, v: &mut [T]) {
+        for i in (1..v.len()).rev() {
+            let j = self.int_range(0, i as i64) as usize;
+            v.swap(i, j);
+        }
+    }
+}
+
+thread_local! {
+    static THREAD_RNG: RefCell<Rng> = RefCell::new(Rng::from_time());
+}
+
+/// ### seed(seed)
+///
+/// Random Number Generator
+///
+/// The `seed` function reseeds the current thread's generator used by `rand` and `rand_vec` (MATLAB's `rng(seed)`),
+/// making their subsequent results reproducible.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::rand_vec;
+/// use mathlab::random::seed;
+/// seed(42);
+/// let first = rand_vec(&[3, 6, 19]);
+/// seed(42);
+/// assert_eq!(rand_vec(&[3, 6, 19]), first);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn seed(seed: u64) {
+    THREAD_RNG.with(|rng| *rng.borrow_mut() = Rng::seed(seed));
+}
+
+/// ### with_thread_rng(f)
+///
+/// Random Number Generator
+///
+/// The `with_thread_rng` function runs `f` with the current thread's generator,
+/// the same one that `seed` resets and `rand` draws from.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::random::{seed, with_thread_rng};
+/// seed(1);
+/// let a = with_thread_rng(|rng| rng.next_f64());
+/// seed(1);
+/// assert_eq!(with_thread_rng(|rng| rng.next_f64()), a);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn with_thread_rng<R>(f: impl FnOnce(&mut Rng) -> R) -> R {
+    THREAD_RNG.with(|rng| f(&mut rng.borrow_mut()))
+}
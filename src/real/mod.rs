@@ -0,0 +1,227 @@
+use std::fmt::{Debug, Display};
+use std::num::ParseFloatError;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
+use std::str::FromStr;
+
+/// ### Real
+///
+/// Numeric Trait
+///
+/// The `Real` trait is implemented for `f32` and `f64` and lets the functions in `mathlab::math`
+/// (`sin`, `exp`, `ln`, `sqrt`, the `*_vec` wrappers, ...) work on either precision,
+/// so `f32` code does not have to round-trip through `f64`.
+/// It provides the arithmetic operators and the usual floating-point methods,
+/// plus `from_f64` to bring `f64` literals and constants into the generic type.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{Real, sqrt, sin_vec};
+/// fn hypot2<T: Real>(x: T, y: T) -> T {
+///     sqrt(x * x + y * y)
+/// }
+/// assert_eq!(hypot2(3.0_f32, 4.0_f32), 5.0_f32);
+/// assert_eq!(hypot2(3.0_f64, 4.0_f64), 5.0_f64);
+/// assert_eq!(sin_vec(&[0.0_f32, 1.0_f32]), [0.0_f32, 0.84147096_f32]);
+/// assert_eq!(f32::from_f64(0.5), 0.5_f32);
+/// ```
+/// <small>End Type Doc</small>
+pub trait Real:
+    Copy
+    + Debug
+    + Display
+    + Default
+    + PartialEq
+    + PartialOrd
+    + FromStr<Err = ParseFloatError>
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + DivAssign
+{
+    const ZERO: Self;
+    const ONE: Self;
+    const EPSILON: Self;
+    const INFINITY: Self;
+    const NEG_INFINITY: Self;
+    const NAN: Self;
+
+    fn from_f64(x: f64) -> Self;
+    fn to_f64(self) -> f64;
+    fn to_f32(self) -> f32;
+
+    fn is_nan(self) -> bool;
+    fn is_infinite(self) -> bool;
+    fn is_finite(self) -> bool;
+
+    fn abs(self) -> Self;
+    fn floor(self) -> Self;
+    fn ceil(self) -> Self;
+    fn round(self) -> Self;
+    fn trunc(self) -> Self;
+    fn recip(self) -> Self;
+    fn sqrt(self) -> Self;
+    fn cbrt(self) -> Self;
+    fn powi(self, n: i32) -> Self;
+    fn powf(self, n: Self) -> Self;
+    fn exp(self) -> Self;
+    fn exp_m1(self) -> Self;
+    fn ln(self) -> Self;
+    fn ln_1p(self) -> Self;
+    fn log2(self) -> Self;
+    fn log10(self) -> Self;
+
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn tan(self) -> Self;
+    fn asin(self) -> Self;
+    fn acos(self) -> Self;
+    fn atan(self) -> Self;
+    fn atan2(self, other: Self) -> Self;
+    fn sinh(self) -> Self;
+    fn cosh(self) -> Self;
+    fn tanh(self) -> Self;
+    fn asinh(self) -> Self;
+    fn acosh(self) -> Self;
+    fn atanh(self) -> Self;
+
+    fn min(self, other: Self) -> Self;
+    fn max(self, other: Self) -> Self;
+}
+
+macro_rules! impl_real {
+    ($t:ident) => {
+        impl Real for $t {
+            const ZERO: Self = 0.0;
+            const ONE: Self = 1.0;
+            const EPSILON: Self = $t::EPSILON;
+            const INFINITY: Self = $t::INFINITY;
+            const NEG_INFINITY: Self = $t::NEG_INFINITY;
+            const NAN: Self = $t::NAN;
+
+            fn from_f64(x: f64) -> Self {
+                x as $t
+            }
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+            fn to_f32(self) -> f32 {
+                self as f32
+            }
+
+            fn is_nan(self) -> bool {
+                $t::is_nan(self)
+            }
+            fn is_infinite(self) -> bool {
+                $t::is_infinite(self)
+            }
+            fn is_finite(self) -> bool {
+                $t::is_finite(self)
+            }
+
+            fn abs(self) -> Self {
+                $t::abs(self)
+            }
+            fn floor(self) -> Self {
+                $t::floor(self)
+            }
+            fn ceil(self) -> Self {
+                $t::ceil(self)
+            }
+            fn round(self) -> Self {
+                $t::round(self)
+            }
+            fn trunc(self) -> Self {
+                $t::trunc(self)
+            }
+            fn recip(self) -> Self {
+                $t::recip(self)
+            }
+            fn sqrt(self) -> Self {
+                $t::sqrt(self)
+            }
+            fn cbrt(self) -> Self {
+                $t::cbrt(self)
+            }
+            fn powi(self, n: i32) -> Self {
+                $t::powi(self, n)
+            }
+            fn powf(self, n: Self) -> Self {
+                $t::powf(self, n)
+            }
+            fn exp(self) -> Self {
+                $t::exp(self)
+            }
+            fn exp_m1(self) -> Self {
+                $t::exp_m1(self)
+            }
+            fn ln(self) -> Self {
+                $t::ln(self)
+            }
+            fn ln_1p(self) -> Self {
+                $t::ln_1p(self)
+            }
+            fn log2(self) -> Self {
+                $t::log2(self)
+            }
+            fn log10(self) -> Self {
+                $t::log10(self)
+            }
+
+            fn sin(self) -> Self {
+                $t::sin(self)
+            }
+            fn cos(self) -> Self {
+                $t::cos(self)
+            }
+            fn tan(self) -> Self {
+                $t::tan(self)
+            }
+            fn asin(self) -> Self {
+                $t::asin(self)
+            }
+            fn acos(self) -> Self {
+                $t::acos(self)
+            }
+            fn atan(self) -> Self {
+                $t::atan(self)
+            }
+            fn atan2(self, other: Self) -> Self {
+                $t::atan2(self, other)
+            }
+            fn sinh(self) -> Self {
+                $t::sinh(self)
+            }
+            fn cosh(self) -> Self {
+                $t::cosh(self)
+            }
+            fn tanh(self) -> Self {
+                $t::tanh(self)
+            }
+            fn asinh(self) -> Self {
+                $t::asinh(self)
+            }
+            fn acosh(self) -> Self {
+                $t::acosh(self)
+            }
+            fn atanh(self) -> Self {
+                $t::atanh(self)
+            }
+
+            fn min(self, other: Self) -> Self {
+                $t::min(self, other)
+            }
+            fn max(self, other: Self) -> Self {
+                $t::max(self, other)
+            }
+        }
+    };
+}
+
+impl_real!(f32);
+impl_real!(f64);
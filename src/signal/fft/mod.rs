@@ -0,0 +1,421 @@
+use crate::complex::Complex;
+use crate::error::MathError;
+use crate::signal::window::{hann, Symmetry};
+
+/// Returns `exp(sign * 2 pi i k / n)`.
+fn twiddle(k: usize, n: usize, sign: f64) -> Complex {
+    let (s, c) = (sign * 2.0 * std::f64::consts::PI * k as f64 / n as f64).sin_cos();
+    Complex::new(c, s)
+}
+
+/// The in-place iterative radix-2 transform of a power-of-two length, with `sign = -1` forward and `+1` backward
+/// (unscaled).
+fn radix2(a: &mut [Complex], sign: f64) {
+    let n = a.len();
+    if n < 2 {
+        return;
+    }
+    // Bit-reversal permutation
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            a.swap(i, j);
+        }
+    }
+    let w: Vec<Complex> = (0..n / 2).map(|k| twiddle(k, n, sign)).collect();
+    let mut len = 2;
+    while len <= n {
+        let stride = n / len;
+        for start in (0..n).step_by(len) {
+            for k in 0..len / 2 {
+                let u = a[start + k];
+                let v = a[start + k + len / 2] * w[k * stride];
+                a[start + k] = u + v;
+                a[start + k + len / 2] = u - v;
+            }
+        }
+        len <<= 1;
+    }
+}
+
+/// Bluestein's chirp-z algorithm: the transform of an arbitrary length as a convolution
+/// computed with radix-2 transforms of a power-of-two length of at least `2n - 1`.
+fn bluestein(x: &[Complex], sign: f64) -> Vec<Complex> {
+    let n = x.len();
+    let m = (2 * n - 1).next_power_of_two();
+    // exp(sign * pi i k^2 / n), with k^2 reduced modulo 2n to keep the angle small
+    let chirp: Vec<Complex> = (0..n)
+        .map(|k| twiddle((k * k) % (2 * n), 2 * n, sign))
+        .collect();
+    let mut a = vec![Complex::ZERO; m];
+    let mut b = vec![Complex::ZERO; m];
+    for k in 0..n {
+        a[k] = x[k] * chirp[k];
+        b[k] = chirp[k].conj();
+        if k > 0 {
+            b[m - k] = b[k];
+        }
+    }
+    radix2(&mut a, -1.0);
+    radix2(&mut b, -1.0);
+    for (ak, bk) in a.iter_mut().zip(&b) {
+        *ak *= *bk;
+    }
+    radix2(&mut a, 1.0);
+    (0..n).map(|k| a[k] * chirp[k] / m as f64).collect()
+}
+
+/// The unscaled discrete Fourier transform of any length, with `sign = -1` forward and `+1` backward.
+fn transform(x: &[Complex], sign: f64) -> Vec<Complex> {
+    let n = x.len();
+    if n.is_power_of_two() {
+        let mut a = x.to_vec();
+        radix2(&mut a, sign);
+        a
+    } else if n == 0 {
+        Vec::new()
+    } else {
+        bluestein(x, sign)
+    }
+}
+
+/// ### fft(x)
+///
+/// Fourier Transform
+///
+/// The `fft` function returns the discrete Fourier transform `X[k] = sum(x[j] * exp(-2 pi i j k / n))` of `x`
+/// (MATLAB's `fft(x)`). Power-of-two lengths use the radix-2 Cooley–Tukey algorithm, and other lengths
+/// Bluestein's algorithm, so every length takes `O(n log n)` operations. An empty input gives an empty output.
+///
+/// For real samples, use `rfft`, or convert with `Complex::from`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::Complex;
+/// use mathlab::signal::fft;
+/// let x: Vec<Complex> = [1.0, 2.0, 3.0, 4.0].into_iter().map(Complex::from).collect();
+/// let expected = [Complex::new(10.0, 0.0), Complex::new(-2.0, 2.0), Complex::new(-2.0, 0.0), Complex::new(-2.0, -2.0)];
+/// assert!(fft(&x).iter().zip(&expected).all(|(a, b)| (*a - *b).abs() < 1e-12));
+/// // Any length: the transform of a unit impulse is flat
+/// let mut d = vec![Complex::ZERO; 7];
+/// d[0] = Complex::ONE;
+/// assert!(fft(&d).iter().all(|z| (*z - Complex::ONE).abs() < 1e-12));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn fft(x: &[Complex]) -> Vec<Complex> {
+    transform(x, -1.0)
+}
+
+/// ### ifft(x)
+///
+/// Fourier Transform
+///
+/// The `ifft` function returns the inverse discrete Fourier transform `x[j] = sum(X[k] * exp(2 pi i j k / n)) / n`
+/// of `x` (MATLAB's `ifft(X)`), so that `ifft(&fft(&x))` is `x` up to rounding. Any length is accepted, as for `fft`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::Complex;
+/// use mathlab::signal::{fft, ifft};
+/// let x = [Complex::new(1.0, -1.0), Complex::new(0.5, 2.0), Complex::new(-3.0, 0.0)];
+/// let y = ifft(&fft(&x));
+/// assert!(x.iter().zip(&y).all(|(a, b)| (*a - *b).abs() < 1e-12));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn ifft(x: &[Complex]) -> Vec<Complex> {
+    let n = x.len() as f64;
+    transform(x, 1.0).into_iter().map(|z| z / n).collect()
+}
+
+/// ### rfft(x)
+///
+/// Fourier Transform
+///
+/// The `rfft` function returns the discrete Fourier transform of real samples `x`, keeping only the
+/// `n / 2 + 1` non-negative frequency terms (NumPy's `rfft`); the others are their complex conjugates.
+/// The frequencies of the terms are given by `rfftfreq`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::Complex;
+/// use mathlab::signal::rfft;
+/// let x = rfft(&[1.0, 2.0, 3.0, 4.0]);
+/// let expected = [Complex::new(10.0, 0.0), Complex::new(-2.0, 2.0), Complex::new(-2.0, 0.0)];
+/// assert_eq!(x.len(), 3);
+/// assert!(x.iter().zip(&expected).all(|(a, b)| (*a - *b).abs() < 1e-12));
+/// assert_eq!(rfft(&[1.0; 5]).len(), 3);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn rfft(x: &[f64]) -> Vec<Complex> {
+    let z: Vec<Complex> = x.iter().map(|&v| Complex::from(v)).collect();
+    let mut spectrum = fft(&z);
+    spectrum.truncate(x.len() / 2 + 1);
+    spectrum
+}
+
+/// ### irfft(x, n)
+///
+/// Fourier Transform
+///
+/// The `irfft` function inverts `rfft`: it returns the `n` real samples whose transform has the non-negative
+/// frequency terms `x` (NumPy's `irfft`). `n` is needed because `n` and `n + 1` samples give the same number
+/// of terms; `x` must have `n / 2 + 1` of them. The imaginary parts of the zero-frequency term
+/// (and of the Nyquist term for even `n`) are ignored.
+///
+/// ### Errors
+///
+/// Returns `MathError::EmptyInput` if `n` is zero and `MathError::DimensionMismatch` if `x` does not have `n / 2 + 1` terms.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::MathError;
+/// use mathlab::signal::{irfft, rfft};
+/// let x = [1.0, -2.0, 0.5, 4.0, 3.0];
+/// let y = irfft(&rfft(&x), 5).unwrap();
+/// assert!(x.iter().zip(&y).all(|(a, b)| (a - b).abs() < 1e-12));
+/// assert_eq!(irfft(&rfft(&x), 8), Err(MathError::DimensionMismatch { expected: 5, found: 3 }));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn irfft(x: &[Complex], n: usize) -> Result<Vec<f64>, MathError> {
+    if n == 0 {
+        return Err(MathError::EmptyInput);
+    }
+    if x.len() != n / 2 + 1 {
+        return Err(MathError::DimensionMismatch {
+            expected: n / 2 + 1,
+            found: x.len(),
+        });
+    }
+    let mut full = vec![Complex::ZERO; n];
+    full[0] = Complex::from(x[0].re);
+    for k in 1..x.len() {
+        full[k] = x[k];
+        full[n - k] = x[k].conj();
+    }
+    if n % 2 == 0 {
+        full[n / 2] = Complex::from(x[n / 2].re);
+    }
+    Ok(ifft(&full).into_iter().map(|z| z.re).collect())
+}
+
+/// ### fftshift(x)
+///
+/// Fourier Transform
+///
+/// The `fftshift` function moves the zero-frequency term of a spectrum to the centre (MATLAB's `fftshift`),
+/// rotating `x` right by `n / 2` places so that the frequencies run from the most negative to the most positive.
+/// It works on spectra and on frequency vectors from `fftfreq` alike.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::signal::{fftfreq, fftshift};
+/// assert_eq!(fftshift(&[0, 1, 2, 3, 4]), [3, 4, 0, 1, 2]);
+/// assert_eq!(fftshift(&fftfreq(4, 0.25)), [-2.0, -1.0, 0.0, 1.0]);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn fftshift<T: Clone>(x: &[T]) -> Vec<T> {
+    let mut out = x.to_vec();
+    out.rotate_right(x.len() / 2);
+    out
+}
+
+/// ### ifftshift(x)
+///
+/// Fourier Transform
+///
+/// The `ifftshift` function undoes `fftshift` (MATLAB's `ifftshift`), rotating `x` left by `n / 2` places;
+/// the two differ for odd lengths.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::signal::{fftshift, ifftshift};
+/// assert_eq!(ifftshift(&[3, 4, 0, 1, 2]), [0, 1, 2, 3, 4]);
+/// assert_eq!(ifftshift(&fftshift(&[1.0, 2.0, 3.0])), [1.0, 2.0, 3.0]);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn ifftshift<T: Clone>(x: &[T]) -> Vec<T> {
+    let mut out = x.to_vec();
+    out.rotate_left(x.len() / 2);
+    out
+}
+
+/// ### fftfreq(n, d)
+///
+/// Fourier Transform
+///
+/// The `fftfreq` function returns the frequencies of the terms of an `n`-point `fft` of samples taken `d` apart
+/// (NumPy's `fftfreq`): `[0, 1, ..., -2, -1] / (d * n)`, with the non-negative frequencies first.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::signal::fftfreq;
+/// assert_eq!(fftfreq(4, 0.25), [0.0, 1.0, -2.0, -1.0]);
+/// assert_eq!(fftfreq(5, 1.0), [0.0, 0.2, 0.4, -0.4, -0.2]);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn fftfreq(n: usize, d: f64) -> Vec<f64> {
+    let scale = d * n as f64;
+    (0..n)
+        .map(|k| {
+            if k < n.div_ceil(2) {
+                k as f64 / scale
+            } else {
+                -((n - k) as f64) / scale
+            }
+        })
+        .collect()
+}
+
+/// ### rfftfreq(n, d)
+///
+/// Fourier Transform
+///
+/// The `rfftfreq` function returns the `n / 2 + 1` non-negative frequencies of the terms of an `n`-point `rfft`
+/// of samples taken `d` apart (NumPy's `rfftfreq`).
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::signal::rfftfreq;
+/// assert_eq!(rfftfreq(4, 0.25), [0.0, 1.0, 2.0]);
+/// assert_eq!(rfftfreq(5, 1.0), [0.0, 0.2, 0.4]);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn rfftfreq(n: usize, d: f64) -> Vec<f64> {
+    let scale = d * n as f64;
+    (0..=n / 2).map(|k| k as f64 / scale).collect()
+}
+
+/// The one-sided power spectral density of one windowed segment: `|X[k]|^2 / (fs * sum(w^2))`,
+/// doubled except at zero frequency and (for even lengths) the Nyquist frequency.
+fn onesided_psd(segment: &[f64], window: &[f64], fs: f64) -> Vec<f64> {
+    let n = segment.len();
+    let tapered: Vec<f64> = segment.iter().zip(window).map(|(x, w)| x * w).collect();
+    let scale = fs * window.iter().map(|w| w * w).sum::<f64>();
+    rfft(&tapered)
+        .iter()
+        .enumerate()
+        .map(|(k, z)| {
+            let p = z.norm_sqr() / scale;
+            if k == 0 || (n % 2 == 0 && k == n / 2) {
+                p
+            } else {
+                2.0 * p
+            }
+        })
+        .collect()
+}
+
+/// Checks that the sampling frequency is positive and finite.
+fn check_fs(fs: f64) -> Result<(), MathError> {
+    if fs > 0.0 && fs.is_finite() {
+        Ok(())
+    } else {
+        Err(MathError::Domain("sampling frequency must be positive"))
+    }
+}
+
+/// ### periodogram(x, fs)
+///
+/// Spectral Estimate
+///
+/// The `periodogram` function estimates the one-sided power spectral density of real samples `x`
+/// taken at the sampling frequency `fs` (MATLAB's `periodogram(x, [], n, fs)`). It returns the frequencies
+/// `0, fs / n, ..., fs / 2` and the density at each, in power per unit of frequency, so that the density
+/// summed over the frequencies times `fs / n` is the mean square of `x` (Parseval's theorem).
+/// No window is applied; use `welch` for a smoother estimate.
+///
+/// ### Errors
+///
+/// Returns `MathError::EmptyInput` if `x` is empty and `MathError::Domain` if `fs` is not positive.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::fix;
+/// use mathlab::signal::periodogram;
+/// // A unit-amplitude 25 Hz sine sampled at 100 Hz for 1 s: power 1/2, all in the 25 Hz bin of width 1 Hz
+/// let x: Vec<f64> = (0..100).map(|k| (2.0 * std::f64::consts::PI * 25.0 * k as f64 / 100.0).sin()).collect();
+/// let (f, p) = periodogram(&x, 100.0).unwrap();
+/// assert_eq!((f.len(), f[25]), (51, 25.0));
+/// assert_eq!(fix(p[25], 12), 0.5);
+/// assert!(p.iter().enumerate().all(|(k, v)| k == 25 || *v < 1e-20));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn periodogram(x: &[f64], fs: f64) -> Result<(Vec<f64>, Vec<f64>), MathError> {
+    if x.is_empty() {
+        return Err(MathError::EmptyInput);
+    }
+    check_fs(fs)?;
+    let window = vec![1.0; x.len()];
+    Ok((rfftfreq(x.len(), 1.0 / fs), onesided_psd(x, &window, fs)))
+}
+
+/// ### welch(x, fs, nperseg, noverlap)
+///
+/// Spectral Estimate
+///
+/// The `welch` function estimates the one-sided power spectral density of real samples `x` taken at the
+/// sampling frequency `fs` with Welch's method (SciPy's `signal.welch`): `x` is split into segments of `nperseg` samples
+/// overlapping by `noverlap`, each segment is tapered with a periodic Hann window, and the periodograms
+/// of the segments are averaged. MATLAB's `pwelch` tapers with a Hamming window instead. Samples after the last whole segment are not used.
+/// Averaging reduces the variance of the estimate at the cost of a frequency resolution of `fs / nperseg`;
+/// `noverlap = nperseg / 2` is the usual choice.
+///
+/// It returns the frequencies `0, fs / nperseg, ..., fs / 2` and the density at each, scaled as in `periodogram`.
+///
+/// ### Errors
+///
+/// Returns `MathError::EmptyInput` if `x` is empty, and `MathError::Domain` if `fs` is not positive,
+/// `nperseg` is zero or longer than `x`, or `noverlap` is not less than `nperseg`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::Rng;
+/// use mathlab::signal::welch;
+/// // White noise of unit variance sampled at 10 Hz has a flat density of 2 / 10 = 0.2 per Hz
+/// let mut rng = Rng::seed(7);
+/// let x: Vec<f64> = (0..20000).map(|_| rng.normal(0.0, 1.0)).collect();
+/// let (f, p) = welch(&x, 10.0, 256, 128).unwrap();
+/// assert_eq!((f.len(), f[128]), (129, 5.0));
+/// let mean = p[1..128].iter().sum::<f64>() / 127.0;
+/// assert!((mean - 0.2).abs() < 0.01);
+/// assert!(welch(&x, 10.0, 256, 256).is_err());
+/// ```
+/// <small>End Fun Doc</small>
+pub fn welch(
+    x: &[f64],
+    fs: f64,
+    nperseg: usize,
+    noverlap: usize,
+) -> Result<(Vec<f64>, Vec<f64>), MathError> {
+    if x.is_empty() {
+        return Err(MathError::EmptyInput);
+    }
+    check_fs(fs)?;
+    if nperseg == 0 || nperseg > x.len() {
+        return Err(MathError::Domain(
+            "segment length must be positive and at most the signal length",
+        ));
+    }
+    if noverlap >= nperseg {
+        return Err(MathError::Domain(
+            "overlap must be less than the segment length",
+        ));
+    }
+    let window = hann(nperseg, Symmetry::Periodic);
+    let step = nperseg - noverlap;
+    let segments = (x.len() - nperseg) / step + 1;
+    let mut psd = vec![0.0; nperseg / 2 + 1];
+    for s in 0..segments {
+        let p = onesided_psd(&x[s * step..s * step + nperseg], &window, fs);
+        for (acc, v) in psd.iter_mut().zip(p) {
+            *acc += v / segments as f64;
+        }
+    }
+    Ok((rfftfreq(nperseg, 1.0 / fs), psd))
+}
@@ -0,0 +1,633 @@
+use crate::complex::{self, Complex};
+use crate::constants::PI;
+use crate::error::MathError;
+use crate::linalg;
+use crate::matrix::Matrix;
+use crate::polynomial;
+use crate::signal::fft::{fft, ifft};
+
+/// Below this length of the shorter input, direct convolution is faster than going through the FFT.
+const DIRECT_CONV_LEN: usize = 64;
+
+/// ### Shape
+///
+/// Convolution Shape
+///
+/// The `Shape` enum selects the part of the convolution of `x` (`n` samples) and `h` (`m` samples) that `conv` returns:
+/// - `Full`: the whole convolution, `n + m - 1` samples
+/// - `Same`: the central part with the same length `n` as `x`, starting at index `m / 2` of the full convolution
+/// - `Valid`: only the `n - m + 1` samples computed without the zero padding of `x`, or none if `m > n`
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::signal::{conv, Shape};
+/// let (x, h) = ([1.0, 2.0, 3.0, 4.0], [1.0, 1.0, 1.0]);
+/// assert_eq!(conv(&x, &h, Shape::Full), [1.0, 3.0, 6.0, 9.0, 7.0, 4.0]);
+/// assert_eq!(conv(&x, &h, Shape::Same), [3.0, 6.0, 9.0, 7.0]);
+/// assert_eq!(conv(&x, &h, Shape::Valid), [6.0, 9.0]);
+/// ```
+/// <small>End Type Doc</small>
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Shape {
+    Full,
+    Same,
+    Valid,
+}
+
+/// ### Band
+///
+/// Filter Band
+///
+/// The `Band` enum selects the response of a designed filter and its cutoff frequencies, normalized so that
+/// `1` is the Nyquist frequency (half the sampling frequency):
+/// - `Lowpass(w)`: passes frequencies below `w`
+/// - `Highpass(w)`: passes frequencies above `w`
+/// - `Bandpass(w1, w2)`: passes frequencies between `w1` and `w2`
+/// - `Bandstop(w1, w2)`: stops frequencies between `w1` and `w2`
+///
+/// Band-pass and band-stop filters have twice the order given to the design function.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::signal::{butter, Band};
+/// // A 100 Hz cutoff at a sampling frequency of 1 kHz
+/// let (b, a) = butter(4, Band::Lowpass(100.0 / 500.0)).unwrap();
+/// assert_eq!((b.len(), a.len()), (5, 5));
+/// let (b, a) = butter(4, Band::Bandpass(0.2, 0.4)).unwrap();
+/// assert_eq!((b.len(), a.len()), (9, 9));
+/// ```
+/// <small>End Type Doc</small>
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Band {
+    Lowpass(f64),
+    Highpass(f64),
+    Bandpass(f64, f64),
+    Bandstop(f64, f64),
+}
+
+fn conv_fft(x: &[f64], h: &[f64]) -> Vec<f64> {
+    let len = x.len() + h.len() - 1;
+    let size = len.next_power_of_two();
+    let pad = |v: &[f64]| -> Vec<Complex> {
+        let mut z: Vec<Complex> = v.iter().map(|&a| Complex::from(a)).collect();
+        z.resize(size, Complex::ZERO);
+        fft(&z)
+    };
+    let product: Vec<Complex> = pad(x).into_iter().zip(pad(h)).map(|(a, b)| a * b).collect();
+    ifft(&product).into_iter().take(len).map(|z| z.re).collect()
+}
+
+/// ### conv(x, h, shape)
+///
+/// Signal Function
+///
+/// The `conv` function returns the convolution `y[k] = sum(x[j] * h[k - j])` of the signal `x` with the
+/// impulse response `h`, trimmed to `shape` (MATLAB's `conv(u, v, shape)`). Short inputs are convolved
+/// directly; when both inputs are long, the product of their FFTs is used instead, which takes
+/// `O((n + m) log(n + m))` operations rather than `O(n m)`, at the cost of rounding errors of the order of
+/// the machine epsilon times the largest output.
+///
+/// An empty input gives an empty full or valid convolution and a `Same` result of zeros.
+/// For the product of polynomials, `polynomial::conv` gives the full convolution directly.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::signal::{conv, Shape};
+/// // A 3-point moving sum
+/// assert_eq!(conv(&[1.0, 2.0, 3.0, 4.0], &[1.0, 1.0, 1.0], Shape::Valid), [6.0, 9.0]);
+/// // MATLAB's example
+/// let (u, v) = ([-1.0, 2.0, 3.0, -2.0, 0.0, 1.0, 2.0], [2.0, 4.0, -1.0, 1.0]);
+/// assert_eq!(conv(&u, &v, Shape::Same), [15.0, 5.0, -9.0, 7.0, 6.0, 7.0, -1.0]);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn conv(x: &[f64], h: &[f64], shape: Shape) -> Vec<f64> {
+    let (n, m) = (x.len(), h.len());
+    let full = if n == 0 || m == 0 {
+        Vec::new()
+    } else if n.min(m) <= DIRECT_CONV_LEN {
+        polynomial::conv(x, h)
+    } else {
+        conv_fft(x, h)
+    };
+    match shape {
+        Shape::Full => full,
+        Shape::Same => (0..n)
+            .map(|k| full.get(k + m / 2).copied().unwrap_or(0.0))
+            .collect(),
+        Shape::Valid if m > 0 && m <= n => full[m - 1..n].to_vec(),
+        Shape::Valid => Vec::new(),
+    }
+}
+
+/// ### conv_full(x, h)
+///
+/// Signal Function
+///
+/// The `conv_full` function returns the full convolution of `x` and `h`, `n + m - 1` samples; it is `conv(x, h, Shape::Full)`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::signal::conv_full;
+/// assert_eq!(conv_full(&[1.0, 2.0, 3.0], &[0.0, 1.0, 0.5]), [0.0, 1.0, 2.5, 4.0, 1.5]);
+/// // Long inputs go through the FFT
+/// let y = conv_full(&[1.0; 100], &[1.0; 100]);
+/// assert_eq!((y.len(), y[99].round(), y[198].round()), (199, 100.0, 1.0));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn conv_full(x: &[f64], h: &[f64]) -> Vec<f64> {
+    conv(x, h, Shape::Full)
+}
+
+/// ### conv_same(x, h)
+///
+/// Signal Function
+///
+/// The `conv_same` function returns the central part of the convolution of `x` and `h` with the same length as `x`;
+/// it is `conv(x, h, Shape::Same)`. With a symmetric `h` of odd length, the output is aligned with `x`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::signal::conv_same;
+/// // A centered 3-point moving average
+/// assert_eq!(conv_same(&[3.0, 6.0, 9.0, 12.0], &[1.0 / 3.0; 3]), [3.0, 6.0, 9.0, 7.0]);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn conv_same(x: &[f64], h: &[f64]) -> Vec<f64> {
+    conv(x, h, Shape::Same)
+}
+
+/// ### conv_valid(x, h)
+///
+/// Signal Function
+///
+/// The `conv_valid` function returns the part of the convolution of `x` and `h` computed without zero padding,
+/// `n - m + 1` samples or none if `h` is longer than `x`; it is `conv(x, h, Shape::Valid)`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::signal::conv_valid;
+/// assert_eq!(conv_valid(&[1.0, 2.0, 3.0, 4.0], &[1.0, -1.0]), [1.0, 1.0, 1.0]);
+/// assert!(conv_valid(&[1.0], &[1.0, 2.0]).is_empty());
+/// ```
+/// <small>End Fun Doc</small>
+pub fn conv_valid(x: &[f64], h: &[f64]) -> Vec<f64> {
+    conv(x, h, Shape::Valid)
+}
+
+/// ### xcorr(x, y)
+///
+/// Signal Function
+///
+/// The `xcorr` function returns the cross-correlation `r[m] = sum(x[k + m] * y[k])` of `x` and `y` at the lags
+/// `m = -(n - 1), ..., n - 1` (MATLAB's `xcorr(x, y)`), where `n` is the length of the longer input and the
+/// shorter one is padded with zeros. The element at index `i` is the lag `i - (n - 1)`, so lag zero is in the middle.
+/// Use `xcorr(x, x)` for the autocorrelation. Two empty inputs give an empty output.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::signal::xcorr;
+/// assert_eq!(xcorr(&[1.0, 2.0, 3.0], &[1.0, 2.0, 3.0]), [3.0, 8.0, 14.0, 8.0, 3.0]);
+/// // y is x delayed by two samples, so the peak is at lag -2
+/// let r = xcorr(&[0.0, 1.0, 0.0, 0.0], &[0.0, 0.0, 0.0, 1.0]);
+/// assert_eq!(r, [0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0]);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn xcorr(x: &[f64], y: &[f64]) -> Vec<f64> {
+    let n = x.len().max(y.len());
+    let mut xp = x.to_vec();
+    xp.resize(n, 0.0);
+    let mut yr = y.to_vec();
+    yr.resize(n, 0.0);
+    yr.reverse();
+    conv(&xp, &yr, Shape::Full)
+}
+
+/// Normalizes `b` and `a` by `a[0]` and pads them with zeros to the same length.
+fn normalize(b: &[f64], a: &[f64]) -> Result<(Vec<f64>, Vec<f64>), MathError> {
+    if b.is_empty() || a.is_empty() {
+        return Err(MathError::EmptyInput);
+    }
+    if a[0] == 0.0 {
+        return Err(MathError::Domain(
+            "first denominator coefficient must be nonzero",
+        ));
+    }
+    let n = b.len().max(a.len());
+    let mut bn: Vec<f64> = b.iter().map(|v| v / a[0]).collect();
+    let mut an: Vec<f64> = a.iter().map(|v| v / a[0]).collect();
+    bn.resize(n, 0.0);
+    an.resize(n, 0.0);
+    Ok((bn, an))
+}
+
+/// Filters `x` in direct form II transposed with normalized, equal-length `b` and `a`, starting from
+/// (and updating) the delay state `z` of `len - 1` elements.
+fn lfilter(b: &[f64], a: &[f64], x: &[f64], z: &mut [f64]) -> Vec<f64> {
+    let order = z.len();
+    x.iter()
+        .map(|&v| {
+            let y = b[0] * v + z.first().copied().unwrap_or(0.0);
+            for i in 0..order {
+                let next = if i + 1 < order { z[i + 1] } else { 0.0 };
+                z[i] = b[i + 1] * v + next - a[i + 1] * y;
+            }
+            y
+        })
+        .collect()
+}
+
+/// ### filter(b, a, x)
+///
+/// Signal Function
+///
+/// The `filter` function applies the rational transfer function
+/// `H(z) = (b[0] + b[1] z^-1 + ... ) / (a[0] + a[1] z^-1 + ...)` to `x`, starting from rest (MATLAB's `filter(b, a, x)`):
+/// `a[0] y[k] = b[0] x[k] + b[1] x[k - 1] + ... - a[1] y[k - 1] - ...`. It is computed in direct form II transposed.
+/// With `a = [1.0]`, this is the FIR filter `conv(x, b)` truncated to the length of `x`.
+///
+/// ### Errors
+///
+/// Returns `MathError::EmptyInput` if `b` or `a` is empty and `MathError::Domain` if `a[0]` is zero.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::signal::filter;
+/// // A 2-point moving average (FIR)
+/// assert_eq!(filter(&[0.5, 0.5], &[1.0], &[2.0, 4.0, 6.0]).unwrap(), [1.0, 3.0, 5.0]);
+/// // y[k] = x[k] + 0.5 y[k - 1] (IIR): the impulse response decays geometrically
+/// assert_eq!(filter(&[1.0], &[1.0, -0.5], &[1.0, 0.0, 0.0, 0.0]).unwrap(), [1.0, 0.5, 0.25, 0.125]);
+/// assert!(filter(&[1.0], &[0.0, 1.0], &[1.0]).is_err());
+/// ```
+/// <small>End Fun Doc</small>
+pub fn filter(b: &[f64], a: &[f64], x: &[f64]) -> Result<Vec<f64>, MathError> {
+    let (b, a) = normalize(b, a)?;
+    let mut z = vec![0.0; b.len() - 1];
+    Ok(lfilter(&b, &a, x, &mut z))
+}
+
+/// The delay state of the normalized filter `b, a` in steady state for a unit step input
+/// (SciPy's `lfilter_zi`): the solution of `(I - A^T) z = b[1..] - a[1..] b[0]`, where `A` is the companion matrix of `a`.
+fn steady_state(b: &[f64], a: &[f64]) -> Result<Vec<f64>, MathError> {
+    let order = b.len() - 1;
+    let m = Matrix::from_fn(order, order, |i, j| {
+        let mut v = if j == 0 { a[i + 1] } else { 0.0 };
+        if i == j {
+            v += 1.0;
+        }
+        if j == i + 1 {
+            v -= 1.0;
+        }
+        v
+    });
+    let rhs: Vec<f64> = (0..order).map(|i| b[i + 1] - a[i + 1] * b[0]).collect();
+    linalg::solve(&m, &rhs)
+}
+
+/// ### filtfilt(b, a, x)
+///
+/// Signal Function
+///
+/// The `filtfilt` function applies the filter `b, a` to `x` forwards and then backwards (MATLAB's `filtfilt`), so that
+/// the output has no phase distortion and the magnitude response of the filter squared. To reduce transients,
+/// `x` is extended at both ends by `3 * (len - 1)` samples reflected about its end points, where `len` is the
+/// longer of `b` and `a`, and each pass starts in the steady state of the filter for its first sample.
+///
+/// ### Errors
+///
+/// Returns `MathError::EmptyInput` if `b`, `a` or `x` is empty, `MathError::Domain` if `a[0]` is zero or
+/// `x` has no more than `3 * (len - 1)` samples, and `MathError::Singular` if the filter has a pole at `z = 1`
+/// (`a` sums to zero), which has no steady state.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::signal::{filter, filtfilt};
+/// // A constant passes a filter of unit DC gain unchanged, with no start-up transient
+/// let (b, a) = ([0.2, 0.2], [1.0, -0.6]);
+/// let y = filtfilt(&b, &a, &[3.0; 10]).unwrap();
+/// assert!(y.iter().all(|v| (v - 3.0).abs() < 1e-12));
+/// assert!((filter(&b, &a, &[3.0; 10]).unwrap()[0] - 0.6).abs() < 1e-12);
+/// // A symmetric pulse stays centered: no delay
+/// let mut x = vec![0.0; 81];
+/// x[40] = 1.0;
+/// let y = filtfilt(&b, &a, &x).unwrap();
+/// assert!((0..40).all(|k| (y[40 - k] - y[40 + k]).abs() < 1e-9));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn filtfilt(b: &[f64], a: &[f64], x: &[f64]) -> Result<Vec<f64>, MathError> {
+    let (b, a) = normalize(b, a)?;
+    if x.is_empty() {
+        return Err(MathError::EmptyInput);
+    }
+    let edge = 3 * (b.len() - 1);
+    if x.len() <= edge {
+        return Err(MathError::Domain(
+            "signal must be longer than three times the filter order",
+        ));
+    }
+    let zi = steady_state(&b, &a)?;
+    let (first, last) = (x[0], x[x.len() - 1]);
+    let mut ext: Vec<f64> = (1..=edge).rev().map(|i| 2.0 * first - x[i]).collect();
+    ext.extend_from_slice(x);
+    ext.extend((1..=edge).map(|i| 2.0 * last - x[x.len() - 1 - i]));
+    let pass = |s: &[f64]| -> Vec<f64> {
+        let mut z: Vec<f64> = zi.iter().map(|v| v * s[0]).collect();
+        let mut y = lfilter(&b, &a, s, &mut z);
+        y.reverse();
+        y
+    };
+    let y = pass(&pass(&ext));
+    Ok(y[edge..edge + x.len()].to_vec())
+}
+
+/// The zeros, poles and gain of a transfer function.
+struct Zpk {
+    z: Vec<Complex>,
+    p: Vec<Complex>,
+    k: f64,
+}
+
+fn prod(v: &[Complex]) -> Complex {
+    v.iter().fold(Complex::ONE, |acc, &z| acc * z)
+}
+
+/// The coefficients, highest power first, of the monic polynomial with the given roots.
+fn poly(roots: &[Complex]) -> Vec<Complex> {
+    let mut c = vec![Complex::ONE];
+    for &r in roots {
+        c.push(Complex::ZERO);
+        for i in (1..c.len()).rev() {
+            let prev = c[i - 1];
+            c[i] -= r * prev;
+        }
+    }
+    c
+}
+
+/// Checks a normalized cutoff frequency and prewarps it for the bilinear transform at a sampling frequency of 2.
+fn warp(w: f64) -> Result<f64, MathError> {
+    if w > 0.0 && w < 1.0 {
+        Ok(4.0 * (PI * w / 2.0).tan())
+    } else {
+        Err(MathError::Domain(
+            "cutoff frequencies must lie strictly between 0 and 1",
+        ))
+    }
+}
+
+/// Checks a two-edge band and returns its prewarped center frequency and bandwidth.
+fn warp_band(w1: f64, w2: f64) -> Result<(f64, f64), MathError> {
+    let (lo, hi) = (warp(w1)?, warp(w2)?);
+    if lo < hi {
+        Ok(((lo * hi).sqrt(), hi - lo))
+    } else {
+        Err(MathError::Domain(
+            "lower cutoff frequency must be less than the upper one",
+        ))
+    }
+}
+
+/// Maps each root `r` of the low-pass prototype to the two roots `r ± sqrt(r^2 - wo^2)` of a band transformation.
+fn split_roots(roots: &[Complex], wo: f64) -> Vec<Complex> {
+    let d: Vec<Complex> = roots
+        .iter()
+        .map(|&r| complex::sqrt(r * r - wo * wo))
+        .collect();
+    let plus = roots.iter().zip(&d).map(|(&r, &s)| r + s);
+    let minus = roots.iter().zip(&d).map(|(&r, &s)| r - s);
+    plus.chain(minus).collect()
+}
+
+/// Designs a digital filter from an analog low-pass prototype with unit cutoff: transforms it to the requested
+/// band (SciPy's `lp2*_zpk`), maps it to the z-plane with the bilinear transform and expands the transfer function.
+fn design(proto: Zpk, band: Band) -> Result<(Vec<f64>, Vec<f64>), MathError> {
+    let Zpk { z, p, k } = proto;
+    let degree = p.len() - z.len();
+    let gain_ratio = |z: &[Complex], p: &[Complex]| {
+        (prod(&z.iter().map(|&r| -r).collect::<Vec<_>>())
+            / prod(&p.iter().map(|&r| -r).collect::<Vec<_>>()))
+        .re
+    };
+    let analog = match band {
+        Band::Lowpass(w) => {
+            let wo = warp(w)?;
+            Zpk {
+                z: z.iter().map(|&r| r * wo).collect(),
+                p: p.iter().map(|&r| r * wo).collect(),
+                k: k * wo.powi(degree as i32),
+            }
+        }
+        Band::Highpass(w) => {
+            let wo = warp(w)?;
+            let mut zh: Vec<Complex> = z.iter().map(|&r| Complex::from(wo) / r).collect();
+            zh.resize(p.len(), Complex::ZERO);
+            Zpk {
+                z: zh,
+                p: p.iter().map(|&r| Complex::from(wo) / r).collect(),
+                k: k * gain_ratio(&z, &p),
+            }
+        }
+        Band::Bandpass(w1, w2) => {
+            let (wo, bw) = warp_band(w1, w2)?;
+            let scale = |v: &[Complex]| v.iter().map(|&r| r * (bw / 2.0)).collect::<Vec<_>>();
+            let mut zb = split_roots(&scale(&z), wo);
+            zb.resize(zb.len() + degree, Complex::ZERO);
+            Zpk {
+                z: zb,
+                p: split_roots(&scale(&p), wo),
+                k: k * bw.powi(degree as i32),
+            }
+        }
+        Band::Bandstop(w1, w2) => {
+            let (wo, bw) = warp_band(w1, w2)?;
+            let invert = |v: &[Complex]| {
+                v.iter()
+                    .map(|&r| Complex::from(bw / 2.0) / r)
+                    .collect::<Vec<_>>()
+            };
+            let mut zb = split_roots(&invert(&z), wo);
+            for _ in 0..degree {
+                zb.push(Complex::new(0.0, wo));
+                zb.push(Complex::new(0.0, -wo));
+            }
+            Zpk {
+                z: zb,
+                p: split_roots(&invert(&p), wo),
+                k: k * gain_ratio(&z, &p),
+            }
+        }
+    };
+    // Bilinear transform at a sampling frequency of 2, so that 1 is the Nyquist frequency
+    let fs2 = Complex::from(4.0);
+    let mut zd: Vec<Complex> = analog.z.iter().map(|&r| (fs2 + r) / (fs2 - r)).collect();
+    zd.resize(analog.p.len(), Complex::from(-1.0));
+    let pd: Vec<Complex> = analog.p.iter().map(|&r| (fs2 + r) / (fs2 - r)).collect();
+    let kd = analog.k
+        * (prod(&analog.z.iter().map(|&r| fs2 - r).collect::<Vec<_>>())
+            / prod(&analog.p.iter().map(|&r| fs2 - r).collect::<Vec<_>>()))
+        .re;
+    let b = poly(&zd).iter().map(|c| kd * c.re).collect();
+    let a = poly(&pd).iter().map(|c| c.re).collect();
+    Ok((b, a))
+}
+
+fn check_order(n: usize) -> Result<(), MathError> {
+    if n == 0 {
+        Err(MathError::Domain("filter order must be positive"))
+    } else {
+        Ok(())
+    }
+}
+
+fn check_ripple(r: f64) -> Result<(), MathError> {
+    if r > 0.0 && r.is_finite() {
+        Ok(())
+    } else {
+        Err(MathError::Domain("ripple must be positive"))
+    }
+}
+
+/// ### butter(n, band)
+///
+/// Filter Design
+///
+/// The `butter` function designs a digital Butterworth filter of order `n` (MATLAB's `butter(n, Wn, ftype)`) and
+/// returns its transfer function coefficients `(b, a)`, for use with `filter` or `filtfilt`. Cutoff frequencies are
+/// normalized so that `1` is the Nyquist frequency, and the magnitude response is `1 / sqrt(2)` (-3 dB) at each cutoff.
+/// The Butterworth response is maximally flat in the passband and falls monotonically.
+///
+/// The analog prototype is mapped to the z-plane with the prewarped bilinear transform. As in MATLAB, high orders
+/// (above about 10, or half that for band filters) lose accuracy in this form; filter in cascaded sections instead.
+///
+/// ### Errors
+///
+/// Returns `MathError::Domain` if `n` is zero, a cutoff frequency is not strictly between `0` and `1`, or the edges
+/// of a band are not increasing.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::fix;
+/// use mathlab::signal::{butter, Band};
+/// let round = |c: Vec<f64>| c.iter().map(|v| fix(*v, 8)).collect::<Vec<f64>>();
+/// let (b, a) = butter(2, Band::Lowpass(0.5)).unwrap();
+/// assert_eq!(round(b), [0.29289322, 0.58578644, 0.29289322]);
+/// assert_eq!(round(a), [1.0, 0.0, 0.17157288]);
+/// let (b, a) = butter(1, Band::Highpass(0.5)).unwrap();
+/// assert_eq!((round(b), round(a)), (vec![0.5, -0.5], vec![1.0, 0.0]));
+/// assert!(butter(2, Band::Lowpass(1.0)).is_err());
+/// ```
+/// <small>End Fun Doc</small>
+pub fn butter(n: usize, band: Band) -> Result<(Vec<f64>, Vec<f64>), MathError> {
+    check_order(n)?;
+    let p = (0..n)
+        .map(|j| {
+            let theta = PI * (2 * j + 1 + n) as f64 / (2 * n) as f64;
+            Complex::new(theta.cos(), theta.sin())
+        })
+        .collect();
+    design(
+        Zpk {
+            z: Vec::new(),
+            p,
+            k: 1.0,
+        },
+        band,
+    )
+}
+
+/// ### cheby1(n, rp, band)
+///
+/// Filter Design
+///
+/// The `cheby1` function designs a digital Chebyshev type I filter of order `n` with `rp` decibels of peak-to-peak
+/// ripple in the passband (MATLAB's `cheby1(n, Rp, Wp, ftype)`) and returns its transfer function coefficients `(b, a)`.
+/// Cutoff frequencies are normalized so that `1` is the Nyquist frequency; the magnitude response is `-rp` dB at each
+/// cutoff. For the same order, the transition band is narrower than that of a Butterworth filter.
+///
+/// ### Errors
+///
+/// Returns `MathError::Domain` if `n` is zero, `rp` is not positive, a cutoff frequency is not strictly between
+/// `0` and `1`, or the edges of a band are not increasing.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::fix;
+/// use mathlab::signal::{cheby1, Band};
+/// // Even orders start at the bottom of the ripple: the DC gain is -rp dB
+/// let (b, a) = cheby1(4, 1.0, Band::Lowpass(0.3)).unwrap();
+/// let dc = b.iter().sum::<f64>() / a.iter().sum::<f64>();
+/// assert_eq!(fix(20.0 * dc.log10(), 10), -1.0);
+/// let (b, a) = cheby1(3, 0.5, Band::Lowpass(0.3)).unwrap();
+/// assert_eq!(fix(b.iter().sum::<f64>() / a.iter().sum::<f64>(), 10), 1.0);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn cheby1(n: usize, rp: f64, band: Band) -> Result<(Vec<f64>, Vec<f64>), MathError> {
+    check_order(n)?;
+    check_ripple(rp)?;
+    let eps = (10f64.powf(0.1 * rp) - 1.0).sqrt();
+    let mu = (1.0 / eps).asinh() / n as f64;
+    let p: Vec<Complex> = (0..n)
+        .map(|j| {
+            let theta = PI * (2.0 * j as f64 + 1.0 - n as f64) / (2 * n) as f64;
+            Complex::new(-mu.sinh() * theta.cos(), -mu.cosh() * theta.sin())
+        })
+        .collect();
+    let mut k = prod(&p.iter().map(|&r| -r).collect::<Vec<_>>()).re;
+    if n % 2 == 0 {
+        k /= (1.0 + eps * eps).sqrt();
+    }
+    design(
+        Zpk {
+            z: Vec::new(),
+            p,
+            k,
+        },
+        band,
+    )
+}
+
+/// ### cheby2(n, rs, band)
+///
+/// Filter Design
+///
+/// The `cheby2` function designs a digital Chebyshev type II filter of order `n` with a stopband attenuated by at
+/// least `rs` decibels (MATLAB's `cheby2(n, Rs, Ws, ftype)`) and returns its transfer function coefficients `(b, a)`.
+/// The passband is flat and the ripple is in the stopband. Cutoff frequencies, normalized so that `1` is the Nyquist
+/// frequency, are the edges of the stopband, where the magnitude response is `-rs` dB.
+///
+/// ### Errors
+///
+/// Returns `MathError::Domain` if `n` is zero, `rs` is not positive, a cutoff frequency is not strictly between
+/// `0` and `1`, or the edges of a band are not increasing.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::fix;
+/// use mathlab::signal::{cheby2, Band};
+/// let (b, a) = cheby2(4, 40.0, Band::Lowpass(0.3)).unwrap();
+/// assert_eq!(fix(b.iter().sum::<f64>() / a.iter().sum::<f64>(), 10), 1.0);
+/// // Even orders reach the stopband ripple at the Nyquist frequency (z = -1)
+/// let alt = |c: &[f64]| c.iter().enumerate().map(|(k, v)| if k % 2 == 0 { *v } else { -v }).sum::<f64>();
+/// assert_eq!(fix(20.0 * (alt(&b) / alt(&a)).abs().log10(), 6), -40.0);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn cheby2(n: usize, rs: f64, band: Band) -> Result<(Vec<f64>, Vec<f64>), MathError> {
+    check_order(n)?;
+    check_ripple(rs)?;
+    let de = 1.0 / (10f64.powf(0.1 * rs) - 1.0).sqrt();
+    let mu = (1.0 / de).asinh() / n as f64;
+    let m = |j: usize| 2.0 * j as f64 + 1.0 - n as f64;
+    let z: Vec<Complex> = (0..n)
+        .filter(|&j| m(j) != 0.0)
+        .map(|j| Complex::new(0.0, 1.0 / (m(j) * PI / (2 * n) as f64).sin()))
+        .collect();
+    let p: Vec<Complex> = (0..n)
+        .map(|j| {
+            let theta = m(j) * PI / (2 * n) as f64;
+            Complex::ONE / Complex::new(-mu.sinh() * theta.cos(), -mu.cosh() * theta.sin())
+        })
+        .collect();
+    let k = (prod(&p.iter().map(|&r| -r).collect::<Vec<_>>())
+        / prod(&z.iter().map(|&r| -r).collect::<Vec<_>>()))
+    .re;
+    design(Zpk { z, p, k }, band)
+}
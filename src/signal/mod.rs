@@ -0,0 +1,7 @@
+pub mod fft;
+pub mod filter;
+pub mod window;
+
+pub use fft::*;
+pub use filter::*;
+pub use window::*;
@@ -0,0 +1,150 @@
+use crate::constants::PI;
+use crate::functions::num::besseli;
+
+/// ### Symmetry
+///
+/// Window Sampling
+///
+/// The `Symmetry` enum selects how a window of `n` points is sampled:
+/// - `Symmetric`: the window is symmetric about its middle, with equal end points (MATLAB's default, for filter design)
+/// - `Periodic`: the window is one period of length `n` of the periodic extension, i.e. the first `n` points of the
+///   symmetric window of `n + 1` points (MATLAB's `'periodic'`, for spectral analysis)
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::fix;
+/// use mathlab::signal::{hann, Symmetry};
+/// let round = |w: Vec<f64>| w.iter().map(|v| fix(*v, 12)).collect::<Vec<f64>>();
+/// assert_eq!(round(hann(3, Symmetry::Symmetric)), [0.0, 1.0, 0.0]);
+/// assert_eq!(round(hann(4, Symmetry::Periodic)), [0.0, 0.5, 1.0, 0.5]);
+/// ```
+/// <small>End Type Doc</small>
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Symmetry {
+    Symmetric,
+    Periodic,
+}
+
+/// A generalized cosine window `sum((-1)^j * a[j] * cos(2 pi j k / m))`, where `m` is `n - 1` for
+/// symmetric windows and `n` for periodic ones.
+fn cosine_window(n: usize, symmetry: Symmetry, a: &[f64]) -> Vec<f64> {
+    if n <= 1 {
+        return vec![1.0; n];
+    }
+    let m = match symmetry {
+        Symmetry::Symmetric => n - 1,
+        Symmetry::Periodic => n,
+    } as f64;
+    (0..n)
+        .map(|k| {
+            let x = 2.0 * PI * k as f64 / m;
+            a.iter()
+                .enumerate()
+                .map(|(j, c)| {
+                    let term = c * (j as f64 * x).cos();
+                    if j % 2 == 0 {
+                        term
+                    } else {
+                        -term
+                    }
+                })
+                .sum()
+        })
+        .collect()
+}
+
+/// ### hann(n, symmetry)
+///
+/// Window Function
+///
+/// The `hann` function returns the Hann window `0.5 - 0.5 * cos(2 pi k / m)` of `n` points (MATLAB's `hann`),
+/// where `m` is `n - 1` for a symmetric window and `n` for a periodic one. A window of one point is `[1.0]`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::fix;
+/// use mathlab::signal::{hann, Symmetry};
+/// let round = |w: Vec<f64>| w.iter().map(|v| fix(*v, 12)).collect::<Vec<f64>>();
+/// assert_eq!(round(hann(5, Symmetry::Symmetric)), [0.0, 0.5, 1.0, 0.5, 0.0]);
+/// assert_eq!(round(hann(4, Symmetry::Periodic)), [0.0, 0.5, 1.0, 0.5]);
+/// assert!(hann(0, Symmetry::Symmetric).is_empty());
+/// ```
+/// <small>End Fun Doc</small>
+pub fn hann(n: usize, symmetry: Symmetry) -> Vec<f64> {
+    cosine_window(n, symmetry, &[0.5, 0.5])
+}
+
+/// ### hamming(n, symmetry)
+///
+/// Window Function
+///
+/// The `hamming` function returns the Hamming window `0.54 - 0.46 * cos(2 pi k / m)` of `n` points (MATLAB's `hamming`),
+/// where `m` is `n - 1` for a symmetric window and `n` for a periodic one. Unlike the Hann window, it does not
+/// reach zero at the ends.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::fix;
+/// use mathlab::signal::{hamming, Symmetry};
+/// let round = |w: Vec<f64>| w.iter().map(|v| fix(*v, 12)).collect::<Vec<f64>>();
+/// assert_eq!(round(hamming(5, Symmetry::Symmetric)), [0.08, 0.54, 1.0, 0.54, 0.08]);
+/// assert_eq!(round(hamming(4, Symmetry::Periodic)), [0.08, 0.54, 1.0, 0.54]);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn hamming(n: usize, symmetry: Symmetry) -> Vec<f64> {
+    cosine_window(n, symmetry, &[0.54, 0.46])
+}
+
+/// ### blackman(n, symmetry)
+///
+/// Window Function
+///
+/// The `blackman` function returns the Blackman window `0.42 - 0.5 * cos(2 pi k / m) + 0.08 * cos(4 pi k / m)`
+/// of `n` points (MATLAB's `blackman`), where `m` is `n - 1` for a symmetric window and `n` for a periodic one.
+/// Its side lobes are lower than those of the Hann and Hamming windows, at the cost of a wider main lobe.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::fix;
+/// use mathlab::signal::{blackman, Symmetry};
+/// let round = |w: Vec<f64>| w.iter().map(|v| fix(*v, 12)).collect::<Vec<f64>>();
+/// assert_eq!(round(blackman(5, Symmetry::Symmetric)), [0.0, 0.34, 1.0, 0.34, 0.0]);
+/// assert_eq!(round(blackman(4, Symmetry::Periodic)), [0.0, 0.34, 1.0, 0.34]);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn blackman(n: usize, symmetry: Symmetry) -> Vec<f64> {
+    cosine_window(n, symmetry, &[0.42, 0.5, 0.08])
+}
+
+/// ### kaiser(n, beta)
+///
+/// Window Function
+///
+/// The `kaiser` function returns the symmetric Kaiser window `I0(beta * sqrt(1 - (2k / (n - 1) - 1)^2)) / I0(beta)`
+/// of `n` points (MATLAB's `kaiser`), where `I0` is the modified Bessel function of the first kind of order zero.
+/// The shape parameter `beta` trades main-lobe width for side-lobe level: `0` gives the rectangular window,
+/// and about `5` is close to a Hamming window.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{fix64, fix64_vec};
+/// use mathlab::signal::kaiser;
+/// assert_eq!(kaiser(4, 0.0), [1.0; 4]);
+/// let w = kaiser(5, 5.0);
+/// assert_eq!(fix64_vec(&w), [0.036710892, 0.5528518, 1.0, 0.5528518, 0.036710892]);
+/// assert_eq!(fix64(w[0]), fix64(1.0 / mathlab::functions::num::besseli(0.0, 5.0)));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn kaiser(n: usize, beta: f64) -> Vec<f64> {
+    if n <= 1 {
+        return vec![1.0; n];
+    }
+    let i0 = besseli(0.0, beta);
+    let m = (n - 1) as f64;
+    (0..n)
+        .map(|k| {
+            let r = 2.0 * k as f64 / m - 1.0;
+            besseli(0.0, beta * (1.0 - r * r).max(0.0).sqrt()) / i0
+        })
+        .collect()
+}
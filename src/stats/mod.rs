@@ -0,0 +1,834 @@
+use crate::error::MathError;
+use crate::real::Real;
+use std::cmp::Ordering;
+
+/// ### Interpolation
+///
+/// Quantile Method
+///
+/// The `Interpolation` type selects how `quantile` and `percentile` estimate a value that falls between two
+/// order statistics. For `n` sorted values `x[0] <= ... <= x[n - 1]` and probability `p`:
+///
+/// - `Linear`: linear interpolation at position `p * (n - 1)` (NumPy's default, R type 7)
+/// - `Lower` / `Higher`: the order statistic just below / above that position
+/// - `Nearest`: the closer of the two order statistics, ties going to the even index
+/// - `Midpoint`: the mean of the two order statistics
+/// - `Hazen`: linear interpolation at position `p * n - 0.5`, clamped to the data (MATLAB's default, R type 5)
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::stats::{quantile, Interpolation};
+/// let x = [1.0, 2.0, 3.0, 4.0];
+/// assert_eq!(quantile(&x, 0.5, Interpolation::Linear), Ok(2.5));
+/// assert_eq!(quantile(&x, 0.5, Interpolation::Lower), Ok(2.0));
+/// assert_eq!(quantile(&x, 0.5, Interpolation::Higher), Ok(3.0));
+/// assert_eq!(quantile(&x, 0.25, Interpolation::Linear), Ok(1.75));
+/// assert_eq!(quantile(&x, 0.25, Interpolation::Hazen), Ok(1.5));
+/// ```
+/// <small>End Type Doc</small>
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Interpolation {
+    Linear,
+    Lower,
+    Higher,
+    Nearest,
+    Midpoint,
+    Hazen,
+}
+
+/// Returns an error for empty input, the common precondition of the statistics below.
+fn check_nonempty<T>(x: &[T]) -> Result<(), MathError> {
+    if x.is_empty() {
+        Err(MathError::EmptyInput)
+    } else {
+        Ok(())
+    }
+}
+
+/// Returns the values that are not NaN.
+fn drop_nan<T: Real>(x: &[T]) -> Vec<T> {
+    x.iter().copied().filter(|v| !v.is_nan()).collect()
+}
+
+/// Returns a sorted copy of `x`, which must not contain NaN.
+fn sorted<T: Real>(x: &[T]) -> Vec<T> {
+    let mut s = x.to_vec();
+    s.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+    s
+}
+
+/// ### sum(x)
+///
+/// Statistical Function
+///
+/// The `sum` function returns the sum of the elements of `x`; the sum of an empty slice is `0`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::stats::sum;
+/// assert_eq!(sum(&[1.0, 2.0, 3.5]), 6.5);
+/// assert_eq!(sum::<f64>(&[]), 0.0);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn sum<T: Real>(x: &[T]) -> T {
+    x.iter().fold(T::ZERO, |acc, &v| acc + v)
+}
+
+/// ### prod(x)
+///
+/// Statistical Function
+///
+/// The `prod` function returns the product of the elements of `x`; the product of an empty slice is `1`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::stats::prod;
+/// assert_eq!(prod(&[1.0, 2.0, 3.5]), 7.0);
+/// assert_eq!(prod::<f32>(&[]), 1.0);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn prod<T: Real>(x: &[T]) -> T {
+    x.iter().fold(T::ONE, |acc, &v| acc * v)
+}
+
+/// ### cumsum(x)
+///
+/// Statistical Function
+///
+/// The `cumsum` function returns the running sums of `x` (MATLAB's `cumsum`).
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::stats::cumsum;
+/// assert_eq!(cumsum(&[1.0, 2.0, 3.0, 4.0]), [1.0, 3.0, 6.0, 10.0]);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn cumsum<T: Real>(x: &[T]) -> Vec<T> {
+    x.iter()
+        .scan(T::ZERO, |acc, &v| {
+            *acc += v;
+            Some(*acc)
+        })
+        .collect()
+}
+
+/// ### cumprod(x)
+///
+/// Statistical Function
+///
+/// The `cumprod` function returns the running products of `x` (MATLAB's `cumprod`).
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::stats::cumprod;
+/// assert_eq!(cumprod(&[1.0, 2.0, 3.0, 4.0]), [1.0, 2.0, 6.0, 24.0]);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn cumprod<T: Real>(x: &[T]) -> Vec<T> {
+    x.iter()
+        .scan(T::ONE, |acc, &v| {
+            *acc *= v;
+            Some(*acc)
+        })
+        .collect()
+}
+
+/// ### mean(x)
+///
+/// Statistical Function
+///
+/// The `mean` function returns the arithmetic mean of `x`. It is `NaN` if `x` contains `NaN`; see `nanmean`.
+///
+/// ### Errors
+///
+/// Returns `MathError::EmptyInput` if `x` is empty.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::{MathError, sqr_vec, range_from_to};
+/// use mathlab::stats::mean;
+/// assert_eq!(mean(&[1.0, 2.0, 3.0, 4.0]), Ok(2.5));
+/// assert_eq!(mean::<f64>(&[]), Err(MathError::EmptyInput));
+/// // Works directly on the output of the *_vec functions
+/// assert_eq!(mean(&sqr_vec(&range_from_to(1.0, 3.0, 1.0))), Ok(14.0 / 3.0));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn mean<T: Real>(x: &[T]) -> Result<T, MathError> {
+    check_nonempty(x)?;
+    Ok(sum(x) / T::from_f64(x.len() as f64))
+}
+
+/// ### median(x)
+///
+/// Statistical Function
+///
+/// The `median` function returns the middle value of `x`, or the mean of the two middle values when `x` has an even length.
+/// It is `NaN` if `x` contains `NaN`; see `nanmedian`.
+///
+/// ### Errors
+///
+/// Returns `MathError::EmptyInput` if `x` is empty.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::stats::median;
+/// assert_eq!(median(&[3.0, 1.0, 2.0]), Ok(2.0));
+/// assert_eq!(median(&[4.0, 1.0, 3.0, 2.0]), Ok(2.5));
+/// assert!(median(&[1.0, f64::NAN]).unwrap().is_nan());
+/// ```
+/// <small>End Fun Doc</small>
+pub fn median<T: Real>(x: &[T]) -> Result<T, MathError> {
+    quantile(x, 0.5, Interpolation::Midpoint)
+}
+
+/// ### mode(x)
+///
+/// Statistical Function
+///
+/// The `mode` function returns the most frequent value of `x`; when several values are equally frequent, the smallest one is returned,
+/// as in MATLAB. `NaN` values are ignored, and `x` holding only `NaN` gives `NaN`.
+///
+/// ### Errors
+///
+/// Returns `MathError::EmptyInput` if `x` is empty.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::stats::mode;
+/// assert_eq!(mode(&[1.0, 2.0, 2.0, 3.0, 3.0]), Ok(2.0));
+/// assert_eq!(mode(&[5.0, f64::NAN, f64::NAN]), Ok(5.0));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn mode<T: Real>(x: &[T]) -> Result<T, MathError> {
+    check_nonempty(x)?;
+    let s = sorted(&drop_nan(x));
+    let (mut best, mut best_count) = (T::NAN, 0);
+    let mut i = 0;
+    while i < s.len() {
+        let j = i + s[i..].iter().take_while(|&&v| v == s[i]).count();
+        if j - i > best_count {
+            best = s[i];
+            best_count = j - i;
+        }
+        i = j;
+    }
+    Ok(best)
+}
+
+/// Sum of squared deviations from the mean.
+fn sum_sq_dev<T: Real>(x: &[T]) -> Result<T, MathError> {
+    let m = mean(x)?;
+    Ok(x.iter().fold(T::ZERO, |acc, &v| acc + (v - m) * (v - m)))
+}
+
+/// ### var(x)
+///
+/// Statistical Function
+///
+/// The `var` function returns the sample variance of `x`, normalized by `n - 1` (MATLAB's default `var`).
+/// A single value has variance `0`. Use `var_pop` for the population variance.
+///
+/// ### Errors
+///
+/// Returns `MathError::EmptyInput` if `x` is empty.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::stats::var;
+/// assert_eq!(var(&[2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]), Ok(32.0 / 7.0));
+/// assert_eq!(var(&[3.0]), Ok(0.0));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn var<T: Real>(x: &[T]) -> Result<T, MathError> {
+    let ss = sum_sq_dev(x)?;
+    Ok(ss / T::from_f64(x.len().saturating_sub(1).max(1) as f64))
+}
+
+/// ### var_pop(x)
+///
+/// Statistical Function
+///
+/// The `var_pop` function returns the population variance of `x`, normalized by `n` (MATLAB's `var(x, 1)`).
+///
+/// ### Errors
+///
+/// Returns `MathError::EmptyInput` if `x` is empty.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::stats::var_pop;
+/// assert_eq!(var_pop(&[2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]), Ok(4.0));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn var_pop<T: Real>(x: &[T]) -> Result<T, MathError> {
+    Ok(sum_sq_dev(x)? / T::from_f64(x.len() as f64))
+}
+
+/// ### std(x)
+///
+/// Statistical Function
+///
+/// The `std` function returns the sample standard deviation of `x`, the square root of `var` (MATLAB's default `std`).
+///
+/// ### Errors
+///
+/// Returns `MathError::EmptyInput` if `x` is empty.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::fix64;
+/// use mathlab::stats::std;
+/// assert_eq!(fix64(std(&[2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]).unwrap()), 2.13809);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn std<T: Real>(x: &[T]) -> Result<T, MathError> {
+    Ok(var(x)?.sqrt())
+}
+
+/// ### std_pop(x)
+///
+/// Statistical Function
+///
+/// The `std_pop` function returns the population standard deviation of `x`, the square root of `var_pop` (MATLAB's `std(x, 1)`).
+///
+/// ### Errors
+///
+/// Returns `MathError::EmptyInput` if `x` is empty.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::stats::std_pop;
+/// assert_eq!(std_pop(&[2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]), Ok(2.0));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn std_pop<T: Real>(x: &[T]) -> Result<T, MathError> {
+    Ok(var_pop(x)?.sqrt())
+}
+
+/// Index of the extreme element by `better`; a NaN element wins, so that NaN propagates.
+fn extreme<T: Real>(x: &[T], better: impl Fn(T, T) -> bool) -> Result<(T, usize), MathError> {
+    check_nonempty(x)?;
+    let mut best = 0;
+    for (i, &v) in x.iter().enumerate().skip(1) {
+        if x[best].is_nan() {
+            break;
+        }
+        if v.is_nan() || better(v, x[best]) {
+            best = i;
+        }
+    }
+    Ok((x[best], best))
+}
+
+/// ### min(x)
+///
+/// Statistical Function
+///
+/// The `min` function returns the smallest element of `x` together with its index (MATLAB's `[m, i] = min(x)`).
+/// The first occurrence is reported for repeated values. If `x` contains `NaN`, the first `NaN` and its index are returned; see `nanmin`.
+///
+/// ### Errors
+///
+/// Returns `MathError::EmptyInput` if `x` is empty.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::stats::min;
+/// assert_eq!(min(&[3.0, 1.0, 2.0, 1.0]), Ok((1.0, 1)));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn min<T: Real>(x: &[T]) -> Result<(T, usize), MathError> {
+    extreme(x, |a, b| a < b)
+}
+
+/// ### max(x)
+///
+/// Statistical Function
+///
+/// The `max` function returns the largest element of `x` together with its index (MATLAB's `[m, i] = max(x)`).
+/// The first occurrence is reported for repeated values. If `x` contains `NaN`, the first `NaN` and its index are returned; see `nanmax`.
+///
+/// ### Errors
+///
+/// Returns `MathError::EmptyInput` if `x` is empty.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::stats::max;
+/// assert_eq!(max(&[3.0, 1.0, 2.0, 3.0]), Ok((3.0, 0)));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn max<T: Real>(x: &[T]) -> Result<(T, usize), MathError> {
+    extreme(x, |a, b| a > b)
+}
+
+/// ### range(x)
+///
+/// Statistical Function
+///
+/// The `range` function returns the difference between the largest and the smallest element of `x` (MATLAB's `range`).
+///
+/// ### Errors
+///
+/// Returns `MathError::EmptyInput` if `x` is empty.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::stats::range;
+/// assert_eq!(range(&[3.0, -1.0, 2.0]), Ok(4.0));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn range<T: Real>(x: &[T]) -> Result<T, MathError> {
+    Ok(max(x)?.0 - min(x)?.0)
+}
+
+/// ### quantile(x, p, method)
+///
+/// Statistical Function
+///
+/// The `quantile` function returns the `p`-quantile of `x` for a probability `p` in `[0, 1]`,
+/// estimated with the given `Interpolation` method. It is `NaN` if `x` contains `NaN`; see `nanquantile`.
+///
+/// ### Errors
+///
+/// Returns `MathError::EmptyInput` if `x` is empty and `MathError::Domain` if `p` is outside `[0, 1]`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::stats::{quantile, Interpolation};
+/// let x = [7.0, 1.0, 3.0, 5.0, 9.0];
+/// assert_eq!(quantile(&x, 0.0, Interpolation::Linear), Ok(1.0));
+/// assert_eq!(quantile(&x, 1.0, Interpolation::Linear), Ok(9.0));
+/// assert_eq!(quantile(&x, 0.3, Interpolation::Linear), Ok(3.4));
+/// assert_eq!(quantile(&x, 0.3, Interpolation::Nearest), Ok(3.0));
+/// assert_eq!(quantile(&x, 0.3, Interpolation::Midpoint), Ok(4.0));
+/// assert_eq!(quantile(&x, 0.3, Interpolation::Hazen), Ok(3.0));
+/// assert!(quantile(&x, 1.5, Interpolation::Linear).is_err());
+/// ```
+/// <small>End Fun Doc</small>
+pub fn quantile<T: Real>(x: &[T], p: f64, method: Interpolation) -> Result<T, MathError> {
+    check_nonempty(x)?;
+    if !(0.0..=1.0).contains(&p) {
+        return Err(MathError::Domain("p must be in [0, 1]"));
+    }
+    if x.iter().any(|v| v.is_nan()) {
+        return Ok(T::NAN);
+    }
+    let s = sorted(x);
+    let n = s.len();
+    let pos = match method {
+        Interpolation::Hazen => (p * n as f64 - 0.5).clamp(0.0, (n - 1) as f64),
+        _ => p * (n - 1) as f64,
+    };
+    let lo = pos.floor() as usize;
+    let hi = pos.ceil() as usize;
+    let frac = pos - lo as f64;
+    Ok(match method {
+        Interpolation::Linear | Interpolation::Hazen => s[lo] + (s[hi] - s[lo]) * T::from_f64(frac),
+        Interpolation::Lower => s[lo],
+        Interpolation::Higher => s[hi],
+        Interpolation::Nearest => {
+            if frac < 0.5 || (frac == 0.5 && lo % 2 == 0) {
+                s[lo]
+            } else {
+                s[hi]
+            }
+        }
+        Interpolation::Midpoint => (s[lo] + s[hi]) / T::from_f64(2.0),
+    })
+}
+
+/// ### percentile(x, p, method)
+///
+/// Statistical Function
+///
+/// The `percentile` function returns the `p`-th percentile of `x` for `p` in `[0, 100]` (MATLAB's `prctile`),
+/// i.e. `quantile(x, p / 100, method)`.
+///
+/// ### Errors
+///
+/// Returns `MathError::EmptyInput` if `x` is empty and `MathError::Domain` if `p` is outside `[0, 100]`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::range_from_to;
+/// use mathlab::stats::{percentile, Interpolation};
+/// let x = range_from_to(1.0, 101.0, 1.0);
+/// assert_eq!(percentile(&x, 90.0, Interpolation::Linear), Ok(91.0));
+/// assert_eq!(percentile(&x, 50.0, Interpolation::Hazen), Ok(51.0));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn percentile<T: Real>(x: &[T], p: f64, method: Interpolation) -> Result<T, MathError> {
+    if !(0.0..=100.0).contains(&p) {
+        return Err(MathError::Domain("p must be in [0, 100]"));
+    }
+    quantile(x, p / 100.0, method)
+}
+
+/// ### skewness(x)
+///
+/// Statistical Function
+///
+/// The `skewness` function returns the sample skewness `m3 / m2^1.5` of `x`, where `mk` is the `k`-th central moment
+/// (MATLAB's default, biased `skewness`). Symmetric data has skewness `0`; a constant slice gives `NaN`.
+///
+/// ### Errors
+///
+/// Returns `MathError::EmptyInput` if `x` is empty.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::math::fix64;
+/// use mathlab::stats::skewness;
+/// assert_eq!(skewness(&[1.0, 2.0, 3.0]), Ok(0.0));
+/// assert_eq!(fix64(skewness(&[1.0, 1.0, 1.0, 5.0]).unwrap()), 1.1547005);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn skewness<T: Real>(x: &[T]) -> Result<T, MathError> {
+    let (m2, m3, _) = central_moments(x)?;
+    Ok(m3 / (m2 * m2 * m2).sqrt())
+}
+
+/// ### kurtosis(x)
+///
+/// Statistical Function
+///
+/// The `kurtosis` function returns the sample kurtosis `m4 / m2²` of `x`, where `mk` is the `k`-th central moment
+/// (MATLAB's default, biased `kurtosis`). The normal distribution has kurtosis `3`; subtract `3` for the excess kurtosis.
+///
+/// ### Errors
+///
+/// Returns `MathError::EmptyInput` if `x` is empty.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::stats::kurtosis;
+/// assert_eq!(kurtosis(&[1.0, 2.0, 3.0, 4.0]), Ok(1.64));
+/// assert_eq!(kurtosis(&[-1.0, 1.0]), Ok(1.0));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn kurtosis<T: Real>(x: &[T]) -> Result<T, MathError> {
+    let (m2, _, m4) = central_moments(x)?;
+    Ok(m4 / (m2 * m2))
+}
+
+/// Second, third and fourth central moments, normalized by `n`.
+fn central_moments<T: Real>(x: &[T]) -> Result<(T, T, T), MathError> {
+    let m = mean(x)?;
+    let n = T::from_f64(x.len() as f64);
+    let (mut m2, mut m3, mut m4) = (T::ZERO, T::ZERO, T::ZERO);
+    for &v in x {
+        let d = v - m;
+        let d2 = d * d;
+        m2 += d2;
+        m3 += d2 * d;
+        m4 += d2 * d2;
+    }
+    Ok((m2 / n, m3 / n, m4 / n))
+}
+
+/// ### nansum(x)
+///
+/// Statistical Function
+///
+/// The `nansum` function returns the sum of the elements of `x` that are not `NaN`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::stats::nansum;
+/// assert_eq!(nansum(&[1.0, f64::NAN, 2.0]), 3.0);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn nansum<T: Real>(x: &[T]) -> T {
+    sum(&drop_nan(x))
+}
+
+/// ### nanprod(x)
+///
+/// Statistical Function
+///
+/// The `nanprod` function returns the product of the elements of `x` that are not `NaN`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::stats::nanprod;
+/// assert_eq!(nanprod(&[3.0, f64::NAN, 2.0]), 6.0);
+/// ```
+/// <small>End Fun Doc</small>
+pub fn nanprod<T: Real>(x: &[T]) -> T {
+    prod(&drop_nan(x))
+}
+
+/// ### nanmean(x)
+///
+/// Statistical Function
+///
+/// The `nanmean` function returns the mean of the elements of `x` that are not `NaN` (MATLAB's `mean(x, 'omitnan')`).
+///
+/// ### Errors
+///
+/// Returns `MathError::EmptyInput` if `x` has no element that is not `NaN`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::stats::nanmean;
+/// assert_eq!(nanmean(&[1.0, f64::NAN, 3.0]), Ok(2.0));
+/// assert!(nanmean(&[f64::NAN]).is_err());
+/// ```
+/// <small>End Fun Doc</small>
+pub fn nanmean<T: Real>(x: &[T]) -> Result<T, MathError> {
+    mean(&drop_nan(x))
+}
+
+/// ### nanmedian(x)
+///
+/// Statistical Function
+///
+/// The `nanmedian` function returns the median of the elements of `x` that are not `NaN`.
+///
+/// ### Errors
+///
+/// Returns `MathError::EmptyInput` if `x` has no element that is not `NaN`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::stats::nanmedian;
+/// assert_eq!(nanmedian(&[4.0, f64::NAN, 1.0, 2.0]), Ok(2.0));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn nanmedian<T: Real>(x: &[T]) -> Result<T, MathError> {
+    median(&drop_nan(x))
+}
+
+/// ### nanvar(x)
+///
+/// Statistical Function
+///
+/// The `nanvar` function returns the sample variance of the elements of `x` that are not `NaN`.
+///
+/// ### Errors
+///
+/// Returns `MathError::EmptyInput` if `x` has no element that is not `NaN`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::stats::nanvar;
+/// assert_eq!(nanvar(&[1.0, f64::NAN, 3.0]), Ok(2.0));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn nanvar<T: Real>(x: &[T]) -> Result<T, MathError> {
+    var(&drop_nan(x))
+}
+
+/// ### nanvar_pop(x)
+///
+/// Statistical Function
+///
+/// The `nanvar_pop` function returns the population variance of the elements of `x` that are not `NaN`.
+///
+/// ### Errors
+///
+/// Returns `MathError::EmptyInput` if `x` has no element that is not `NaN`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::stats::nanvar_pop;
+/// assert_eq!(nanvar_pop(&[1.0, f64::NAN, 3.0]), Ok(1.0));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn nanvar_pop<T: Real>(x: &[T]) -> Result<T, MathError> {
+    var_pop(&drop_nan(x))
+}
+
+/// ### nanstd(x)
+///
+/// Statistical Function
+///
+/// The `nanstd` function returns the sample standard deviation of the elements of `x` that are not `NaN`.
+///
+/// ### Errors
+///
+/// Returns `MathError::EmptyInput` if `x` has no element that is not `NaN`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::stats::nanstd;
+/// assert_eq!(nanstd(&[1.0, f64::NAN, 5.0]), Ok(8.0_f64.sqrt()));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn nanstd<T: Real>(x: &[T]) -> Result<T, MathError> {
+    std(&drop_nan(x))
+}
+
+/// ### nanstd_pop(x)
+///
+/// Statistical Function
+///
+/// The `nanstd_pop` function returns the population standard deviation of the elements of `x` that are not `NaN`.
+///
+/// ### Errors
+///
+/// Returns `MathError::EmptyInput` if `x` has no element that is not `NaN`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::stats::nanstd_pop;
+/// assert_eq!(nanstd_pop(&[1.0, f64::NAN, 5.0]), Ok(2.0));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn nanstd_pop<T: Real>(x: &[T]) -> Result<T, MathError> {
+    std_pop(&drop_nan(x))
+}
+
+/// Index of the extreme non-NaN element by `better`.
+fn nan_extreme<T: Real>(x: &[T], better: impl Fn(T, T) -> bool) -> Result<(T, usize), MathError> {
+    let mut best: Option<usize> = None;
+    for (i, &v) in x.iter().enumerate() {
+        if !v.is_nan() && best.is_none_or(|b| better(v, x[b])) {
+            best = Some(i);
+        }
+    }
+    best.map(|b| (x[b], b)).ok_or(MathError::EmptyInput)
+}
+
+/// ### nanmin(x)
+///
+/// Statistical Function
+///
+/// The `nanmin` function returns the smallest element of `x` that is not `NaN`, together with its index in `x`
+/// (MATLAB's default `min`, which omits `NaN`).
+///
+/// ### Errors
+///
+/// Returns `MathError::EmptyInput` if `x` has no element that is not `NaN`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::stats::nanmin;
+/// assert_eq!(nanmin(&[f64::NAN, 3.0, 1.0]), Ok((1.0, 2)));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn nanmin<T: Real>(x: &[T]) -> Result<(T, usize), MathError> {
+    nan_extreme(x, |a, b| a < b)
+}
+
+/// ### nanmax(x)
+///
+/// Statistical Function
+///
+/// The `nanmax` function returns the largest element of `x` that is not `NaN`, together with its index in `x`
+/// (MATLAB's default `max`, which omits `NaN`).
+///
+/// ### Errors
+///
+/// Returns `MathError::EmptyInput` if `x` has no element that is not `NaN`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::stats::nanmax;
+/// assert_eq!(nanmax(&[f64::NAN, 3.0, 1.0]), Ok((3.0, 1)));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn nanmax<T: Real>(x: &[T]) -> Result<(T, usize), MathError> {
+    nan_extreme(x, |a, b| a > b)
+}
+
+/// ### nanrange(x)
+///
+/// Statistical Function
+///
+/// The `nanrange` function returns the range of the elements of `x` that are not `NaN`.
+///
+/// ### Errors
+///
+/// Returns `MathError::EmptyInput` if `x` has no element that is not `NaN`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::stats::nanrange;
+/// assert_eq!(nanrange(&[f64::NAN, 3.0, 1.0]), Ok(2.0));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn nanrange<T: Real>(x: &[T]) -> Result<T, MathError> {
+    range(&drop_nan(x))
+}
+
+/// ### nanquantile(x, p, method)
+///
+/// Statistical Function
+///
+/// The `nanquantile` function returns the `p`-quantile of the elements of `x` that are not `NaN`.
+///
+/// ### Errors
+///
+/// Returns `MathError::EmptyInput` if `x` has no element that is not `NaN` and `MathError::Domain` if `p` is outside `[0, 1]`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::stats::{nanquantile, Interpolation};
+/// assert_eq!(nanquantile(&[1.0, f64::NAN, 2.0, 3.0], 0.5, Interpolation::Linear), Ok(2.0));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn nanquantile<T: Real>(x: &[T], p: f64, method: Interpolation) -> Result<T, MathError> {
+    quantile(&drop_nan(x), p, method)
+}
+
+/// ### nanpercentile(x, p, method)
+///
+/// Statistical Function
+///
+/// The `nanpercentile` function returns the `p`-th percentile of the elements of `x` that are not `NaN`.
+///
+/// ### Errors
+///
+/// Returns `MathError::EmptyInput` if `x` has no element that is not `NaN` and `MathError::Domain` if `p` is outside `[0, 100]`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::stats::{nanpercentile, Interpolation};
+/// assert_eq!(nanpercentile(&[1.0, f64::NAN, 2.0, 3.0], 100.0, Interpolation::Linear), Ok(3.0));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn nanpercentile<T: Real>(x: &[T], p: f64, method: Interpolation) -> Result<T, MathError> {
+    percentile(&drop_nan(x), p, method)
+}
+
+/// ### nanskewness(x)
+///
+/// Statistical Function
+///
+/// The `nanskewness` function returns the skewness of the elements of `x` that are not `NaN`.
+///
+/// ### Errors
+///
+/// Returns `MathError::EmptyInput` if `x` has no element that is not `NaN`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::stats::nanskewness;
+/// assert_eq!(nanskewness(&[1.0, f64::NAN, 2.0, 3.0]), Ok(0.0));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn nanskewness<T: Real>(x: &[T]) -> Result<T, MathError> {
+    skewness(&drop_nan(x))
+}
+
+/// ### nankurtosis(x)
+///
+/// Statistical Function
+///
+/// The `nankurtosis` function returns the kurtosis of the elements of `x` that are not `NaN`.
+///
+/// ### Errors
+///
+/// Returns `MathError::EmptyInput` if `x` has no element that is not `NaN`.
+///
+/// ### Examples
+/// ```rust
+/// use mathlab::stats::nankurtosis;
+/// assert_eq!(nankurtosis(&[-1.0, f64::NAN, 1.0]), Ok(1.0));
+/// ```
+/// <small>End Fun Doc</small>
+pub fn nankurtosis<T: Real>(x: &[T]) -> Result<T, MathError> {
+    kurtosis(&drop_nan(x))
+}